@@ -0,0 +1,200 @@
+use clap::Parser;
+use rng::{ONDRng, SeedContext};
+use serde::Serialize;
+use simulator::{
+    meta::write_meta,
+    output::{gz_path, CsvAppender},
+};
+use sweep_common::{
+    apply_layer_params, apply_noisy_layer_params, build_layer_params, chi_max, parse_list,
+    SweepNoise,
+};
+use tn::{mps::MPS, truncation::Truncation};
+
+use std::time::Instant;
+
+#[derive(Parser, Debug, Serialize)]
+#[command(author, version, about = "MPS chi growth sweep (brickwork 1D)")]
+pub struct Args {
+    /// Number of qubits
+    #[arg(long, default_value_t = 64)]
+    n: usize,
+
+    /// Maximum circuit depth (number of brickwork layers)
+    #[arg(long, default_value_t = 200)]
+    depth_max: usize,
+
+    /// Depth step between measurements
+    #[arg(long, default_value_t = 5)]
+    depth_step: usize,
+
+    /// Comma-separated list of max bond dimensions
+    #[arg(long, default_value = "16,32,64")]
+    max_bond: String,
+
+    /// SVD cutoff
+    #[arg(long, default_value_t = 1e-8)]
+    cutoff: f64,
+
+    /// Base RNG seed (shared across max_bond sweeps)
+    #[arg(long, default_value = "chi-sweep")]
+    seed: String,
+
+    /// Output CSV path
+    #[arg(long, default_value = "chi_sweep.csv")]
+    out: String,
+
+    /// Widen the measurement interval once the half-chain entropy saturates
+    #[arg(long)]
+    adaptive: bool,
+
+    /// Entropy change below which --adaptive treats the state as saturated
+    #[arg(long, default_value_t = 0.05)]
+    entropy_tol: f64,
+
+    /// Print worst-case memory and gate counts, then exit without running
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Write gzip-compressed `<out>.gz` instead of plain CSV
+    #[arg(long)]
+    gzip: bool,
+
+    /// Round circuit angles down to this many bits of a turn (exact binary fractions)
+    #[arg(long)]
+    angle_bits: Option<u32>,
+
+    /// Depolarizing probability after each two-qubit block (0 = noiseless)
+    #[arg(long, default_value_t = 0.0)]
+    p: f64,
+
+    /// Apply two-qubit depolarizing noise after each CNOT and no single-qubit noise
+    #[arg(long)]
+    noise_2q_only: bool,
+
+    /// Separate seed for the noise draws (default: use --seed for both)
+    #[arg(long)]
+    noise_seed: Option<String>,
+}
+
+/// Run the sweep described by `args` (the body of the standalone binary).
+pub fn run(args: &Args) {
+    if args.depth_step == 0 {
+        eprintln!("depth_step must be > 0");
+        std::process::exit(1);
+    }
+
+    let max_bonds = parse_list(&args.max_bond);
+    if max_bonds.is_empty() {
+        eprintln!("max_bond must contain at least one integer value");
+        std::process::exit(1);
+    }
+
+    if args.dry_run {
+        run_dry(args, &max_bonds);
+        return;
+    }
+
+    let noise = SweepNoise::from_flags(args.p, args.noise_2q_only);
+    let noise_seed = args.noise_seed.as_deref().unwrap_or(&args.seed);
+    let noise_ctx = SeedContext::new("chi-sweep-noise", noise_seed.as_bytes());
+    let mut rows: Vec<(usize, usize, usize, f64)> = Vec::new();
+
+    for &max_bond in &max_bonds {
+        let trunc = Truncation::builder().max_bond(max_bond).cutoff(args.cutoff).build();
+        let mut rng = ONDRng::new(args.seed.as_bytes());
+        // Noise draws get their own stream, so the circuit angles match the noiseless run.
+        let mut noise_rng = noise_ctx.rng(&[]);
+        let mut psi = MPS::new_zero(args.n);
+
+        let mut depth = 0usize;
+        let mut step = args.depth_step;
+        let mut last_entropy: Option<f64> = None;
+        let mut saturated = false;
+        while depth < args.depth_max {
+            let layers = (args.depth_max - depth).min(step);
+            let start = Instant::now();
+            for _ in 0..layers {
+                let layer = build_layer_params(args.n, &mut rng, args.angle_bits);
+                let hit = match noise {
+                    Some(noise) => {
+                        apply_noisy_layer_params(&mut psi, trunc, &layer, noise, &mut noise_rng)
+                    }
+                    None => apply_layer_params(&mut psi, trunc, &layer),
+                };
+                depth += 1;
+                if hit && !saturated {
+                    saturated = true;
+                    eprintln!(
+                        "max_bond={}: truncation saturated at depth {}",
+                        max_bond, depth
+                    );
+                }
+            }
+            let elapsed = start.elapsed().as_secs_f64();
+            let layer_ms = (elapsed / layers as f64) * 1000.0;
+            let chi = chi_max(&psi);
+
+            rows.push((max_bond, depth, chi, layer_ms));
+            println!(
+                "max_bond={} depth={} chi_max={} layer_ms={:.3}",
+                max_bond, depth, chi, layer_ms
+            );
+
+            if args.adaptive && args.n > 1 {
+                // Double the interval while the entropy is flat; snap back once it moves again.
+                let entropy = psi.entanglement_entropy(args.n / 2);
+                step = match last_entropy {
+                    Some(prev) if (entropy - prev).abs() < args.entropy_tol => step * 2,
+                    _ => args.depth_step,
+                };
+                last_entropy = Some(entropy);
+            }
+        }
+    }
+
+    write_csv(args, &rows);
+    write_meta(&args.out, args).expect("failed to write meta sidecar");
+}
+
+fn run_dry(args: &Args, max_bonds: &[usize]) {
+    // Each brickwork layer applies one random 2q block (6 rotations + CNOT) per bond.
+    let bonds = args.n.saturating_sub(1);
+    let gates_2q = args.depth_max * bonds;
+    let gates_1q = 6 * gates_2q;
+
+    for &max_bond in max_bonds {
+        let half = (args.n / 2).min(usize::BITS as usize - 2);
+        let chi = max_bond.min(1usize << half);
+        let bytes = MPS::memory_bytes_bound(args.n, max_bond);
+        println!(
+            "dry-run max_bond={} chi_worst={} memory_bytes={} ({:.2} MiB) gates_1q={} gates_2q={}",
+            max_bond,
+            chi,
+            bytes,
+            bytes as f64 / (1024.0 * 1024.0),
+            gates_1q,
+            gates_2q
+        );
+    }
+    println!(
+        "dry-run total: {} sweeps, {} two-qubit gates",
+        max_bonds.len(),
+        gates_2q * max_bonds.len()
+    );
+}
+
+fn write_csv(args: &Args, rows: &[(usize, usize, usize, f64)]) {
+    let header = "max_bond,depth,chi_max,layer_ms";
+    let mut w = if args.gzip {
+        CsvAppender::create_gz(&gz_path(&args.out), header)
+    } else {
+        CsvAppender::create(&args.out, header)
+    }
+    .expect("failed to create CSV file");
+    for (max_bond, depth, chi, layer_ms) in rows {
+        w.write_row(&format!("{},{},{},{}", max_bond, depth, chi, layer_ms))
+            .expect("failed to write row");
+    }
+    w.finish().expect("failed to finish CSV file");
+}
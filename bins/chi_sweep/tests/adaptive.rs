@@ -0,0 +1,28 @@
+use std::process::Command;
+
+fn csv_rows(extra: &[&str], tag: &str) -> usize {
+    let out = std::env::temp_dir().join(format!("chi_{}_{}.csv", tag, std::process::id()));
+    let status = Command::new(env!("CARGO_BIN_EXE_chi_sweep"))
+        .args(["--n", "8", "--depth-max", "60", "--depth-step", "2", "--max-bond", "16"])
+        .args(extra)
+        .arg("--out")
+        .arg(&out)
+        .output()
+        .expect("failed to run chi_sweep")
+        .status;
+    assert!(status.success());
+
+    let text = std::fs::read_to_string(&out).unwrap();
+    let _ = std::fs::remove_file(&out);
+    let _ = std::fs::remove_file(format!("{}.meta.json", out.display()));
+    text.lines().count() - 1
+}
+
+#[test]
+fn adaptive_mode_samples_steady_state_less() {
+    let fixed = csv_rows(&[], "fixed");
+    let adaptive = csv_rows(&["--adaptive", "--entropy-tol", "0.2"], "adaptive");
+
+    assert_eq!(fixed, 30);
+    assert!(adaptive < fixed, "adaptive {} vs fixed {}", adaptive, fixed);
+}
@@ -0,0 +1,27 @@
+use std::process::Command;
+
+#[test]
+fn dry_run_reports_memory_without_writing_csv() {
+    let out = std::env::temp_dir().join(format!("chi_dry_run_{}.csv", std::process::id()));
+    let _ = std::fs::remove_file(&out);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_chi_sweep"))
+        .args(["--n", "40", "--depth-max", "10", "--max-bond", "16,64", "--dry-run"])
+        .arg("--out")
+        .arg(&out)
+        .output()
+        .expect("failed to run chi_sweep");
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let bytes: Vec<usize> = stdout
+        .split_whitespace()
+        .filter_map(|tok| tok.strip_prefix("memory_bytes="))
+        .map(|v| v.parse().unwrap())
+        .collect();
+    assert_eq!(bytes.len(), 2, "{}", stdout);
+    assert!(bytes.iter().all(|&b| b > 0));
+    assert!(bytes[1] > bytes[0]);
+
+    assert!(!out.exists(), "dry run must not create the CSV");
+}
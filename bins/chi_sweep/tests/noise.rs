@@ -0,0 +1,60 @@
+use std::process::Command;
+
+/// Runs the sweep and returns column `col` of every data row.
+fn column(extra: &[&str], tag: &str, col: usize) -> Vec<String> {
+    let out = std::env::temp_dir().join(format!("chi_noise_{}_{}.csv", tag, std::process::id()));
+    let status = Command::new(env!("CARGO_BIN_EXE_chi_sweep"))
+        .args(["--n", "8", "--depth-step", "1"])
+        .args(["--max-bond", "4", "--cutoff", "1e-2"])
+        .args(extra)
+        .arg("--out")
+        .arg(&out)
+        .output()
+        .expect("failed to run chi_sweep")
+        .status;
+    assert!(status.success());
+
+    let text = std::fs::read_to_string(&out).unwrap();
+    let _ = std::fs::remove_file(&out);
+    let _ = std::fs::remove_file(format!("{}.meta.json", out.display()));
+    text.lines()
+        .skip(1)
+        .map(|l| l.split(',').nth(col).unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn noise_2q_only_at_zero_matches_noiseless_run() {
+    let clean = column(&["--depth-max", "12"], "clean", 2);
+    let zero = ["--depth-max", "12", "--noise-2q-only", "--p", "0"];
+    let zero = column(&zero, "zero", 2);
+    assert_eq!(clean, zero);
+    assert_eq!(clean.len(), 12);
+}
+
+#[test]
+fn noise_2q_only_changes_the_state_reproducibly() {
+    // chi saturates either way, but the adaptive depth grid follows the mid-chain
+    // entropy, which the Pauli kicks change.
+    let adaptive = ["--depth-max", "40", "--adaptive", "--entropy-tol", "0.05"];
+    let noisy = [&adaptive[..], &["--noise-2q-only", "--p", "0.2"]].concat();
+
+    let clean = column(&adaptive, "adaptive_clean", 1);
+    let first = column(&noisy, "adaptive_noisy_a", 1);
+    let second = column(&noisy, "adaptive_noisy_b", 1);
+    assert_ne!(clean, first);
+    assert_eq!(first, second);
+}
+
+#[test]
+fn noise_seed_resamples_noise_with_the_circuit_fixed() {
+    let noisy = ["--depth-max", "40", "--adaptive", "--noise-2q-only", "--p", "0.2"];
+    let with_seed = |tag: &str, seed: &str| {
+        column(&[&noisy[..], &["--noise-seed", seed]].concat(), tag, 1)
+    };
+
+    // Without --noise-seed the noise comes from --seed (default "chi-sweep").
+    let default = column(&noisy, "seed_default", 1);
+    assert_eq!(with_seed("seed_same", "chi-sweep"), default);
+    assert_ne!(with_seed("seed_other", "other-noise"), default);
+}
@@ -9,9 +9,16 @@ use quantum::{
     observables::{expect_z, expect_zz},
 };
 use rng::ONDRng;
-use simulator::{benchmark, noisy_vqe_sweep, vqe_sweep, vqe_sweep_shots, vqe_sweep_steps};
+use simulator::{
+    benchmark, noisy_vqe_sweep,
+    scaling::{circuit_scaling, thread_scaling, write_scaling_csv},
+    vqe_sweep, vqe_sweep_shots, vqe_sweep_steps,
+};
 use tn::{mps::MPS, truncation::Truncation};
 
+/// Haar brickwork layers for the built-in benchmark; about half a second at n = 40.
+const BENCH_DEPTH: usize = 8;
+
 /// Quantum MPS Emulator (OND-RNG)
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -40,6 +47,10 @@ struct Args {
     #[arg(long, default_value = "default-seed")]
     seed: String,
 
+    /// Separate seed for the noise channels of noisy VQE (default: use --seed for both)
+    #[arg(long)]
+    noise_seed: Option<String>,
+
     /// Number of Rayon worker threads (0 = Rayon default)
     #[arg(long, default_value_t = 0)]
     threads: usize,
@@ -47,6 +58,15 @@ struct Args {
     /// Run MPS benchmark
     #[arg(long)]
     benchmark: bool,
+
+    /// Time the noisy VQE at each of these thread counts (e.g. 1,2,4,8) and write vqe_scaling.csv
+    #[arg(long, value_delimiter = ',')]
+    scaling: Vec<usize>,
+
+    /// With --scaling, time one shared Haar brickwork circuit (n = 16, depth 16) per
+    /// trajectory instead, and write circuit_scaling.csv
+    #[arg(long, requires = "scaling")]
+    scaling_circuits: bool,
 }
 
 #[derive(ValueEnum, Clone, Debug)]
@@ -59,6 +79,34 @@ enum Mode {
 fn main() {
     let args = Args::parse();
 
+    if !args.scaling.is_empty() {
+        let (rows, out) = if args.scaling_circuits {
+            let trunc = Truncation::builder().max_bond(32).build();
+            let rows = circuit_scaling(&args.scaling, 16, 16, args.trajectories, trunc, &args.seed);
+            (rows, "circuit_scaling.csv")
+        } else {
+            let rows = thread_scaling(
+                &args.scaling,
+                args.theta_steps,
+                args.trajectories,
+                args.shots,
+                args.p,
+                &args.seed,
+            );
+            (rows, "vqe_scaling.csv")
+        };
+        for r in &rows {
+            println!(
+                "threads = {:>2} → {:.3} s (speedup {:.2}x)",
+                r.threads, r.seconds, r.speedup
+            );
+        }
+        if let Err(err) = write_scaling_csv(out, &rows) {
+            eprintln!("Failed to write CSV to {}: {}", out, err);
+        }
+        return;
+    }
+
     if args.threads > 0 {
         rayon::ThreadPoolBuilder::new()
             .num_threads(args.threads)
@@ -69,10 +117,7 @@ fn main() {
     // --------------------------------------------------
     // Demo state: Bell pair (UNCHANGED default behavior)
     // --------------------------------------------------
-    let trunc = Truncation {
-        max_bond: 64,
-        cutoff: 1e-8,
-    };
+    let trunc = Truncation::default();
 
     let mut rng = ONDRng::new(args.seed.as_bytes());
     let mut psi = MPS::new_zero(2);
@@ -97,21 +142,22 @@ fn main() {
     // --------------------------------------------------
     match args.mode {
         None => {
-            benchmark(40, 80);
+            benchmark(40, BENCH_DEPTH);
             vqe_sweep();
             vqe_sweep_shots(60, 50, &args.seed);
-            noisy_vqe_sweep(40, 5, 50, 0.01, &args.seed);
+            let noise_seed = args.noise_seed.as_deref();
+            noisy_vqe_sweep(40, 5, 50, 0.01, &args.seed, noise_seed);
         }
         Some(Mode::Analytic) => {
             vqe_sweep_steps(args.theta_steps);
             if args.benchmark {
-                benchmark(40, 80);
+                benchmark(40, BENCH_DEPTH);
             }
         }
         Some(Mode::Shots) => {
             vqe_sweep_shots(args.theta_steps, args.shots, &args.seed);
             if args.benchmark {
-                benchmark(40, 80);
+                benchmark(40, BENCH_DEPTH);
             }
         }
         Some(Mode::Noisy) => {
@@ -121,9 +167,10 @@ fn main() {
                 args.shots,
                 args.p,
                 &args.seed,
+                args.noise_seed.as_deref(),
             );
             if args.benchmark {
-                benchmark(40, 80);
+                benchmark(40, BENCH_DEPTH);
             }
         }
     }
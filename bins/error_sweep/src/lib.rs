@@ -0,0 +1,245 @@
+use clap::Parser;
+use quantum::{
+    apply_cnot,
+    energy::{energy_diagonal_fast, energy_heisenberg},
+    gates::hadamard,
+    hamiltonian::{Hamiltonian, Heisenberg},
+};
+use serde::Serialize;
+use simulator::{
+    meta::write_meta,
+    output::{gz_path, CsvAppender},
+};
+use sweep_common::{build_state, parse_list};
+use tn::{mps::MPS, truncation::Truncation};
+
+pub enum HMode {
+    Ising(Hamiltonian),
+    Heisenberg(Heisenberg),
+}
+
+impl HMode {
+    pub fn energy(&self, psi: &MPS) -> f64 {
+        match self {
+            HMode::Ising(h) => energy_diagonal_fast(psi, h),
+            HMode::Heisenberg(h) => energy_heisenberg(psi, h),
+        }
+    }
+}
+
+#[derive(Parser, Debug, Serialize)]
+#[command(author, version, about = "MPS energy error sweep vs bond dimension")]
+pub struct Args {
+    /// Number of qubits
+    #[arg(long, default_value_t = 40)]
+    n: usize,
+
+    /// Circuit depth (brickwork layers)
+    #[arg(long, default_value_t = 50)]
+    depth: usize,
+
+    /// Comma-separated list of test bond dimensions
+    #[arg(long, default_value = "8,16,32")]
+    chi_test: String,
+
+    /// Reference bond dimension
+    #[arg(long, default_value_t = 128)]
+    chi_ref: usize,
+
+    /// Optional check bond dimension for reference convergence (0 = disabled)
+    #[arg(long, default_value_t = 0)]
+    chi_ref_check: usize,
+
+    /// Hamiltonian: ising | heisenberg
+    #[arg(long, default_value = "heisenberg")]
+    h: String,
+
+    /// Heisenberg coupling Jx (only used when --h heisenberg)
+    #[arg(long, default_value_t = 1.0)]
+    heisenberg_jx: f64,
+
+    /// Heisenberg coupling Jy (only used when --h heisenberg)
+    #[arg(long, default_value_t = 1.0)]
+    heisenberg_jy: f64,
+
+    /// Heisenberg coupling Jz (only used when --h heisenberg)
+    #[arg(long, default_value_t = 1.0)]
+    heisenberg_jz: f64,
+
+    /// Run Bell-state sanity check for Heisenberg energy and exit
+    #[arg(long)]
+    sanity: bool,
+
+    /// SVD cutoff
+    #[arg(long, default_value_t = 1e-8)]
+    cutoff: f64,
+
+    /// RNG seed
+    #[arg(long, default_value = "err-40")]
+    seed: String,
+
+    /// Output CSV path
+    #[arg(long, default_value = "error_sweep.csv")]
+    out: String,
+
+    /// Append to an existing --out with the same header instead of overwriting it
+    #[arg(long)]
+    append: bool,
+
+    /// Write gzip-compressed `<out>.gz` instead of plain CSV
+    #[arg(long, conflicts_with = "append")]
+    gzip: bool,
+
+    /// Round circuit angles down to this many bits of a turn (exact binary fractions)
+    #[arg(long)]
+    angle_bits: Option<u32>,
+}
+
+/// Run the sweep described by `args` (the body of the standalone binary).
+pub fn run(args: &Args) {
+    if args.sanity {
+        run_sanity(args);
+        return;
+    }
+
+    let chi_test = parse_list(&args.chi_test);
+    if chi_test.is_empty() {
+        eprintln!("chi_test must contain at least one integer value");
+        std::process::exit(1);
+    }
+
+    if args.chi_ref_check > 0 && args.chi_ref_check <= args.chi_ref {
+        eprintln!(
+            "ERROR: --chi-ref-check ({}) must be > --chi-ref ({})",
+            args.chi_ref_check, args.chi_ref
+        );
+        std::process::exit(1);
+    }
+
+    let h_mode = match args.h.as_str() {
+        "ising" => HMode::Ising(Hamiltonian::ising(args.n, 0.0, 1.0)),
+        "heisenberg" => {
+            let bonds = args.n.saturating_sub(1);
+            HMode::Heisenberg(Heisenberg {
+                jx: vec![args.heisenberg_jx; bonds],
+                jy: vec![args.heisenberg_jy; bonds],
+                jz: vec![args.heisenberg_jz; bonds],
+            })
+        }
+        other => {
+            eprintln!("ERROR: --h must be 'ising' or 'heisenberg', got '{}'", other);
+            std::process::exit(1);
+        }
+    };
+
+    let build = |max_bond| {
+        let trunc = Truncation::builder().max_bond(max_bond).cutoff(args.cutoff).build();
+        build_state(args.n, args.depth, trunc, &args.seed, args.angle_bits)
+    };
+
+    if args.chi_ref_check > 0 {
+        let e_ref = h_mode.energy(&build(args.chi_ref));
+        let e_check = h_mode.energy(&build(args.chi_ref_check));
+        let diff = (e_ref - e_check).abs();
+        const REF_TOL: f64 = 1e-6;
+        if diff > REF_TOL {
+            eprintln!(
+                "WARNING: reference not converged: |E({}) - E({})| = {:.3e}",
+                args.chi_ref, args.chi_ref_check, diff
+            );
+        }
+    }
+
+    let header = "chi,energy,error_energy";
+    let mut w = if args.gzip {
+        CsvAppender::create_gz(&gz_path(&args.out), header)
+    } else if args.append {
+        CsvAppender::append(&args.out, header)
+    } else {
+        CsvAppender::create(&args.out, header)
+    }
+    .expect("failed to open CSV file");
+
+    let rows = error_rows(&chi_test, args.chi_ref, &h_mode, build);
+    for (chi, e, err) in rows {
+        w.write_row(&format!("{},{},{}", chi, e, err))
+            .expect("failed to write row");
+        println!("chi={}  E={}  |dE|={:.3e}", chi, e, err);
+    }
+    w.finish().expect("failed to finish CSV file");
+
+    write_meta(&args.out, args).expect("failed to write meta sidecar");
+}
+
+fn run_sanity(args: &Args) {
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-12).build();
+
+    let mut psi = MPS::new_zero(2);
+    psi.apply_1q(0, hadamard());
+    apply_cnot(&mut psi, 0, trunc);
+
+    let h = Heisenberg {
+        jx: vec![args.heisenberg_jx],
+        jy: vec![args.heisenberg_jy],
+        jz: vec![args.heisenberg_jz],
+    };
+    let e = energy_heisenberg(&psi, &h);
+    let expected = args.heisenberg_jx - args.heisenberg_jy + args.heisenberg_jz;
+    let err = (e - expected).abs();
+
+    assert!(
+        err < 1e-12,
+        "Sanity check failed: E={} expected={} err={}",
+        e,
+        expected,
+        err
+    );
+    println!("Sanity OK: E = {}", e);
+}
+
+/// (chi, energy, |energy - reference|) for each test bond dimension.
+///
+/// The reference energy comes from the same circuit and seed truncated at `chi_ref`.
+pub fn error_sweep(
+    n: usize,
+    depth: usize,
+    chi_test: &[usize],
+    chi_ref: usize,
+    cutoff: f64,
+    seed: &str,
+    h: &HMode,
+) -> Vec<(usize, f64, f64)> {
+    let build = |max_bond| {
+        let trunc = Truncation::builder().max_bond(max_bond).cutoff(cutoff).build();
+        build_state(n, depth, trunc, seed, None)
+    };
+    error_rows(chi_test, chi_ref, h, build).collect()
+}
+
+/// `error_sweep` as a lazy iterator over states from `build(max_bond)`: the reference
+/// runs up front, each chi on demand.
+pub fn error_rows<'a, F>(
+    chi_test: &'a [usize],
+    chi_ref: usize,
+    h: &'a HMode,
+    build: F,
+) -> impl Iterator<Item = (usize, f64, f64)> + 'a
+where
+    F: Fn(usize) -> MPS + 'a,
+{
+    let e_ref = h.energy(&build(chi_ref));
+    chi_test.iter().map(move |&chi| {
+        let e = h.energy(&build(chi));
+        (chi, e, (e - e_ref).abs())
+    })
+}
+
+pub fn run_energy(
+    n: usize,
+    depth: usize,
+    trunc: Truncation,
+    seed: &str,
+    h: &HMode,
+) -> f64 {
+    h.energy(&build_state(n, depth, trunc, seed, None))
+}
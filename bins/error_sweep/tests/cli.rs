@@ -0,0 +1,37 @@
+use std::process::Command;
+
+fn scratch_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("error_sweep_{}_{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// The `error_energy` column of a small sweep run with `extra` flags.
+fn run_sweep(dir: &std::path::Path, out: &str, extra: &[&str]) -> Vec<f64> {
+    let output = Command::new(env!("CARGO_BIN_EXE_error_sweep"))
+        .args(["--n", "6", "--depth", "4", "--chi-test", "2,4", "--chi-ref", "8"])
+        .args(extra)
+        .args(["--out", out])
+        .current_dir(dir)
+        .output()
+        .expect("failed to run error_sweep");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let text = std::fs::read_to_string(dir.join(out)).unwrap();
+    assert_eq!(text.lines().next(), Some("chi,energy,error_energy"));
+    text.lines()
+        .skip(1)
+        .map(|l| l.split(',').nth(2).unwrap().parse().unwrap())
+        .collect()
+}
+
+#[test]
+fn one_angle_bit_gives_a_clifford_circuit_with_no_truncation_error() {
+    let dir = scratch_dir("angle_bits");
+    let full = run_sweep(&dir, "full.csv", &[]);
+    assert!(full.iter().any(|&e| e > 1e-6), "{:?}", full);
+
+    // One bit leaves only 0 and π: Paulis and CNOTs keep a basis state at bond dimension 1.
+    let clifford = run_sweep(&dir, "clifford.csv", &["--angle-bits", "1"]);
+    assert_eq!(clifford, vec![0.0, 0.0]);
+}
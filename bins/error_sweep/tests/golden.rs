@@ -0,0 +1,43 @@
+use error_sweep::{error_sweep, HMode};
+use quantum::hamiltonian::{Hamiltonian, Heisenberg};
+
+const TOL: f64 = 1e-9;
+
+fn assert_rows(rows: &[(usize, f64, f64)], expected: &[(usize, f64, f64)]) {
+    assert_eq!(rows.len(), expected.len());
+    for (row, exp) in rows.iter().zip(expected) {
+        assert_eq!(row.0, exp.0);
+        assert!((row.1 - exp.1).abs() < TOL, "chi={} E={} expected {}", row.0, row.1, exp.1);
+        assert!((row.2 - exp.2).abs() < TOL, "chi={} dE={} expected {}", row.0, row.2, exp.2);
+    }
+}
+
+#[test]
+fn heisenberg_sweep_matches_golden_values() {
+    let h = HMode::Heisenberg(Heisenberg::uniform(8, 1.0));
+    let rows = error_sweep(8, 6, &[2, 4, 8], 16, 1e-12, "golden", &h);
+
+    assert_rows(
+        &rows,
+        &[
+            (2, 5.006396685272132e-2, 5.221209552136743e-1),
+            (4, -6.626090550490962e-1, 1.905520666881432e-1),
+            (8, -5.258525910990164e-1, 5.379560273806344e-2),
+        ],
+    );
+}
+
+#[test]
+fn ising_sweep_matches_golden_values() {
+    let h = HMode::Ising(Hamiltonian::ising(8, 0.0, 1.0));
+    let rows = error_sweep(8, 6, &[2, 4, 8], 16, 1e-12, "golden", &h);
+
+    assert_rows(
+        &rows,
+        &[
+            (2, 1.438446198093401e-1, 3.917625694044962e-1),
+            (4, -1.316847984624798e-1, 1.162331511326763e-1),
+            (8, -2.465747500730116e-1, 1.343199522144428e-3),
+        ],
+    );
+}
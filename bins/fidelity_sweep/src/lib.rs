@@ -0,0 +1,346 @@
+use clap::Parser;
+use rng::ONDRng;
+use simulator::output::{gz_path, CsvAppender};
+use sweep_common::{apply_layer_params, build_layer_params, build_state, parse_list};
+use tn::{mps::fidelity, mps::MPS, truncation::Truncation};
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+const DEPTH_HEADER: &str = "depth,chi,fidelity,one_minus_fidelity";
+
+/// Default `--self-tol`.
+pub const DEFAULT_SELF_TOL: f64 = 1e-8;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about = "MPS fidelity sweep vs bond dimension (n <= 30)")]
+pub struct Args {
+    /// Number of qubits (recommended <= 30)
+    #[arg(long, default_value_t = 24)]
+    n: usize,
+
+    /// Circuit depth (brickwork layers)
+    #[arg(long, default_value_t = 30)]
+    depth: usize,
+
+    /// Sweep depth from 1..=depth and output a depth x chi surface
+    #[arg(long)]
+    depth_sweep: bool,
+
+    /// With --depth-sweep, run each test chi in its own pass from the same seed so only
+    /// one test MPS is resident; with --append, chi values already written are resumed
+    #[arg(long, requires = "depth_sweep")]
+    per_chi: bool,
+
+    /// Depth step for --depth-sweep
+    #[arg(long, default_value_t = 1)]
+    depth_step: usize,
+
+    /// Start depth for --depth-sweep (inclusive)
+    #[arg(long, default_value_t = 1)]
+    depth_start: usize,
+
+    /// End depth for --depth-sweep (inclusive). 0 = use --depth
+    #[arg(long, default_value_t = 0)]
+    depth_end: usize,
+
+    /// Comma-separated list of test bond dimensions
+    #[arg(long, default_value = "4,8,16,32")]
+    chi_test: String,
+
+    /// Reference bond dimension
+    #[arg(long, default_value_t = 64)]
+    chi_ref: usize,
+
+    /// Largest 1 - fidelity accepted when a test bond dimension equals chi_ref
+    #[arg(long, default_value_t = DEFAULT_SELF_TOL)]
+    self_tol: f64,
+
+    /// SVD cutoff
+    #[arg(long, default_value_t = 1e-8)]
+    cutoff: f64,
+
+    /// RNG seed
+    #[arg(long, default_value = "fid-24")]
+    seed: String,
+
+    /// Output CSV path
+    #[arg(long, default_value = "fidelity_sweep.csv")]
+    out: String,
+
+    /// Append to an existing output file with the same header instead of overwriting it
+    #[arg(long)]
+    append: bool,
+
+    /// Write gzip-compressed `<out>.gz` instead of plain CSV
+    #[arg(long, conflicts_with = "append")]
+    gzip: bool,
+
+    /// Also write the reference state's half-chain Schmidt values to this CSV
+    #[arg(long)]
+    spectrum_out: Option<String>,
+
+    /// Round circuit angles down to this many bits of a turn (exact binary fractions)
+    #[arg(long)]
+    angle_bits: Option<u32>,
+}
+
+/// Run the sweep described by `args` (the body of the standalone binary).
+pub fn run(args: &Args) {
+    if args.depth_step == 0 {
+        eprintln!("depth_step must be > 0");
+        std::process::exit(1);
+    }
+
+    let depth_end = if args.depth_end == 0 {
+        args.depth
+    } else {
+        args.depth_end
+    };
+    if args.depth_sweep {
+        if args.depth_start == 0 {
+            eprintln!("depth_start must be >= 1");
+            std::process::exit(1);
+        }
+        if depth_end < args.depth_start {
+            eprintln!(
+                "depth_end must be >= depth_start ({} < {})",
+                depth_end, args.depth_start
+            );
+            std::process::exit(1);
+        }
+    }
+
+    if args.n > 30 {
+        eprintln!("WARNING: fidelity sweep is intended for n <= 30 (got n={})", args.n);
+    }
+
+    let chi_test = parse_list(&args.chi_test);
+    if chi_test.is_empty() {
+        eprintln!("chi_test must contain at least one integer value");
+        std::process::exit(1);
+    }
+
+    let max_test = *chi_test.iter().max().unwrap_or(&0);
+    if args.chi_ref <= max_test {
+        eprintln!(
+            "WARNING: chi_ref ({}) should be > max chi_test ({})",
+            args.chi_ref, max_test
+        );
+    }
+
+    if args.depth_sweep {
+        let depth_out = depth_output_path(&args.out);
+        println!("depth-sweep output: {}", depth_out.display());
+        let depth_out = depth_out.to_str().expect("output path must be UTF-8");
+        let done = if args.append && !args.gzip {
+            completed_depths(depth_out)
+        } else {
+            BTreeMap::new()
+        };
+        let mut w = open_csv(depth_out, DEPTH_HEADER, args);
+
+        let psi_ref = if args.per_chi {
+            // One streaming pass per chi, so fully written chis are skipped outright.
+            let mut psi_ref = None;
+            for &chi in &chi_test {
+                if done.get(&chi) == Some(&depth_end) {
+                    println!("chi={}  already complete, skipping", chi);
+                    continue;
+                }
+                psi_ref = Some(depth_sweep_pass(args, &[chi], depth_end, &mut w, &done));
+            }
+            psi_ref
+        } else {
+            Some(depth_sweep_pass(args, &chi_test, depth_end, &mut w, &done))
+        };
+        w.finish().expect("failed to finish CSV file");
+        if let Some(path) = &args.spectrum_out {
+            // Every chi was already on disk, but the spectrum still needs the reference.
+            let psi_ref = psi_ref.unwrap_or_else(|| {
+                build_state(
+                    args.n,
+                    depth_end,
+                    Truncation::builder().max_bond(args.chi_ref).cutoff(args.cutoff).build(),
+                    &args.seed,
+                    args.angle_bits,
+                )
+            });
+            write_spectrum(&psi_ref, path);
+        }
+    } else {
+        let psi_ref = build_state(
+            args.n,
+            args.depth,
+            Truncation::builder().max_bond(args.chi_ref).cutoff(args.cutoff).build(),
+            &args.seed,
+            args.angle_bits,
+        );
+        if let Some(path) = &args.spectrum_out {
+            write_spectrum(&psi_ref, path);
+        }
+
+        let mut w = open_csv(&args.out, "chi,fidelity,one_minus_fidelity", args);
+        for &chi in &chi_test {
+            let psi = build_state(
+                args.n,
+                args.depth,
+                Truncation::builder().max_bond(chi).cutoff(args.cutoff).build(),
+                &args.seed,
+                args.angle_bits,
+            );
+
+            let fid = fidelity(&psi, &psi_ref);
+            let one_minus = 1.0 - fid;
+
+            self_check(chi, args.chi_ref, one_minus, args.self_tol);
+
+            w.write_row(&format!("{},{},{}", chi, fid, one_minus))
+                .expect("failed to write row");
+            println!("chi={}  1-fidelity={:.3e}", chi, one_minus);
+        }
+        w.finish().expect("failed to finish CSV file");
+    }
+}
+
+/// Advance the reference and one test state per entry of `chis` layer by layer from the
+/// seed, writing a row per chi at every sampled depth; returns the final reference.
+///
+/// Rows for depths at or below `done[chi]` were written by an earlier run and are skipped.
+fn depth_sweep_pass(
+    args: &Args,
+    chis: &[usize],
+    depth_end: usize,
+    w: &mut CsvAppender,
+    done: &BTreeMap<usize, usize>,
+) -> MPS {
+    let mut rng = ONDRng::new(args.seed.as_bytes());
+    let trunc_ref = Truncation::builder().max_bond(args.chi_ref).cutoff(args.cutoff).build();
+    let truncs: Vec<Truncation> = chis
+        .iter()
+        .map(|&chi| Truncation::builder().max_bond(chi).cutoff(args.cutoff).build())
+        .collect();
+
+    let mut psi_ref = MPS::new_zero(args.n);
+    let mut psi_tests: Vec<MPS> = chis.iter().map(|_| MPS::new_zero(args.n)).collect();
+    let mut saturated = vec![false; chis.len()];
+
+    let mut depth = 0usize;
+    while depth < depth_end {
+        let layer = build_layer_params(args.n, &mut rng, args.angle_bits);
+
+        apply_layer_params(&mut psi_ref, trunc_ref, &layer);
+        depth += 1;
+        for (idx, (psi, &trunc)) in psi_tests.iter_mut().zip(truncs.iter()).enumerate() {
+            if apply_layer_params(psi, trunc, &layer) && !saturated[idx] {
+                saturated[idx] = true;
+                eprintln!("chi={}: truncation saturated at depth {}", chis[idx], depth);
+            }
+        }
+
+        if depth < args.depth_start {
+            continue;
+        }
+        if (depth - args.depth_start).is_multiple_of(args.depth_step) || depth == depth_end {
+            let mut rows = 0;
+            for (idx, &chi) in chis.iter().enumerate() {
+                if done.get(&chi).is_some_and(|&d| depth <= d) {
+                    continue;
+                }
+                let fid = fidelity(&psi_tests[idx], &psi_ref);
+                let one_minus = 1.0 - fid;
+
+                self_check(chi, args.chi_ref, one_minus, args.self_tol);
+
+                w.write_row(&format!("{},{},{},{}", depth, chi, fid, one_minus))
+                    .expect("failed to write row");
+                rows += 1;
+            }
+            println!("depth={}  wrote {} rows", depth, rows);
+        }
+    }
+    psi_ref
+}
+
+/// Deepest row already written for each chi in an existing depth-sweep CSV.
+fn completed_depths(path: &str) -> BTreeMap<usize, usize> {
+    let mut done = BTreeMap::new();
+    let Ok(text) = std::fs::read_to_string(path) else {
+        return done;
+    };
+    for line in text.lines().skip(1) {
+        let mut cols = line.split(',');
+        let (Some(Ok(depth)), Some(Ok(chi))) = (
+            cols.next().map(str::parse::<usize>),
+            cols.next().map(str::parse::<usize>),
+        ) else {
+            continue;
+        };
+        let d = done.entry(chi).or_insert(0);
+        *d = (*d).max(depth);
+    }
+    done
+}
+
+fn open_csv(path: &str, header: &str, args: &Args) -> CsvAppender {
+    if args.gzip {
+        CsvAppender::create_gz(&gz_path(path), header)
+    } else if args.append {
+        CsvAppender::append(path, header)
+    } else {
+        CsvAppender::create(path, header)
+    }
+    .expect("failed to open CSV file")
+}
+
+/// Write the Schmidt values across the middle bond, so the fidelity-vs-chi curve can be
+/// read off as the weight beyond each test bond dimension.
+fn write_spectrum(psi: &MPS, path: &str) {
+    let bond = psi.sites().len() / 2;
+    let spectrum = psi.schmidt_values(bond);
+    let mut w = CsvAppender::create(path, "bond,index,singular_value,weight")
+        .expect("failed to open spectrum file");
+    for (i, s) in spectrum.iter().enumerate() {
+        w.write_row(&format!("{},{},{},{}", bond, i, s, s * s))
+            .expect("failed to write row");
+    }
+    w.finish().expect("failed to finish spectrum file");
+    println!("spectrum: bond {}  {} values -> {}", bond, spectrum.len(), path);
+}
+
+fn self_check(chi: usize, chi_ref: usize, one_minus: f64, tol: f64) {
+    if let Err(msg) = check_self_fidelity(chi, chi_ref, one_minus, tol) {
+        eprintln!("ERROR: {}", msg);
+        std::process::exit(1);
+    }
+}
+
+/// The reference run against itself must agree to within `tol`; other chi always pass.
+pub fn check_self_fidelity(
+    chi: usize,
+    chi_ref: usize,
+    one_minus: f64,
+    tol: f64,
+) -> Result<(), String> {
+    if chi == chi_ref && one_minus > tol {
+        return Err(format!(
+            "self-fidelity check failed for chi_ref={} (1-fidelity={:.3e} > self_tol={:e})",
+            chi_ref, one_minus, tol
+        ));
+    }
+    Ok(())
+}
+
+fn depth_output_path(out: &str) -> PathBuf {
+    let path = Path::new(out);
+    let stem = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("fidelity_sweep");
+    let ext = path.extension().and_then(|s| s.to_str()).unwrap_or("csv");
+    let file_name = format!("{stem}_depth.{ext}");
+    match path.parent() {
+        Some(parent) => parent.join(file_name),
+        None => PathBuf::from(file_name),
+    }
+}
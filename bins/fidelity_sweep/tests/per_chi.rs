@@ -0,0 +1,72 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("fid_{}_{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Runs a depth sweep in `dir` and returns the depth CSV's data rows, sorted.
+fn depth_rows(dir: &Path, extra: &[&str]) -> Vec<String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_fidelity_sweep"))
+        .args(["--n", "8", "--depth", "6", "--chi-test", "2,4,8", "--chi-ref", "16"])
+        .args(["--depth-sweep", "--depth-step", "2", "--seed", "per-chi"])
+        .args(["--out", "fid.csv"])
+        .args(extra)
+        .current_dir(dir)
+        .output()
+        .expect("failed to run fidelity_sweep");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let text = std::fs::read_to_string(dir.join("fid_depth.csv")).unwrap();
+    let mut lines = text.lines();
+    assert_eq!(lines.next(), Some("depth,chi,fidelity,one_minus_fidelity"));
+    let mut rows: Vec<String> = lines.map(str::to_string).collect();
+    rows.sort_by_key(|r| {
+        let mut cols = r.split(',').map(|v| v.parse::<usize>().unwrap_or(0));
+        (cols.next(), cols.next())
+    });
+    rows
+}
+
+#[test]
+fn per_chi_passes_match_all_at_once() {
+    let dir = scratch_dir("per_chi");
+    let together = depth_rows(&dir, &[]);
+    let separate = depth_rows(&dir, &["--per-chi"]);
+    assert_eq!(together.len(), 4 * 3);
+    assert_eq!(together, separate);
+
+    // Drop everything after chi=4's first row, then resume: the missing rows are rebuilt.
+    let path = dir.join("fid_depth.csv");
+    let text = std::fs::read_to_string(&path).unwrap();
+    let cut = text.find("\n1,4,").unwrap() + 1;
+    let keep = &text[..cut + text[cut..].find('\n').unwrap() + 1];
+    std::fs::write(&path, keep).unwrap();
+    let resumed = depth_rows(&dir, &["--per-chi", "--append"]);
+    assert_eq!(resumed, together);
+
+    // The all-at-once sweep resumes the same way.
+    std::fs::write(&path, keep).unwrap();
+    let resumed = depth_rows(&dir, &["--append"]);
+    assert_eq!(resumed, together);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn complete_per_chi_resume_still_writes_the_spectrum() {
+    let dir = scratch_dir("per_chi_spectrum");
+    let together = depth_rows(&dir, &["--spectrum-out", "first.csv"]);
+    let resumed = depth_rows(&dir, &["--per-chi", "--append", "--spectrum-out", "again.csv"]);
+    assert_eq!(resumed, together);
+
+    let first = std::fs::read_to_string(dir.join("first.csv")).unwrap();
+    let again = std::fs::read_to_string(dir.join("again.csv")).unwrap();
+    assert!(first.lines().count() > 1);
+    assert_eq!(again, first);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,13 @@
+use fidelity_sweep::{check_self_fidelity, DEFAULT_SELF_TOL};
+
+#[test]
+fn self_check_follows_the_configured_tolerance() {
+    let borderline = 5e-8;
+    let err = check_self_fidelity(64, 64, borderline, DEFAULT_SELF_TOL).unwrap_err();
+    assert!(err.contains("self_tol=1e-8"), "{}", err);
+    assert!(check_self_fidelity(64, 64, borderline, 1e-7).is_ok());
+    assert!(check_self_fidelity(64, 64, 1e-9, DEFAULT_SELF_TOL).is_ok());
+
+    // Only the chi == chi_ref row is checked.
+    assert!(check_self_fidelity(32, 64, 0.5, DEFAULT_SELF_TOL).is_ok());
+}
@@ -0,0 +1,41 @@
+use std::process::Command;
+use sweep_common::build_state;
+use tn::truncation::Truncation;
+
+#[test]
+fn spectrum_has_one_value_per_reference_bond_index() {
+    let (n, depth, chi_ref) = (8, 6, 16);
+    let dir = std::env::temp_dir();
+    let out = dir.join(format!("fid_spectrum_{}.csv", std::process::id()));
+    let spectrum = dir.join(format!("fid_spectrum_{}_schmidt.csv", std::process::id()));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_fidelity_sweep"))
+        .args(["--n", "8", "--depth", "6", "--chi-test", "2,4", "--chi-ref", "16"])
+        .args(["--seed", "spectrum-test", "--angle-bits", "16"])
+        .arg("--out")
+        .arg(&out)
+        .arg("--spectrum-out")
+        .arg(&spectrum)
+        .output()
+        .expect("failed to run fidelity_sweep");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let text = std::fs::read_to_string(&spectrum).unwrap();
+    let _ = std::fs::remove_file(&out);
+    let _ = std::fs::remove_file(&spectrum);
+
+    let rows: Vec<Vec<f64>> = text
+        .lines()
+        .skip(1)
+        .map(|l| l.split(',').map(|v| v.parse().unwrap()).collect())
+        .collect();
+
+    let trunc = Truncation::builder().max_bond(chi_ref).cutoff(1e-8).build();
+    let psi_ref = build_state(n, depth, trunc, "spectrum-test", Some(16));
+    assert_eq!(rows.len(), psi_ref.sites()[n / 2 - 1].dr);
+    assert!(rows.iter().all(|r| r[0] == (n / 2) as f64));
+
+    // The weights are the reference's Schmidt probabilities.
+    let total: f64 = rows.iter().map(|r| r[3]).sum();
+    assert!((total - 1.0).abs() < 1e-8, "total weight {}", total);
+}
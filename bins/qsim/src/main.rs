@@ -0,0 +1,144 @@
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use simulator::{
+    benchmark, noisy_vqe_sweep, noisy_vqe_sweep_flat, vqe_sweep_grid, vqe_sweep_shots, ThetaGrid,
+};
+
+/// Quantum MPS simulator: sweeps, VQE and benchmarks behind one command
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Bond-dimension growth vs depth (same flags as the chi_sweep binary)
+    ChiSweep(chi_sweep::Args),
+    /// Energy error vs bond dimension (same flags as the error_sweep binary)
+    ErrorSweep(error_sweep::Args),
+    /// Fidelity vs bond dimension (same flags as the fidelity_sweep binary)
+    FidelitySweep(fidelity_sweep::Args),
+    /// Two-qubit VQE theta sweep
+    Vqe(VqeArgs),
+    /// Time the shared Haar-random brickwork circuit
+    Benchmark(BenchmarkArgs),
+}
+
+#[derive(Args, Debug)]
+struct VqeArgs {
+    /// VQE mode: analytic | shots | noisy | noisy-flat
+    #[arg(long, value_enum, default_value_t = Mode::Analytic)]
+    mode: Mode,
+
+    /// Number of theta steps in VQE sweep
+    #[arg(long, default_value_t = 200)]
+    theta_steps: usize,
+
+    /// Theta grid placement for the analytic sweep: aligned | offset | interleaved
+    #[arg(long, value_enum, default_value_t = Grid::Aligned)]
+    theta_grid: Grid,
+
+    /// Number of shots for shot-based VQE
+    #[arg(long, default_value_t = 50)]
+    shots: usize,
+
+    /// Number of trajectories for noisy VQE
+    #[arg(long, default_value_t = 5)]
+    trajectories: usize,
+
+    /// Depolarizing noise probability
+    #[arg(long, default_value_t = 0.01)]
+    p: f64,
+
+    /// RNG seed (full reproducibility)
+    #[arg(long, default_value = "default-seed")]
+    seed: String,
+
+    /// Separate seed for the noise channels of noisy VQE (default: use --seed for both)
+    #[arg(long)]
+    noise_seed: Option<String>,
+
+    /// Number of Rayon worker threads (0 = Rayon default)
+    #[arg(long, default_value_t = 0)]
+    threads: usize,
+}
+
+#[derive(ValueEnum, Clone, Debug)]
+enum Mode {
+    Analytic,
+    Shots,
+    Noisy,
+    /// Noisy, parallelized over (theta, trajectory) pairs instead of trajectories only
+    NoisyFlat,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Grid {
+    Aligned,
+    /// Shifted by half a step
+    Offset,
+    /// Aligned and offset grids together
+    Interleaved,
+}
+
+impl From<Grid> for ThetaGrid {
+    fn from(g: Grid) -> Self {
+        match g {
+            Grid::Aligned => ThetaGrid::Aligned,
+            Grid::Offset => ThetaGrid::HalfOffset,
+            Grid::Interleaved => ThetaGrid::Interleaved,
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+struct BenchmarkArgs {
+    /// Number of qubits
+    #[arg(long, default_value_t = 40)]
+    n: usize,
+
+    /// Number of brickwork layers (each up to n/2 Haar two-qubit gates)
+    #[arg(long, default_value_t = 8)]
+    depth: usize,
+}
+
+fn main() {
+    match Cli::parse().command {
+        Command::ChiSweep(args) => chi_sweep::run(&args),
+        Command::ErrorSweep(args) => error_sweep::run(&args),
+        Command::FidelitySweep(args) => fidelity_sweep::run(&args),
+        Command::Vqe(args) => run_vqe(&args),
+        Command::Benchmark(args) => benchmark(args.n, args.depth),
+    }
+}
+
+fn run_vqe(args: &VqeArgs) {
+    if args.threads > 0 {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(args.threads)
+            .build_global()
+            .expect("Failed to build Rayon thread pool");
+    }
+
+    match args.mode {
+        Mode::Analytic => vqe_sweep_grid(args.theta_steps, args.theta_grid.into()),
+        Mode::Shots => vqe_sweep_shots(args.theta_steps, args.shots, &args.seed),
+        Mode::Noisy => noisy_vqe_sweep(
+            args.theta_steps,
+            args.trajectories,
+            args.shots,
+            args.p,
+            &args.seed,
+            args.noise_seed.as_deref(),
+        ),
+        Mode::NoisyFlat => noisy_vqe_sweep_flat(
+            args.theta_steps,
+            args.trajectories,
+            args.shots,
+            args.p,
+            &args.seed,
+            args.noise_seed.as_deref(),
+        ),
+    }
+}
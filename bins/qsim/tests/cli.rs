@@ -0,0 +1,72 @@
+use std::process::Command;
+
+fn scratch_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("qsim_{}_{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn vqe_analytic_writes_csv() {
+    let dir = scratch_dir("vqe");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_qsim"))
+        .args(["vqe", "--mode", "analytic", "--theta-steps", "8"])
+        .current_dir(&dir)
+        .output()
+        .expect("failed to run qsim");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let csv = std::fs::read_to_string(dir.join("vqe_analytic.csv")).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "theta,energy");
+    assert_eq!(lines.len(), 1 + 9);
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("VQE result"), "{}", stdout);
+}
+
+/// Runs `qsim vqe --mode <mode>` in `dir` and returns the lines of `vqe_noisy.csv`.
+fn noisy_csv(dir: &std::path::Path, mode: &str) -> Vec<String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_qsim"))
+        .args(["vqe", "--mode", mode, "--theta-steps", "6"])
+        .args(["--trajectories", "8", "--shots", "20", "--p", "0.1", "--seed", "ci"])
+        .current_dir(dir)
+        .output()
+        .expect("failed to run qsim");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let csv = std::fs::read_to_string(dir.join("vqe_noisy.csv")).unwrap();
+    csv.lines().map(str::to_string).collect()
+}
+
+#[test]
+fn vqe_noisy_reports_a_bootstrap_interval() {
+    let dir = scratch_dir("noisy");
+
+    let lines = noisy_csv(&dir, "noisy");
+    assert_eq!(lines[0], "theta,energy,ci_low,ci_high");
+    assert_eq!(lines.len(), 1 + 7);
+    for line in &lines[1..] {
+        let cols: Vec<f64> = line.split(',').map(|v| v.parse().unwrap()).collect();
+        assert!(cols[2] <= cols[1] && cols[1] <= cols[3], "{}", line);
+    }
+    assert_eq!(noisy_csv(&dir, "noisy-flat"), lines);
+}
+
+#[test]
+fn sweep_subcommands_accept_binary_flags() {
+    let dir = scratch_dir("chi");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_qsim"))
+        .args(["chi-sweep", "--n", "6", "--depth-max", "4", "--depth-step", "2"])
+        .args(["--max-bond", "4", "--out", "chi.csv"])
+        .current_dir(&dir)
+        .output()
+        .expect("failed to run qsim");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let csv = std::fs::read_to_string(dir.join("chi.csv")).unwrap();
+    assert_eq!(csv.lines().count(), 1 + 2);
+}
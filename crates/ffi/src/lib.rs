@@ -0,0 +1,120 @@
+//! C ABI over the core MPS operations, for driving the simulator from C or Python.
+//!
+//! States are opaque `QsimMps` handles created by `qsim_mps_new` and released with
+//! `qsim_mps_free`. Fallible calls return 0 on success and -1 on a null handle,
+//! null gate pointer, or out-of-range site; expectation values return NaN instead.
+//! Panics inside the library are caught at the boundary and reported the same way; the
+//! state a failed call leaves behind is unspecified.
+
+use quantum::{apply_cnot, observables};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use tn::{mps::MPS, truncation::Truncation};
+
+/// Opaque MPS handle.
+pub struct QsimMps {
+    psi: MPS,
+}
+
+const OK: i32 = 0;
+const ERR: i32 = -1;
+
+/// Run `f`, returning `fallback` instead of unwinding across the C boundary.
+fn guard<T>(fallback: T, f: impl FnOnce() -> T) -> T {
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or(fallback)
+}
+
+/// |0...0> on `n` qubits. Never returns null.
+#[no_mangle]
+pub extern "C" fn qsim_mps_new(n: usize) -> *mut QsimMps {
+    Box::into_raw(Box::new(QsimMps {
+        psi: MPS::new_zero(n),
+    }))
+}
+
+/// Release a handle from `qsim_mps_new`; null is a no-op.
+///
+/// # Safety
+/// `h` must be null or a live handle from `qsim_mps_new`, and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn qsim_mps_free(h: *mut QsimMps) {
+    if !h.is_null() {
+        drop(Box::from_raw(h));
+    }
+}
+
+/// Number of sites, or 0 for a null handle.
+///
+/// # Safety
+/// `h` must be null or a live handle from `qsim_mps_new`.
+#[no_mangle]
+pub unsafe extern "C" fn qsim_mps_len(h: *const QsimMps) -> usize {
+    match h.as_ref() {
+        Some(h) => h.psi.sites().len(),
+        None => 0,
+    }
+}
+
+/// Apply a single-qubit gate given as 8 doubles: row-major `[re, im]` pairs.
+///
+/// # Safety
+/// `h` must be null or a live handle; `m` must be null or point to 8 readable doubles.
+#[no_mangle]
+pub unsafe extern "C" fn qsim_mps_apply_1q(h: *mut QsimMps, k: usize, m: *const f64) -> i32 {
+    let Some(h) = h.as_mut() else { return ERR };
+    if m.is_null() || k >= h.psi.sites().len() {
+        return ERR;
+    }
+    let m = &*(m as *const [f64; 8]);
+    guard(ERR, || {
+        h.psi.apply_1q_flat(k, m);
+        OK
+    })
+}
+
+/// CNOT with control k and target k+1, truncated to `max_bond` / `cutoff`.
+///
+/// # Safety
+/// `h` must be null or a live handle from `qsim_mps_new`.
+#[no_mangle]
+pub unsafe extern "C" fn qsim_mps_apply_cnot(
+    h: *mut QsimMps,
+    k: usize,
+    max_bond: usize,
+    cutoff: f64,
+) -> i32 {
+    let Some(h) = h.as_mut() else { return ERR };
+    if k >= h.psi.sites().len().saturating_sub(1) {
+        return ERR;
+    }
+    let trunc = Truncation::builder().max_bond(max_bond).cutoff(cutoff).build();
+    guard(ERR, || {
+        apply_cnot(&mut h.psi, k, trunc);
+        OK
+    })
+}
+
+/// ⟨Z_k⟩, or NaN for a null handle or out-of-range site.
+///
+/// # Safety
+/// `h` must be null or a live handle from `qsim_mps_new`.
+#[no_mangle]
+pub unsafe extern "C" fn qsim_mps_expect_z(h: *const QsimMps, k: usize) -> f64 {
+    match h.as_ref() {
+        Some(h) if k < h.psi.sites().len() => guard(f64::NAN, || observables::expect_z(&h.psi, k)),
+        _ => f64::NAN,
+    }
+}
+
+/// ⟨Z_k Z_{k+1}⟩, or NaN for a null handle or out-of-range bond.
+///
+/// # Safety
+/// `h` must be null or a live handle from `qsim_mps_new`.
+#[no_mangle]
+pub unsafe extern "C" fn qsim_mps_expect_zz(h: *const QsimMps, k: usize) -> f64 {
+    match h.as_ref() {
+        Some(h) if k < h.psi.sites().len().saturating_sub(1) => {
+            guard(f64::NAN, || observables::expect_zz(&h.psi, k, k + 1))
+        }
+        _ => f64::NAN,
+    }
+}
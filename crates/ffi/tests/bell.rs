@@ -0,0 +1,66 @@
+//! Drives the library only through its C entry points, as an external caller would.
+
+use qsim_ffi::{
+    qsim_mps_apply_1q, qsim_mps_apply_cnot, qsim_mps_expect_z, qsim_mps_expect_zz,
+    qsim_mps_free, qsim_mps_len, qsim_mps_new,
+};
+use std::ptr;
+
+#[test]
+fn bell_state_through_c_abi() {
+    let h = std::f64::consts::FRAC_1_SQRT_2;
+    let hadamard = [h, 0.0, h, 0.0, h, 0.0, -h, 0.0];
+
+    unsafe {
+        let psi = qsim_mps_new(2);
+        assert_eq!(qsim_mps_len(psi), 2);
+        assert_eq!(qsim_mps_apply_1q(psi, 0, hadamard.as_ptr()), 0);
+        assert_eq!(qsim_mps_apply_cnot(psi, 0, 8, 1e-12), 0);
+
+        assert!(qsim_mps_expect_z(psi, 0).abs() < 1e-12);
+        assert!(qsim_mps_expect_z(psi, 1).abs() < 1e-12);
+        assert!((qsim_mps_expect_zz(psi, 0) - 1.0).abs() < 1e-12);
+
+        qsim_mps_free(psi);
+    }
+}
+
+#[test]
+fn invalid_arguments_are_reported_not_panicked() {
+    let ident = [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+
+    unsafe {
+        let psi = qsim_mps_new(2);
+        assert_eq!(qsim_mps_apply_1q(psi, 2, ident.as_ptr()), -1);
+        assert_eq!(qsim_mps_apply_1q(psi, 0, ptr::null()), -1);
+        assert_eq!(qsim_mps_apply_cnot(psi, 1, 8, 1e-12), -1);
+        assert!(qsim_mps_expect_z(psi, 5).is_nan());
+        assert!(qsim_mps_expect_zz(psi, 1).is_nan());
+        qsim_mps_free(psi);
+
+        assert_eq!(qsim_mps_len(ptr::null()), 0);
+        assert_eq!(qsim_mps_apply_cnot(ptr::null_mut(), 0, 8, 1e-12), -1);
+        assert!(qsim_mps_expect_z(ptr::null(), 0).is_nan());
+        qsim_mps_free(ptr::null_mut());
+    }
+}
+
+#[test]
+fn largest_site_index_is_rejected_without_overflow() {
+    let ident = [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+
+    unsafe {
+        let psi = qsim_mps_new(2);
+        assert_eq!(qsim_mps_apply_1q(psi, usize::MAX, ident.as_ptr()), -1);
+        assert_eq!(qsim_mps_apply_cnot(psi, usize::MAX, 8, 1e-12), -1);
+        assert!(qsim_mps_expect_z(psi, usize::MAX).is_nan());
+        assert!(qsim_mps_expect_zz(psi, usize::MAX).is_nan());
+        qsim_mps_free(psi);
+
+        // An empty chain has no bonds at all.
+        let empty = qsim_mps_new(0);
+        assert_eq!(qsim_mps_apply_cnot(empty, 0, 8, 1e-12), -1);
+        assert!(qsim_mps_expect_zz(empty, 0).is_nan());
+        qsim_mps_free(empty);
+    }
+}
@@ -0,0 +1,177 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use quantum::{
+    circuit::{haar_brickwork, haar_u4, BENCH_SEED},
+    energy::{energy, energy_diagonal_fast, energy_heisenberg, energy_heisenberg_canonical},
+    gates::rx,
+    hamiltonian::{Hamiltonian, Heisenberg},
+    measurement::measure_z,
+    shot_estimator::estimate_z_shots,
+};
+use rng::ONDRng;
+use tn::{
+    mps::{overlap, MPS},
+    truncation::Truncation,
+};
+
+use std::time::{Duration, Instant};
+
+const N: usize = 16;
+const BOND_DIMS: [usize; 4] = [8, 16, 32, 64];
+
+/// The shared Haar brickwork, n layers deep, so the middle bond saturates at `chi`.
+fn entangled_state(n: usize, chi: usize) -> MPS {
+    let trunc = Truncation::builder().max_bond(chi).cutoff(1e-12).build();
+    let mut psi = MPS::new_zero(n);
+    haar_brickwork(n, n, BENCH_SEED).apply(&mut psi, trunc);
+    psi
+}
+
+fn bench_apply_2q_svd(c: &mut Criterion) {
+    let gate = haar_u4(&mut ONDRng::new(BENCH_SEED.as_bytes()));
+    let mut group = c.benchmark_group("apply_2q_svd");
+    for chi in BOND_DIMS {
+        let trunc = Truncation::builder().max_bond(chi).cutoff(1e-12).build();
+        let psi = entangled_state(N, chi);
+        group.bench_with_input(BenchmarkId::from_parameter(chi), &psi, |b, psi| {
+            b.iter_batched(
+                || psi.clone(),
+                |mut psi| {
+                    psi.apply_2q_svd(N / 2 - 1, gate, trunc);
+                    psi
+                },
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn bench_apply_1q(c: &mut Criterion) {
+    let u = rx(0.9);
+    let mut group = c.benchmark_group("apply_1q");
+    for chi in BOND_DIMS {
+        let psi = entangled_state(N, chi);
+        group.bench_with_input(BenchmarkId::from_parameter(chi), &psi, |b, psi| {
+            b.iter_batched(
+                || psi.clone(),
+                |mut psi| {
+                    psi.apply_1q(N / 2, u);
+                    psi
+                },
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn bench_overlap(c: &mut Criterion) {
+    let mut group = c.benchmark_group("overlap");
+    for chi in BOND_DIMS {
+        let psi = entangled_state(N, chi);
+        group.bench_with_input(BenchmarkId::from_parameter(chi), &psi, |b, psi| {
+            b.iter(|| overlap(black_box(psi), black_box(psi)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_energy(c: &mut Criterion) {
+    let ising = Hamiltonian::ising(N, 0.5, 1.0);
+    let heis = Heisenberg::uniform(N, 1.0);
+    let mut group = c.benchmark_group("energy");
+    for chi in [8, 16, 32] {
+        let psi = entangled_state(N, chi);
+        group.bench_with_input(BenchmarkId::new("ising", chi), &psi, |b, psi| {
+            b.iter(|| energy(black_box(psi), &ising))
+        });
+        group.bench_with_input(BenchmarkId::new("ising_fast", chi), &psi, |b, psi| {
+            b.iter(|| energy_diagonal_fast(black_box(psi), &ising))
+        });
+        group.bench_with_input(BenchmarkId::new("heisenberg", chi), &psi, |b, psi| {
+            b.iter(|| energy_heisenberg(black_box(psi), &heis))
+        });
+    }
+    group.finish();
+}
+
+/// 1000 Z shots on n = 40: a collapsed clone per shot vs `estimate_z_shots`.
+fn bench_z_shots(c: &mut Criterion) {
+    let n = 40;
+    let shots = 1000;
+    let psi = entangled_state(n, 8);
+    let k = n / 2;
+
+    let mut group = c.benchmark_group("z_shots");
+    group.sample_size(10);
+    group.bench_function("clone_per_shot", |b| {
+        b.iter(|| {
+            let mut rng = ONDRng::new(b"z-shots");
+            let mut sum = 0.0;
+            for _ in 0..shots {
+                let mut psi_copy = psi.clone();
+                sum += if measure_z(&mut psi_copy, k, &mut rng) == 0 { 1.0 } else { -1.0 };
+            }
+            sum / shots as f64
+        })
+    });
+    group.bench_function("direct", |b| {
+        b.iter(|| estimate_z_shots(black_box(&psi), k, &mut ONDRng::new(b"z-shots"), shots))
+    });
+    group.finish();
+}
+
+/// Best-of-`reps` wall time, to keep the regression guard below robust to noise.
+fn best_time<F: FnMut() -> f64>(reps: usize, mut f: F) -> Duration {
+    (0..reps)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+/// Per-term environments vs the canonical sweep on n = 30; panics if canonical ever loses.
+fn bench_energy_canonical(c: &mut Criterion) {
+    let n = 30;
+    let chi = 8;
+    let heis = Heisenberg::uniform(n, 1.0);
+    let psi = entangled_state(n, chi);
+
+    let naive = best_time(3, || energy_heisenberg(&psi, &heis));
+    let canonical = best_time(3, || energy_heisenberg_canonical(&psi, &heis));
+    let ratio = naive.as_secs_f64() / canonical.as_secs_f64();
+    println!(
+        "energy_heisenberg n={} chi={}: naive {:?}, canonical {:?}, speedup {:.1}x",
+        n, chi, naive, canonical, ratio
+    );
+    assert!(
+        canonical < naive,
+        "canonical energy sweep is slower than per-term environments ({:?} vs {:?})",
+        canonical,
+        naive
+    );
+
+    let mut group = c.benchmark_group("energy_canonical");
+    group.sample_size(10);
+    group.bench_function(BenchmarkId::new("naive", n), |b| {
+        b.iter(|| energy_heisenberg(black_box(&psi), &heis))
+    });
+    group.bench_function(BenchmarkId::new("canonical", n), |b| {
+        b.iter(|| energy_heisenberg_canonical(black_box(&psi), &heis))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_apply_2q_svd,
+    bench_apply_1q,
+    bench_overlap,
+    bench_energy,
+    bench_z_shots,
+    bench_energy_canonical
+);
+criterion_main!(benches);
@@ -0,0 +1,249 @@
+use rng::{ONDRng, SeedContext};
+use std::ops::Range;
+use tn::{
+    mps::{C64, MPS},
+    truncation::Truncation,
+};
+
+/// One gate of a nearest-neighbour circuit; `Two` acts on sites (k, k+1).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Gate {
+    One { k: usize, u: [[C64; 2]; 2] },
+    Two { k: usize, u: [[C64; 4]; 4] },
+}
+
+impl Gate {
+    /// Sites the gate acts on.
+    pub fn sites(&self) -> Range<usize> {
+        match *self {
+            Gate::One { k, .. } => k..k + 1,
+            Gate::Two { k, .. } => k..k + 2,
+        }
+    }
+}
+
+/// Ordered gate list, replayable on an MPS or on a dense state vector.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Circuit {
+    pub gates: Vec<Gate>,
+}
+
+impl Circuit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push_1q(&mut self, k: usize, u: [[C64; 2]; 2]) {
+        self.gates.push(Gate::One { k, u });
+    }
+
+    pub fn push_2q(&mut self, k: usize, u: [[C64; 4]; 4]) {
+        self.gates.push(Gate::Two { k, u });
+    }
+
+    /// Apply every gate to `psi`; returns true when any two-site gate saturated `max_bond`.
+    pub fn apply(&self, psi: &mut MPS, trunc: Truncation) -> bool {
+        let mut saturated = false;
+        for gate in &self.gates {
+            match *gate {
+                Gate::One { k, u } => psi.apply_1q(k, u),
+                Gate::Two { k, u } => saturated |= psi.apply_2q_svd(k, u, trunc),
+            }
+        }
+        saturated
+    }
+
+    /// The same gates, reordered so each cut finishes its entangling phase before another
+    /// cut starts one.
+    ///
+    /// Only gates on disjoint sites are swapped past each other, so the final state is
+    /// unchanged. Greedy and simulation-free: every cut carries an upper bound on its bond,
+    /// and a two-qubit gate on cut k raises it to min(4χ_k, 2χ_{k-1}, 2χ_{k+1}, 2^min(k+1,
+    /// n-k-1), `trunc.max_bond`). Among the ready gates the one with the smallest bound
+    /// growth is taken (single-qubit gates grow nothing), ties in circuit order. The bound
+    /// never shrinks, so disentangling gates are not preferred for lowering χ; they are
+    /// simply free to run next, which is what closes a cut's phase early.
+    ///
+    /// Limitation: this cannot lower peak χ. A single cut's bond is fixed by the gates on
+    /// that cut (gates off the cut act on one side of it), and reordering commuting gates
+    /// does not change which gates those are. What it lowers is the peak total size of the
+    /// MPS when entangling phases on different cuts overlap in the original order.
+    pub fn schedule_low_memory(&self, n: usize, trunc: Truncation) -> Circuit {
+        // Per-site queues of gate indices; a gate is ready when it heads every queue it is in.
+        let mut queues: Vec<Vec<usize>> = vec![Vec::new(); n];
+        for (i, gate) in self.gates.iter().enumerate() {
+            for q in gate.sites() {
+                assert!(q < n, "schedule_low_memory: gate {} touches site {} of {}", i, q, n);
+                queues[q].push(i);
+            }
+        }
+        let mut front = vec![0usize; n];
+
+        // Bound on the bond of cut k (between sites k and k+1) after a gate on that cut.
+        let mut bond = vec![1usize; n.saturating_sub(1)];
+        let grown = |bond: &[usize], k: usize| {
+            let left = if k == 0 { 1 } else { bond[k - 1] };
+            let right = bond.get(k + 1).copied().unwrap_or(1);
+            let full = 1usize.checked_shl((k + 1).min(n - k - 1) as u32).unwrap_or(usize::MAX);
+            bond[k]
+                .saturating_mul(4)
+                .min(left.saturating_mul(2))
+                .min(right.saturating_mul(2))
+                .min(full)
+                .min(trunc.max_bond)
+        };
+
+        let mut out = Circuit::new();
+        while out.gates.len() < self.gates.len() {
+            let ready = (0..n).filter_map(|q| {
+                let &i = queues[q].get(front[q])?;
+                let sites = self.gates[i].sites();
+                let heads = sites.clone().all(|p| queues[p].get(front[p]) == Some(&i));
+                (heads && sites.start == q).then_some(i)
+            });
+
+            let cost = |i: usize| match self.gates[i] {
+                Gate::One { .. } => 0,
+                Gate::Two { k, .. } => grown(&bond, k).saturating_sub(bond[k]),
+            };
+            let (_, i) = ready
+                .map(|i| (cost(i), i))
+                .min()
+                .expect("schedule_low_memory: no ready gate");
+            let gate = self.gates[i];
+            if let Gate::Two { k, .. } = gate {
+                bond[k] = grown(&bond, k);
+            }
+            for q in gate.sites() {
+                front[q] += 1;
+            }
+            out.gates.push(gate);
+        }
+        out
+    }
+
+    /// Exact amplitudes of the circuit acting on |0...0> (site 0 most significant).
+    pub fn run_dense(&self, n: usize) -> Vec<C64> {
+        let mut amps = vec![C64::new(0.0, 0.0); 1usize << n];
+        amps[0] = C64::new(1.0, 0.0);
+        for gate in &self.gates {
+            match *gate {
+                Gate::One { k, u } => apply_dense_1q(&mut amps, n, k, u),
+                Gate::Two { k, u } => apply_dense_2q(&mut amps, n, k, u),
+            }
+        }
+        amps
+    }
+}
+
+fn apply_dense_1q(amps: &mut [C64], n: usize, k: usize, u: [[C64; 2]; 2]) {
+    let bit = 1usize << (n - 1 - k);
+    for x in 0..amps.len() {
+        if x & bit == 0 {
+            let (a0, a1) = (amps[x], amps[x | bit]);
+            amps[x] = u[0][0] * a0 + u[0][1] * a1;
+            amps[x | bit] = u[1][0] * a0 + u[1][1] * a1;
+        }
+    }
+}
+
+fn apply_dense_2q(amps: &mut [C64], n: usize, k: usize, u: [[C64; 4]; 4]) {
+    let hi = 1usize << (n - 1 - k);
+    let lo = 1usize << (n - 2 - k);
+    for x in 0..amps.len() {
+        if x & (hi | lo) == 0 {
+            let idx = [x, x | lo, x | hi, x | hi | lo];
+            let v = idx.map(|i| amps[i]);
+            for (i, &out) in idx.iter().enumerate() {
+                amps[out] = (0..4).map(|j| u[i][j] * v[j]).sum();
+            }
+        }
+    }
+}
+
+/// |⟨dense|mps⟩|² / ⟨mps|mps⟩ between the exact state and the MPS run under `trunc`.
+///
+/// Cross-checks the truncation machinery against a dense simulation, so `n` is
+/// limited to 16 qubits.
+pub fn dense_mps_fidelity(circuit: &Circuit, n: usize, trunc: Truncation) -> f64 {
+    assert!(n <= 16, "dense_mps_fidelity needs n <= 16 (got {})", n);
+    let dense = circuit.run_dense(n);
+
+    let mut psi = MPS::new_zero(n);
+    circuit.apply(&mut psi, trunc);
+    let amps = psi.to_statevector();
+
+    let mut ov = C64::new(0.0, 0.0);
+    let mut norm = 0.0;
+    for (d, m) in dense.iter().zip(&amps) {
+        ov += d.conj() * m;
+        norm += m.norm_sqr();
+    }
+    if norm == 0.0 {
+        return 0.0;
+    }
+    ov.norm_sqr() / norm
+}
+
+/// Seed of the shared benchmark workload.
+pub const BENCH_SEED: &str = "haar-bench";
+
+/// Haar-random 4×4 unitary: Gram–Schmidt on a complex Ginibre matrix.
+///
+/// Gram–Schmidt leaves the implied R with a positive real diagonal, which is the phase
+/// fix that makes the QR of a Ginibre matrix Haar distributed.
+pub fn haar_u4(rng: &mut ONDRng) -> [[C64; 4]; 4] {
+    let mut gaussian = || {
+        let r = (-2.0 * rng.next_f64(b"HAAR_U4").max(f64::MIN_POSITIVE).ln()).sqrt();
+        let phi = 2.0 * std::f64::consts::PI * rng.next_f64(b"HAAR_U4");
+        C64::from_polar(r / std::f64::consts::SQRT_2, phi)
+    };
+    let mut cols = [[C64::new(0.0, 0.0); 4]; 4];
+    for col in cols.iter_mut() {
+        for x in col.iter_mut() {
+            *x = gaussian();
+        }
+    }
+
+    for j in 0..4 {
+        for i in 0..j {
+            let proj: C64 = (0..4).map(|r| cols[i][r].conj() * cols[j][r]).sum();
+            let ci = cols[i];
+            for (x, &c) in cols[j].iter_mut().zip(&ci) {
+                *x -= proj * c;
+            }
+        }
+        let norm = cols[j].iter().map(|x| x.norm_sqr()).sum::<f64>().sqrt();
+        for x in cols[j].iter_mut() {
+            *x /= norm;
+        }
+    }
+
+    let mut u = [[C64::new(0.0, 0.0); 4]; 4];
+    for r in 0..4 {
+        for c in 0..4 {
+            u[r][c] = cols[c][r];
+        }
+    }
+    u
+}
+
+/// Brickwork of Haar-random two-qubit gates on `n` sites, the fixed benchmark workload.
+///
+/// Layer l acts on bonds (k, k+1) with k ≡ l mod 2, and each layer draws from its own
+/// stream keyed by `(n, l, seed)`, so a circuit of depth d is the first d layers of any
+/// deeper one with the same `n` and `seed`. Every gate is generically entangling, so the
+/// bond dimension grows as fast as the brickwork allows.
+pub fn haar_brickwork(n: usize, depth: usize, seed: &str) -> Circuit {
+    let ctx = SeedContext::new("haar-brickwork", seed.as_bytes());
+    let mut c = Circuit::new();
+    for layer in 0..depth {
+        let mut rng = ctx.rng(&[("n", n as u64), ("layer", layer as u64)]);
+        let mut k = layer % 2;
+        while k + 1 < n {
+            c.push_2q(k, haar_u4(&mut rng));
+            k += 2;
+        }
+    }
+    c
+}
@@ -1,35 +1,352 @@
-use crate::hamiltonian::{Hamiltonian, Heisenberg};
-use crate::observables::{expect_xx, expect_yy, expect_z, expect_zz};
-use tn::mps::MPS;
+use crate::env::{left_env, left_step, open_view, right_step};
+use crate::gates::{kron, pauli_x, pauli_y, pauli_z};
+use crate::hamiltonian::{Hamiltonian, Heisenberg, PauliSum, SpinChain};
+use crate::mpo::{expect_mpo, hamiltonian_mpo, heisenberg_mpo, pauli_sum_mpo};
+use crate::observables::{
+    expect_pair, expect_pauli_string, expect_two_site_env, expect_xx, expect_yy, expect_zz,
+    Observable,
+};
+use crate::spin::{spin_sx, spin_sy, spin_sz};
+use crate::trotter::{bond_couplings, num_bonds};
+use tn::mps::{Tensor3, C64, MPS};
 
 /// Expectation value ⟨ψ|H|ψ⟩ for a diagonal Z/ZZ Hamiltonian.
+///
+/// One contraction with the Hamiltonian's MPO (`Mpo::from_hamiltonian`), so the cost is
+/// a single pair of environments rather than two per term.
 pub fn energy(psi: &MPS, h: &Hamiltonian) -> f64 {
-    let mut e = 0.0;
+    let n = psi.sites().len();
+    assert!(h.z_fields.len() <= n, "z_fields longer than the chain");
+    assert!(h.zz_couplings.len() < n.max(1), "zz_couplings longer than the bond count");
+    expect_mpo(psi, &hamiltonian_mpo(h, n))
+}
+
+/// Σ ⟨O_i⟩ over an arbitrary list of observables, e.g. a Hamiltonian mixing built-in
+/// terms with user-defined ones.
+pub fn energy_observables(psi: &MPS, terms: &[Box<dyn Observable>]) -> f64 {
+    terms.iter().map(|o| o.expectation(psi)).sum()
+}
+
+/// `energy` in one left-to-right sweep instead of two environments per term.
+///
+/// Carries three transfer environments: the plain norm, one with a Z left open on the
+/// previous site (pending ZZ), and the sum of all completed terms, so the cost is
+/// O(n χ³) rather than O(n² χ⁴). Normalized by ⟨ψ|ψ⟩ like `energy`.
+pub fn energy_diagonal_fast(psi: &MPS, h: &Hamiltonian) -> f64 {
+    let n = psi.sites().len();
+    assert!(h.z_fields.len() <= n, "z_fields longer than the chain");
+    assert!(h.zz_couplings.len() < n.max(1), "zz_couplings longer than the bond count");
+    let psi = &*open_view(psi);
+
+    let mut env_id = vec![C64::new(1.0, 0.0)];
+    let mut env_z = vec![C64::new(0.0, 0.0)];
+    let mut env_h = vec![C64::new(0.0, 0.0)];
+
+    for (i, a) in psi.sites().iter().enumerate() {
+        assert!(a.dp == 2, "energy_diagonal_fast supports qubits only");
+        let hz = h.z_fields.get(i).copied().unwrap_or(0.0);
+        let jzz = if i > 0 {
+            h.zz_couplings.get(i - 1).copied().unwrap_or(0.0)
+        } else {
+            0.0
+        };
+
+        let half_id = absorb(&env_id, a);
+        let half_z = absorb(&env_z, a);
+        let half_h = absorb(&env_h, a);
 
-    for (i, &hi) in h.z_fields.iter().enumerate() {
-        e += hi * expect_z(psi, i);
+        let next_id = close(&half_id, a, [1.0, 1.0]);
+        let next_z = close(&half_id, a, [1.0, -1.0]);
+        let mut next_h = close(&half_h, a, [1.0, 1.0]);
+        let closed_zz = close(&half_z, a, [1.0, -1.0]);
+        for ((e, z), zz) in next_h.iter_mut().zip(&next_z).zip(&closed_zz) {
+            *e += hz * z + jzz * zz;
+        }
+
+        env_id = next_id;
+        env_z = next_z;
+        env_h = next_h;
     }
 
-    for (i, &j) in h.zz_couplings.iter().enumerate() {
-        e += j * expect_zz(psi, i, i + 1);
+    let norm = env_id[0].re;
+    if norm == 0.0 {
+        return 0.0;
     }
+    env_h[0].re / norm
+}
 
-    e
+/// Σ_l E[l, l'] A[l, p, r], laid out as [(l' · dp + p) · dr + r].
+fn absorb(env: &[C64], a: &Tensor3) -> Vec<C64> {
+    let mut out = vec![C64::new(0.0, 0.0); a.dl * a.dp * a.dr];
+    for l in 0..a.dl {
+        for lp in 0..a.dl {
+            let e = env[l * a.dl + lp];
+            if e == C64::new(0.0, 0.0) {
+                continue;
+            }
+            for p in 0..a.dp {
+                for r in 0..a.dr {
+                    out[(lp * a.dp + p) * a.dr + r] += e * a.get(l, p, r);
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Σ_{l', p} d_p · half[l', p, r] · conj(A[l', p, r']) for a diagonal one-site operator d.
+fn close(half: &[C64], a: &Tensor3, d: [f64; 2]) -> Vec<C64> {
+    let mut out = vec![C64::new(0.0, 0.0); a.dr * a.dr];
+    for lp in 0..a.dl {
+        for (p, &dp) in d.iter().enumerate() {
+            for r in 0..a.dr {
+                let x = half[(lp * a.dp + p) * a.dr + r] * dp;
+                for rp in 0..a.dr {
+                    out[r * a.dr + rp] += x * a.get(lp, p, rp).conj();
+                }
+            }
+        }
+    }
+    out
 }
 
 /// Expectation value ⟨ψ|H|ψ⟩ for nearest-neighbor Heisenberg (XX + YY + ZZ).
+///
+/// Contracted with the MPO of `Mpo::from_heisenberg` in one pass.
 pub fn energy_heisenberg(psi: &MPS, h: &Heisenberg) -> f64 {
+    let n = psi.sites().len();
+    assert!(num_bonds(h) < n.max(1), "couplings longer than the bond count");
+    expect_mpo(psi, &heisenberg_mpo(h, n))
+}
+
+/// `energy_heisenberg` in one canonical sweep instead of two environments per term.
+///
+/// The orthogonality center walks left to right on an open copy of ψ; with the center on
+/// site b both environments of bond (b, b+1) are identities, so each bond term is a
+/// local two-site contraction and the whole energy costs O(n χ³).
+pub fn energy_heisenberg_canonical(psi: &MPS, h: &Heisenberg) -> f64 {
+    let bonds = num_bonds(h).min(psi.sites().len().saturating_sub(1));
+    if bonds == 0 {
+        return 0.0;
+    }
+
+    let mut phi = psi.to_open();
+    phi.canonicalize_at(0);
+
+    let mut e = 0.0;
+    for b in 0..bonds {
+        let (jx, jy, jz) = bond_couplings(h, b);
+        e += local_bond_expectation(&phi, b, heisenberg_bond_op(jx, jy, jz));
+        if b + 1 < bonds {
+            phi.qr_left(b);
+        }
+    }
+    e
+}
+
+/// jx XX + jy YY + jz ZZ on one bond.
+fn heisenberg_bond_op(jx: f64, jy: f64, jz: f64) -> [[C64; 4]; 4] {
+    let xx = kron(pauli_x(), pauli_x());
+    let yy = kron(pauli_y(), pauli_y());
+    let zz = kron(pauli_z(), pauli_z());
+    let mut op = [[C64::new(0.0, 0.0); 4]; 4];
+    for i in 0..4 {
+        for j in 0..4 {
+            op[i][j] = xx[i][j] * jx + yy[i][j] * jy + zz[i][j] * jz;
+        }
+    }
+    op
+}
+
+/// ⟨op⟩ on bond (b, b+1) assuming identity environments (center on b or b+1).
+fn local_bond_expectation(psi: &MPS, b: usize, op: [[C64; 4]; 4]) -> f64 {
+    let a = &psi.sites()[b];
+    let c = &psi.sites()[b + 1];
+    assert!(a.dp == 2 && c.dp == 2, "local_bond_expectation supports qubits only");
+    let (dl, chi, dr) = (a.dl, a.dr, c.dr);
+
+    // theta[l][p1 * 2 + p2][r] = Σ_m A[l, p1, m] B[m, p2, r]
+    let mut theta = vec![C64::new(0.0, 0.0); dl * 4 * dr];
+    for l in 0..dl {
+        for p1 in 0..2 {
+            for m in 0..chi {
+                let av = a.get(l, p1, m);
+                for p2 in 0..2 {
+                    for r in 0..dr {
+                        theta[(l * 4 + p1 * 2 + p2) * dr + r] += av * c.get(m, p2, r);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut numer = C64::new(0.0, 0.0);
+    let mut denom = 0.0;
+    for l in 0..dl {
+        for r in 0..dr {
+            for p in 0..4 {
+                let bra = theta[(l * 4 + p) * dr + r].conj();
+                denom += bra.norm_sqr();
+                for q in 0..4 {
+                    numer += bra * op[p][q] * theta[(l * 4 + q) * dr + r];
+                }
+            }
+        }
+    }
+
+    if denom == 0.0 {
+        return 0.0;
+    }
+    numer.re / denom
+}
+
+/// Per-bond Heisenberg energies ⟨h_{i,i+1}⟩; they sum to `energy_heisenberg`.
+pub fn energy_density_heisenberg(psi: &MPS, h: &Heisenberg) -> Vec<f64> {
+    let bonds = num_bonds(h).min(psi.sites().len().saturating_sub(1));
+    (0..bonds)
+        .map(|i| {
+            let (jx, jy, jz) = bond_couplings(h, i);
+            jx * expect_xx(psi, i, i + 1)
+                + jy * expect_yy(psi, i, i + 1)
+                + jz * expect_zz(psi, i, i + 1)
+        })
+        .collect()
+}
+
+/// Expectation value ⟨ψ|H|ψ⟩ for a nearest-neighbor spin-S chain.
+pub fn energy_spin_chain(psi: &MPS, h: &SpinChain) -> f64 {
+    let sx = spin_sx(h.two_s);
+    let sy = spin_sy(h.two_s);
+    let sz = spin_sz(h.two_s);
     let mut e = 0.0;
 
     for i in 0..h.jx.len() {
-        e += h.jx[i] * expect_xx(psi, i, i + 1);
+        e += h.jx[i] * expect_pair(psi, i, &sx, &sx);
     }
     for i in 0..h.jy.len() {
-        e += h.jy[i] * expect_yy(psi, i, i + 1);
+        e += h.jy[i] * expect_pair(psi, i, &sy, &sy);
     }
     for i in 0..h.jz.len() {
-        e += h.jz[i] * expect_zz(psi, i, i + 1);
+        e += h.jz[i] * expect_pair(psi, i, &sz, &sz);
     }
 
     e
 }
+
+/// Expectation value ⟨ψ|H|ψ⟩ for a general Pauli-sum Hamiltonian.
+///
+/// All terms share one MPO (`Mpo::from_pauli_sum`), so this is a single contraction;
+/// `energy_terms` still evaluates them one by one.
+pub fn energy_pauli_sum(psi: &MPS, h: &PauliSum) -> f64 {
+    expect_mpo(psi, &pauli_sum_mpo(h, psi.sites().len()))
+}
+
+/// Per-term contributions (label, coeff · ⟨term⟩); they sum to `energy_pauli_sum`.
+pub fn energy_terms(psi: &MPS, h: &PauliSum) -> Vec<(String, f64)> {
+    h.terms
+        .iter()
+        .map(|t| (t.label(), t.coeff * expect_pauli_string(psi, &t.ops)))
+        .collect()
+}
+
+/// Energy variance ⟨H²⟩ − ⟨H⟩²; zero exactly when ψ is an eigenstate of H.
+///
+/// H is Hermitian, so ⟨H²⟩ = ‖H|ψ⟩‖² / ⟨ψ|ψ⟩: one exact MPO application instead of
+/// expanding H² into all T² ordered products, whose MPO would need a state per product.
+pub fn energy_variance(psi: &MPS, h: &PauliSum) -> f64 {
+    let n = psi.sites().len();
+    if n == 0 {
+        return 0.0;
+    }
+    let w = pauli_sum_mpo(h, n);
+    let psi = open_view(psi);
+    let norm = left_env(psi.sites(), n)[0].re;
+    if norm == 0.0 {
+        return 0.0;
+    }
+
+    let e = expect_mpo(&psi, &w);
+    let mut h_psi = psi.into_owned();
+    w.contract(&mut h_psi);
+    let h2 = left_env(h_psi.sites(), n)[0].re / norm;
+    (h2 - e * e).max(0.0)
+}
+
+/// Heisenberg energy with cached environments for cheap re-evaluation after 1q gates.
+///
+/// A single-site unitary leaves every environment unchanged (the physical index is
+/// traced against its conjugate) and commutes with all bond terms not touching the
+/// site, so only the one or two adjacent bond energies need recomputing.
+pub struct EnergyCache {
+    left: Vec<Vec<C64>>,
+    right: Vec<Vec<C64>>,
+    ops: Vec<[[C64; 4]; 4]>,
+    terms: Vec<f64>,
+}
+
+impl EnergyCache {
+    pub fn new(psi: &MPS, h: &Heisenberg) -> Self {
+        assert!(!psi.is_periodic(), "EnergyCache needs an open-boundary MPS");
+        let n = psi.sites().len();
+        let bonds = num_bonds(h).min(n.saturating_sub(1));
+
+        let ops: Vec<[[C64; 4]; 4]> = (0..bonds)
+            .map(|b| {
+                let (jx, jy, jz) = bond_couplings(h, b);
+                heisenberg_bond_op(jx, jy, jz)
+            })
+            .collect();
+
+        // left[k] / right[k] are left_env(k) / right_env(k), one transfer step apart.
+        let mut left = vec![vec![C64::new(1.0, 0.0)]; n];
+        for k in 1..n {
+            left[k] = left_step(&left[k - 1], &psi.sites()[k - 1]);
+        }
+        let mut right = vec![vec![C64::new(1.0, 0.0)]; n];
+        for k in (1..n).rev() {
+            right[k - 1] = right_step(&right[k], &psi.sites()[k]);
+        }
+
+        let mut cache = Self {
+            left,
+            right,
+            ops,
+            terms: vec![0.0; bonds],
+        };
+        for b in 0..bonds {
+            cache.update_bond(psi, b);
+        }
+        cache
+    }
+
+    fn update_bond(&mut self, psi: &MPS, b: usize) {
+        let (left, right) = (&self.left[b], &self.right[b + 1]);
+        self.terms[b] = expect_two_site_env(psi, b, self.ops[b], left, right);
+    }
+
+    /// Current ⟨ψ|H|ψ⟩.
+    pub fn energy(&self) -> f64 {
+        self.terms.iter().sum()
+    }
+
+    /// Apply a single-qubit unitary to site k and refresh only the adjacent bond terms.
+    ///
+    /// `u` must be unitary: the cached environments are only invariant under unitaries, and
+    /// a non-unitary `u` would silently leave them stale (checked in debug builds).
+    pub fn apply_1q(&mut self, psi: &mut MPS, k: usize, u: [[C64; 2]; 2]) {
+        debug_assert!(
+            (0..2).all(|i| (0..2).all(|j| {
+                let dot = u[0][i].conj() * u[0][j] + u[1][i].conj() * u[1][j];
+                (dot - C64::new(if i == j { 1.0 } else { 0.0 }, 0.0)).norm() < 1e-10
+            })),
+            "EnergyCache::apply_1q needs a unitary gate"
+        );
+        psi.apply_1q(k, u);
+        if k > 0 && k - 1 < self.terms.len() {
+            self.update_bond(psi, k - 1);
+        }
+        if k < self.terms.len() {
+            self.update_bond(psi, k);
+        }
+    }
+}
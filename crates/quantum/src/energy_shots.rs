@@ -1,3 +1,4 @@
+use crate::energy::energy;
 use crate::hamiltonian::Hamiltonian;
 use crate::shot_estimator::{estimate_z_shots, estimate_zz_shots};
 use rng::ONDRng;
@@ -22,3 +23,26 @@ pub fn estimate_energy_shots(
 
     e
 }
+
+/// Statistical error |estimate - analytic| of the shot estimator for a given budget.
+pub fn shot_analytic_gap(psi: &MPS, h: &Hamiltonian, rng: &mut ONDRng, shots: usize) -> f64 {
+    (estimate_energy_shots(psi, h, rng, shots) - energy(psi, h)).abs()
+}
+
+/// Control-variate shot energy of ψ using a surrogate whose exact energy is cheap.
+///
+/// Both states are sampled with the same RNG stream (common random numbers), and
+/// E ≈ Ê_ψ − (Ê_s − E_s). The closer the surrogate tracks ψ, the more of the shot
+/// noise cancels; identical states give E_s exactly, with zero variance.
+pub fn control_variate_energy(
+    psi: &MPS,
+    surrogate: &MPS,
+    h: &Hamiltonian,
+    rng: &mut ONDRng,
+    shots: usize,
+) -> f64 {
+    let mut rng_surrogate = rng.clone();
+    let e_psi = estimate_energy_shots(psi, h, rng, shots);
+    let e_surrogate = estimate_energy_shots(surrogate, h, &mut rng_surrogate, shots);
+    e_psi - (e_surrogate - energy(surrogate, h))
+}
@@ -1,49 +1,70 @@
-use tn::mps::{C64, Tensor3};
+use std::borrow::Cow;
+use tn::mps::{C64, MPS, Tensor3};
+
+/// `psi` itself for open chains, its open embedding (`MPS::to_open`) for rings.
+///
+/// The environments below start and end on dimension-1 bonds; periodic chains go
+/// through this first so the boundary bond is carried along and traced at the end.
+pub(crate) fn open_view(psi: &MPS) -> Cow<'_, MPS> {
+    if psi.is_periodic() {
+        Cow::Owned(psi.to_open())
+    } else {
+        Cow::Borrowed(psi)
+    }
+}
 
 pub(crate) fn left_env(sites: &[Tensor3], k: usize) -> Vec<C64> {
     let mut env = vec![C64::new(1.0, 0.0)];
-    for i in 0..k {
-        let a = &sites[i];
-        let mut next = vec![C64::new(0.0, 0.0); a.dr * a.dr];
-        for l in 0..a.dl {
-            for lp in 0..a.dl {
-                let lval = env[l * a.dl + lp];
-                for p in 0..a.dp {
-                    for r in 0..a.dr {
-                        let aval = a.get(l, p, r);
-                        for rp in 0..a.dr {
-                            let idx = r * a.dr + rp;
-                            next[idx] += lval * aval * a.get(lp, p, rp).conj();
-                        }
+    for a in &sites[..k] {
+        env = left_step(&env, a);
+    }
+    env
+}
+
+pub(crate) fn right_env(sites: &[Tensor3], k: usize) -> Vec<C64> {
+    let mut env = vec![C64::new(1.0, 0.0)];
+    for a in sites.iter().skip(k + 1).rev() {
+        env = right_step(&env, a);
+    }
+    env
+}
+
+/// One transfer step: the left environment of the site after `a`, given the one before it.
+pub(crate) fn left_step(env: &[C64], a: &Tensor3) -> Vec<C64> {
+    let mut next = vec![C64::new(0.0, 0.0); a.dr * a.dr];
+    for l in 0..a.dl {
+        for lp in 0..a.dl {
+            let lval = env[l * a.dl + lp];
+            for p in 0..a.dp {
+                for r in 0..a.dr {
+                    let aval = a.get(l, p, r);
+                    for rp in 0..a.dr {
+                        let idx = r * a.dr + rp;
+                        next[idx] += lval * aval * a.get(lp, p, rp).conj();
                     }
                 }
             }
         }
-        env = next;
     }
-    env
+    next
 }
 
-pub(crate) fn right_env(sites: &[Tensor3], k: usize) -> Vec<C64> {
-    let mut env = vec![C64::new(1.0, 0.0)];
-    for i in (k + 1..sites.len()).rev() {
-        let a = &sites[i];
-        let mut next = vec![C64::new(0.0, 0.0); a.dl * a.dl];
-        for r in 0..a.dr {
-            for rp in 0..a.dr {
-                let rval = env[r * a.dr + rp];
-                for p in 0..a.dp {
-                    for l in 0..a.dl {
-                        let aval = a.get(l, p, r);
-                        for lp in 0..a.dl {
-                            let idx = l * a.dl + lp;
-                            next[idx] += aval * a.get(lp, p, rp).conj() * rval;
-                        }
+/// One transfer step: the right environment of the site before `a`, given the one after it.
+pub(crate) fn right_step(env: &[C64], a: &Tensor3) -> Vec<C64> {
+    let mut next = vec![C64::new(0.0, 0.0); a.dl * a.dl];
+    for r in 0..a.dr {
+        for rp in 0..a.dr {
+            let rval = env[r * a.dr + rp];
+            for p in 0..a.dp {
+                for l in 0..a.dl {
+                    let aval = a.get(l, p, r);
+                    for lp in 0..a.dl {
+                        let idx = l * a.dl + lp;
+                        next[idx] += aval * a.get(lp, p, rp).conj() * rval;
                     }
                 }
             }
         }
-        env = next;
     }
-    env
+    next
 }
@@ -30,6 +30,24 @@ pub fn pauli_z() -> [[C64; 2]; 2] {
     [[o, z], [z, m]]
 }
 
+/// Single-qubit Pauli label, used for Pauli strings and measurement bases.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Pauli {
+    X,
+    Y,
+    Z,
+}
+
+impl Pauli {
+    pub fn matrix(self) -> [[C64; 2]; 2] {
+        match self {
+            Pauli::X => pauli_x(),
+            Pauli::Y => pauli_y(),
+            Pauli::Z => pauli_z(),
+        }
+    }
+}
+
 pub fn rx(theta: f64) -> [[C64; 2]; 2] {
     let c = (theta / 2.0).cos();
     let s = (theta / 2.0).sin();
@@ -39,6 +57,63 @@ pub fn rx(theta: f64) -> [[C64; 2]; 2] {
     ]
 }
 
+/// exp(-i θ/2 n·σ) for a rotation axis n (normalized here, so any nonzero length works).
+///
+/// `rotation([1, 0, 0], θ)` is `rx(θ)`; the y and z axes give Ry and Rz.
+pub fn rotation(axis: [f64; 3], theta: f64) -> [[C64; 2]; 2] {
+    let norm = (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt();
+    assert!(norm > 0.0, "rotation axis must be nonzero");
+    let [nx, ny, nz] = axis.map(|a| a / norm);
+
+    let c = (theta / 2.0).cos();
+    let s = (theta / 2.0).sin();
+    [
+        [C64::new(c, -s * nz), C64::new(-s * ny, -s * nx)],
+        [C64::new(s * ny, -s * nx), C64::new(c, s * nz)],
+    ]
+}
+
+/// Tensor product a ⊗ b, with `a` acting on the first (left) site.
+pub fn kron(a: [[C64; 2]; 2], b: [[C64; 2]; 2]) -> [[C64; 4]; 4] {
+    let mut out = [[C64::new(0.0, 0.0); 4]; 4];
+    for i in 0..2 {
+        for j in 0..2 {
+            for k in 0..2 {
+                for l in 0..2 {
+                    out[i * 2 + k][j * 2 + l] = a[i][j] * b[k][l];
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Matrix product a · b of single-qubit operators, so `b` acts first.
+pub(crate) fn matmul2(a: [[C64; 2]; 2], b: [[C64; 2]; 2]) -> [[C64; 2]; 2] {
+    let mut out = [[C64::new(0.0, 0.0); 2]; 2];
+    for i in 0..2 {
+        for j in 0..2 {
+            for k in 0..2 {
+                out[i][j] += a[i][k] * b[k][j];
+            }
+        }
+    }
+    out
+}
+
+/// Matrix product a · b of two-qubit gates, so `b` acts first.
+pub(crate) fn matmul4(a: [[C64; 4]; 4], b: [[C64; 4]; 4]) -> [[C64; 4]; 4] {
+    let mut out = [[C64::new(0.0, 0.0); 4]; 4];
+    for i in 0..4 {
+        for j in 0..4 {
+            for k in 0..4 {
+                out[i][j] += a[i][k] * b[k][j];
+            }
+        }
+    }
+    out
+}
+
 /// |00>→|00>, |01>→|01>, |10>→|11>, |11>→|10>
 pub fn cnot() -> [[C64; 4]; 4] {
     let z = C64::new(0.0, 0.0);
@@ -63,3 +138,46 @@ pub fn cz() -> [[C64; 4]; 4] {
         [z, z, z, m],
     ]
 }
+
+/// |01>→i|10>, |10>→i|01>; |00> and |11> unchanged.
+pub fn iswap() -> [[C64; 4]; 4] {
+    let z = C64::new(0.0, 0.0);
+    let o = C64::new(1.0, 0.0);
+    let i = C64::new(0.0, 1.0);
+    [
+        [o, z, z, z],
+        [z, z, i, z],
+        [z, i, z, z],
+        [z, z, z, o],
+    ]
+}
+
+/// fSim(θ, φ): a θ swap-rotation in the {|01>, |10>} block and phase e^{-iφ} on |11>.
+///
+/// Follows the Google convention, so `fsim(π/2, 0)` is iSWAP† and `fsim(0, π)` is CZ.
+pub fn fsim(theta: f64, phi: f64) -> [[C64; 4]; 4] {
+    let z = C64::new(0.0, 0.0);
+    let o = C64::new(1.0, 0.0);
+    let c = C64::new(theta.cos(), 0.0);
+    let s = C64::new(0.0, -theta.sin());
+    [
+        [o, z, z, z],
+        [z, c, s, z],
+        [z, s, c, z],
+        [z, z, z, C64::from_polar(1.0, -phi)],
+    ]
+}
+
+/// One ansatz block as a single 4×4 gate: `pre0 ⊗ pre1`, then `entangler`, then
+/// `post0 ⊗ post1`, with the `*0` gates on the first (left) site.
+///
+/// Applying the result with one `apply_2q_svd` replaces five tensor passes.
+pub fn two_qubit_block(
+    pre0: [[C64; 2]; 2],
+    pre1: [[C64; 2]; 2],
+    entangler: [[C64; 4]; 4],
+    post0: [[C64; 2]; 2],
+    post1: [[C64; 2]; 2],
+) -> [[C64; 4]; 4] {
+    matmul4(kron(post0, post1), matmul4(entangler, kron(pre0, pre1)))
+}
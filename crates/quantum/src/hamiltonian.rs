@@ -1,3 +1,6 @@
+use crate::gates::Pauli;
+use rng::ONDRng;
+
 #[derive(Clone)]
 pub struct Hamiltonian {
     /// local fields h_i * Z_i
@@ -13,6 +16,19 @@ impl Hamiltonian {
             zz_couplings: vec![j; n.saturating_sub(1)],
         }
     }
+
+    /// Ising chain with uniform couplings j and random fields h_i uniform in [-w, w].
+    pub fn random_field_ising(n: usize, j: f64, w: f64, rng: &mut ONDRng) -> Self {
+        let mut z_fields = Vec::with_capacity(n);
+        for _ in 0..n {
+            let u = rng.next_f64(b"ISING_FIELD");
+            z_fields.push(w * (2.0 * u - 1.0));
+        }
+        Self {
+            z_fields,
+            zz_couplings: vec![j; n.saturating_sub(1)],
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -30,4 +46,85 @@ impl Heisenberg {
             jz: vec![j; n.saturating_sub(1)],
         }
     }
+
+    /// Random-bond isotropic chain: each bond draws J_i uniformly in
+    /// [j_mean - disorder, j_mean + disorder] and uses it for XX, YY and ZZ.
+    pub fn disordered(n: usize, j_mean: f64, disorder: f64, rng: &mut ONDRng) -> Self {
+        let bonds = n.saturating_sub(1);
+        let mut j = Vec::with_capacity(bonds);
+        for _ in 0..bonds {
+            let u = rng.next_f64(b"HEIS_BOND");
+            j.push(j_mean + disorder * (2.0 * u - 1.0));
+        }
+        Self {
+            jx: j.clone(),
+            jy: j.clone(),
+            jz: j,
+        }
+    }
+}
+
+/// Nearest-neighbor spin-S chain Σ_i (jx S^x S^x + jy S^y S^y + jz S^z S^z).
+#[derive(Clone)]
+pub struct SpinChain {
+    /// twice the spin quantum number (2S); local dimension is 2S + 1
+    pub two_s: usize,
+    pub jx: Vec<f64>,
+    pub jy: Vec<f64>,
+    pub jz: Vec<f64>,
+}
+
+impl SpinChain {
+    pub fn uniform(n: usize, two_s: usize, j: f64) -> Self {
+        Self {
+            two_s,
+            jx: vec![j; n.saturating_sub(1)],
+            jy: vec![j; n.saturating_sub(1)],
+            jz: vec![j; n.saturating_sub(1)],
+        }
+    }
+}
+
+/// One weighted Pauli string coeff * ∏ P_k; sites not listed carry the identity.
+#[derive(Clone, Debug)]
+pub struct PauliTerm {
+    pub coeff: f64,
+    pub ops: Vec<(usize, Pauli)>,
+}
+
+impl PauliTerm {
+    /// Operator string such as "Z0 Z1" (coefficient omitted); "I" for the identity.
+    pub fn label(&self) -> String {
+        if self.ops.is_empty() {
+            return "I".to_string();
+        }
+        self.ops
+            .iter()
+            .map(|(k, p)| format!("{:?}{}", p, k))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// General Hamiltonian as a sum of weighted Pauli strings on n qubits.
+#[derive(Clone, Debug)]
+pub struct PauliSum {
+    pub n: usize,
+    pub terms: Vec<PauliTerm>,
+}
+
+impl PauliSum {
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            terms: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, coeff: f64, ops: &[(usize, Pauli)]) {
+        self.terms.push(PauliTerm {
+            coeff,
+            ops: ops.to_vec(),
+        });
+    }
 }
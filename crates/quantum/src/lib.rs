@@ -1,19 +1,31 @@
 pub mod gates;
+pub mod circuit;
 pub mod measurement;
+pub mod sampling;
 pub mod observables;
 pub mod hamiltonian;
 pub mod energy;
 pub mod shot_estimator;
 pub mod energy_shots;
 pub mod noise;
+pub mod mpo;
+pub mod trotter;
+pub mod spin;
+pub mod protocols;
+pub mod states;
+pub mod register;
 mod env;
 
 use tn::{mps::MPS, truncation::Truncation};
 
-pub fn apply_cnot(psi: &mut MPS, k: usize, trunc: Truncation) {
-    psi.apply_2q_svd(k, gates::cnot(), trunc);
+/// CNOT on (k, k+1); returns true when the truncation saturated `max_bond`.
+pub fn apply_cnot(psi: &mut MPS, k: usize, trunc: Truncation) -> bool {
+    psi.assert_bond(k, "apply_cnot");
+    psi.apply_2q_svd(k, gates::cnot(), trunc)
 }
 
-pub fn apply_cz(psi: &mut MPS, k: usize, trunc: Truncation) {
-    psi.apply_2q_svd(k, gates::cz(), trunc);
+/// CZ on (k, k+1); returns true when the truncation saturated `max_bond`.
+pub fn apply_cz(psi: &mut MPS, k: usize, trunc: Truncation) -> bool {
+    psi.assert_bond(k, "apply_cz");
+    psi.apply_2q_svd(k, gates::cz(), trunc)
 }
@@ -1,47 +1,72 @@
-use crate::env::{left_env, right_env};
+use crate::env::{left_env, open_view, right_env};
 use rng::ONDRng;
 use tn::mps::{C64, MPS, Tensor3};
 
-pub fn measure_z(psi: &mut MPS, k: usize, rng: &mut ONDRng) -> u8 {
-    let s = &psi.sites[k];
-    let left = left_env(&psi.sites, k);
-    let right = right_env(&psi.sites, k);
+/// A state whose ⟨ψ|ψ⟩ is too far from 1 for outcome frequencies to mean anything.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Denormalized {
+    pub norm_sq: f64,
+    pub tol: f64,
+}
 
-    let mut probs = vec![0.0f64; s.dp];
-    for p in 0..s.dp {
-        let mut acc = C64::new(0.0, 0.0);
-        for l in 0..s.dl {
-            for lp in 0..s.dl {
-                let lval = left[l * s.dl + lp];
-                for r in 0..s.dr {
-                    for rp in 0..s.dr {
-                        let rval = right[r * s.dr + rp];
-                        acc += lval
-                            * s.get(l, p, r)
-                            * s.get(lp, p, rp).conj()
-                            * rval;
-                    }
-                }
-            }
-        }
-        let val = acc.re;
-        probs[p] = if val < 0.0 { 0.0 } else { val };
+impl std::fmt::Display for Denormalized {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "state norm² is {} (allowed deviation from 1 is {})",
+            self.norm_sq, self.tol
+        )
+    }
+}
+
+impl std::error::Error for Denormalized {}
+
+/// `measure_z`, but refuses to measure when |⟨ψ|ψ⟩ - 1| > `tol`.
+///
+/// `measure_z` renormalizes by the total weight, so a state that lost (or gained) norm
+/// through a bad truncation still yields plausible outcomes; this surfaces it instead.
+/// The state and RNG are left untouched on error.
+pub fn measure_z_checked(
+    psi: &mut MPS,
+    k: usize,
+    rng: &mut ONDRng,
+    tol: f64,
+) -> Result<u8, Denormalized> {
+    let probs = site_probs(psi, k);
+    let norm_sq: f64 = probs.iter().sum();
+    if (norm_sq - 1.0).abs() > tol {
+        return Err(Denormalized { norm_sq, tol });
     }
+    Ok(collapse(psi, k, &probs, || rng.next_f64(b"MEASURE_Z")))
+}
 
+pub fn measure_z(psi: &mut MPS, k: usize, rng: &mut ONDRng) -> u8 {
+    measure_z_with(psi, k, || rng.next_f64(b"MEASURE_Z"))
+}
+
+/// Projective Z measurement with the uniform draw supplied by the caller.
+///
+/// `draw` is invoked at most once, exactly when `measure_z` would consume the RNG.
+pub fn measure_z_with<F>(psi: &mut MPS, k: usize, draw: F) -> u8
+where
+    F: FnOnce() -> f64,
+{
+    let probs = site_probs(psi, k);
+    collapse(psi, k, &probs, draw)
+}
+
+/// Draw an outcome from the site weights `probs` and project site k onto it.
+fn collapse<F>(psi: &mut MPS, k: usize, probs: &[f64], draw: F) -> u8
+where
+    F: FnOnce() -> f64,
+{
     let total: f64 = probs.iter().sum();
     if total == 0.0 {
         return 0;
     }
 
-    let mut x = rng.next_f64(b"MEASURE_Z") * total;
-    let mut outcome = 0usize;
-    for (idx, p) in probs.iter().enumerate() {
-        if x < *p {
-            outcome = idx;
-            break;
-        }
-        x -= *p;
-    }
+    let outcome = pick_outcome(probs, draw() * total);
+    let s = &psi.sites()[k];
 
     let norm = probs[outcome].sqrt();
     if norm == 0.0 {
@@ -60,6 +85,47 @@ pub fn measure_z(psi: &mut MPS, k: usize, rng: &mut ONDRng) -> u8 {
         }
     }
 
-    psi.sites[k] = t;
+    *psi.site_mut(k) = t;
     outcome as u8
 }
+
+/// Unnormalized outcome weights ⟨ψ|P_p^(k)|ψ⟩ for every local level p of site k.
+pub(crate) fn site_probs(psi: &MPS, k: usize) -> Vec<f64> {
+    let psi = &*open_view(psi);
+    let s = &psi.sites()[k];
+    let left = left_env(psi.sites(), k);
+    let right = right_env(psi.sites(), k);
+
+    let mut probs = vec![0.0f64; s.dp];
+    for (p, prob) in probs.iter_mut().enumerate() {
+        let mut acc = C64::new(0.0, 0.0);
+        for l in 0..s.dl {
+            for lp in 0..s.dl {
+                let lval = left[l * s.dl + lp];
+                for r in 0..s.dr {
+                    for rp in 0..s.dr {
+                        let rval = right[r * s.dr + rp];
+                        acc += lval
+                            * s.get(l, p, r)
+                            * s.get(lp, p, rp).conj()
+                            * rval;
+                    }
+                }
+            }
+        }
+        let val = acc.re;
+        *prob = if val < 0.0 { 0.0 } else { val };
+    }
+    probs
+}
+
+/// Outcome selected by `x` in [0, Σ probs); falls back to 0 if `x` overshoots.
+pub(crate) fn pick_outcome(probs: &[f64], mut x: f64) -> usize {
+    for (idx, p) in probs.iter().enumerate() {
+        if x < *p {
+            return idx;
+        }
+        x -= *p;
+    }
+    0
+}
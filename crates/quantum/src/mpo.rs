@@ -0,0 +1,572 @@
+use crate::env::{left_env, open_view};
+use crate::gates::{matmul2, pauli_x, pauli_y, pauli_z};
+use crate::hamiltonian::{Hamiltonian, Heisenberg, PauliSum};
+use crate::trotter::{bond_couplings, heisenberg_bond_gate, num_bonds};
+use tn::mps::{C64, MPS, Tensor3};
+use tn::truncation::Truncation;
+
+#[derive(Clone)]
+pub struct Tensor4 {
+    pub data: Vec<C64>,
+    pub wl: usize,
+    pub dp: usize,
+    pub wr: usize,
+}
+
+impl Tensor4 {
+    pub fn zeros(wl: usize, dp: usize, wr: usize) -> Self {
+        Self {
+            data: vec![C64::new(0.0, 0.0); wl * dp * dp * wr],
+            wl,
+            dp,
+            wr,
+        }
+    }
+
+    #[inline]
+    fn idx(&self, wl: usize, po: usize, pi: usize, wr: usize) -> usize {
+        ((wl * self.dp + po) * self.dp + pi) * self.wr + wr
+    }
+
+    /// Element W[wl, out, in, wr].
+    pub fn get(&self, wl: usize, po: usize, pi: usize, wr: usize) -> C64 {
+        self.data[self.idx(wl, po, pi, wr)]
+    }
+
+    pub fn set(&mut self, wl: usize, po: usize, pi: usize, wr: usize, v: C64) {
+        let i = self.idx(wl, po, pi, wr);
+        self.data[i] = v;
+    }
+
+    fn add_op(&mut self, wl: usize, wr: usize, coeff: f64, op: [[C64; 2]; 2]) {
+        for (po, row) in op.iter().enumerate() {
+            for (pi, &o) in row.iter().enumerate() {
+                let i = self.idx(wl, po, pi, wr);
+                self.data[i] += o * coeff;
+            }
+        }
+    }
+}
+
+/// Matrix product operator over qubit sites.
+#[derive(Clone)]
+pub struct Mpo {
+    pub sites: Vec<Tensor4>,
+}
+
+fn identity() -> [[C64; 2]; 2] {
+    let z = C64::new(0.0, 0.0);
+    let o = C64::new(1.0, 0.0);
+    [[o, z], [z, o]]
+}
+
+/// A nearest-neighbor channel c_i * A_i B_{i+1} with per-bond couplings.
+struct Channel<'a> {
+    left: [[C64; 2]; 2],
+    right: [[C64; 2]; 2],
+    couplings: &'a [f64],
+}
+
+/// Builds the standard finite-state-machine MPO for
+/// Σ_i f_i O_i + Σ_c Σ_i c_i A_i B_{i+1}.
+///
+/// Bond index 0 = "term finished", 1..=channels = "A placed, waiting for B",
+/// last = "nothing placed yet".
+fn nearest_neighbor_mpo(
+    n: usize,
+    fields: &[f64],
+    field_op: [[C64; 2]; 2],
+    channels: &[Channel],
+) -> Mpo {
+    let d = channels.len() + 2;
+    let start = d - 1;
+    let done = 0;
+
+    let mut sites = Vec::with_capacity(n);
+    for i in 0..n {
+        let mut w = Tensor4::zeros(d, 2, d);
+
+        w.add_op(start, start, 1.0, identity());
+        w.add_op(done, done, 1.0, identity());
+        if let Some(&f) = fields.get(i) {
+            if f != 0.0 {
+                w.add_op(start, done, f, field_op);
+            }
+        }
+        for (c, ch) in channels.iter().enumerate() {
+            if let Some(&j) = ch.couplings.get(i) {
+                w.add_op(start, c + 1, j, ch.left);
+            }
+            w.add_op(c + 1, done, 1.0, ch.right);
+        }
+
+        sites.push(w);
+    }
+
+    // Boundary vectors: the left edge starts in `start`, the right edge ends in `done`.
+    if let Some(first) = sites.first_mut() {
+        let mut edge = Tensor4::zeros(1, 2, first.wr);
+        for po in 0..2 {
+            for pi in 0..2 {
+                for r in 0..first.wr {
+                    edge.set(0, po, pi, r, first.get(start, po, pi, r));
+                }
+            }
+        }
+        *first = edge;
+    }
+    if let Some(last) = sites.last_mut() {
+        let mut edge = Tensor4::zeros(last.wl, 2, 1);
+        for l in 0..last.wl {
+            for po in 0..2 {
+                for pi in 0..2 {
+                    edge.set(l, po, pi, 0, last.get(l, po, pi, done));
+                }
+            }
+        }
+        *last = edge;
+    }
+
+    Mpo { sites }
+}
+
+impl Mpo {
+    /// Bond-dimension-1 MPO for the product operator ⊗_k ops[k].
+    pub fn product(ops: &[[[C64; 2]; 2]]) -> Self {
+        let sites = ops
+            .iter()
+            .map(|&op| {
+                let mut w = Tensor4::zeros(1, 2, 1);
+                w.add_op(0, 0, 1.0, op);
+                w
+            })
+            .collect();
+        Self { sites }
+    }
+
+    /// Contract the MPO into ψ site by site, then re-truncate the enlarged bonds.
+    ///
+    /// Panics on a periodic MPS, leaving it unchanged; convert with `MPS::to_open` first.
+    pub fn apply(&self, psi: &mut MPS, trunc: Truncation) {
+        self.contract(psi);
+        psi.compress(trunc);
+    }
+
+    /// Exact W|ψ⟩: bond dimensions multiply by the MPO's and nothing is truncated.
+    ///
+    /// Open chains only; a ring is rejected before any site is touched, since the
+    /// compression in `apply` cannot handle the boundary bond.
+    pub(crate) fn contract(&self, psi: &mut MPS) {
+        assert!(!psi.is_periodic(), "Mpo::apply needs an open-boundary MPS");
+        assert_eq!(psi.sites().len(), self.sites.len(), "MPS/MPO length mismatch");
+
+        for (a, w) in psi.sites_mut().iter_mut().zip(self.sites.iter()) {
+            assert!(a.dp == w.dp, "MPS/MPO physical dimension mismatch");
+            let mut out = Tensor3::zeros(a.dl * w.wl, a.dp, a.dr * w.wr);
+            for l in 0..a.dl {
+                for wl in 0..w.wl {
+                    for wr in 0..w.wr {
+                        for po in 0..a.dp {
+                            for pi in 0..a.dp {
+                                let wval = w.get(wl, po, pi, wr);
+                                if wval == C64::new(0.0, 0.0) {
+                                    continue;
+                                }
+                                for r in 0..a.dr {
+                                    let ol = l * w.wl + wl;
+                                    let or = r * w.wr + wr;
+                                    let cur = out.get(ol, po, or);
+                                    out.set(ol, po, or, cur + wval * a.get(l, pi, r));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            *a = out;
+        }
+    }
+
+    /// MPO for the diagonal Z/ZZ Hamiltonian.
+    pub fn from_hamiltonian(h: &Hamiltonian) -> Self {
+        hamiltonian_mpo(h, h.z_fields.len().max(h.zz_couplings.len() + 1))
+    }
+
+    /// MPO for nearest-neighbor Heisenberg (XX + YY + ZZ).
+    pub fn from_heisenberg(h: &Heisenberg) -> Self {
+        heisenberg_mpo(h, h.jx.len().max(h.jy.len()).max(h.jz.len()) + 1)
+    }
+
+    /// MPO for a general Pauli sum on `h.n` qubits.
+    pub fn from_pauli_sum(h: &PauliSum) -> Self {
+        pauli_sum_mpo(h, h.n)
+    }
+}
+
+/// `Mpo::from_hamiltonian` on an n-site chain; terms past the chain are dropped.
+pub(crate) fn hamiltonian_mpo(h: &Hamiltonian, n: usize) -> Mpo {
+    let channels = [Channel {
+        left: pauli_z(),
+        right: pauli_z(),
+        couplings: &h.zz_couplings,
+    }];
+    nearest_neighbor_mpo(n, &h.z_fields, pauli_z(), &channels)
+}
+
+/// `Mpo::from_heisenberg` on an n-site chain; bonds past the chain are dropped.
+pub(crate) fn heisenberg_mpo(h: &Heisenberg, n: usize) -> Mpo {
+    let channels = [
+        Channel {
+            left: pauli_x(),
+            right: pauli_x(),
+            couplings: &h.jx,
+        },
+        Channel {
+            left: pauli_y(),
+            right: pauli_y(),
+            couplings: &h.jy,
+        },
+        Channel {
+            left: pauli_z(),
+            right: pauli_z(),
+            couplings: &h.jz,
+        },
+    ];
+    nearest_neighbor_mpo(n, &[], pauli_z(), &channels)
+}
+
+/// Finite-state-machine MPO for Σ_t c_t ∏_k P_{t,k} on n sites.
+///
+/// Same layout as `nearest_neighbor_mpo` (0 = "term finished", 1 = "nothing placed
+/// yet"), plus one state per term on every bond strictly inside its support, so bond b
+/// carries 2 + (number of terms spanning b) states. Repeated sites in a term are
+/// multiplied in list order, as in `expect_pauli_string`.
+pub(crate) fn pauli_sum_mpo(h: &PauliSum, n: usize) -> Mpo {
+    const DONE: usize = 0;
+    const START: usize = 1;
+
+    // Each term as (coeff, sorted [(site, local product)]); the empty term is c·I on site 0.
+    let mut terms: Vec<(f64, Vec<(usize, Mat2)>)> = Vec::with_capacity(h.terms.len());
+    for t in &h.terms {
+        let mut local: Vec<(usize, Mat2)> = Vec::new();
+        for &(k, p) in &t.ops {
+            assert!(k < n, "pauli_sum_mpo: site {} out of range", k);
+            let i = match local.iter().position(|&(j, _)| j == k) {
+                Some(i) => i,
+                None => {
+                    local.push((k, identity()));
+                    local.len() - 1
+                }
+            };
+            local[i].1 = matmul2(p.matrix(), local[i].1);
+        }
+        if local.is_empty() {
+            if n == 0 {
+                continue;
+            }
+            local.push((0, identity()));
+        }
+        local.sort_by_key(|&(k, _)| k);
+        terms.push((t.coeff, local));
+    }
+
+    // slot[b][t]: bond-b state of term t while it is open across bond b.
+    let bonds = n.saturating_sub(1);
+    let mut slot = vec![vec![None; terms.len()]; bonds];
+    let mut dims = vec![2usize; bonds];
+    for (t, (_, local)) in terms.iter().enumerate() {
+        let (first, last) = (local[0].0, local[local.len() - 1].0);
+        for b in first..last {
+            slot[b][t] = Some(dims[b]);
+            dims[b] += 1;
+        }
+    }
+
+    let mut sites = Vec::with_capacity(n);
+    for i in 0..n {
+        // Edges keep only the "nothing placed" row on the left and "finished" column on the right.
+        let wl = if i == 0 { 1 } else { dims[i - 1] };
+        let wr = if i + 1 == n { 1 } else { dims[i] };
+        let row = |state: usize| match i {
+            0 => (state == START).then_some(0),
+            _ => Some(state),
+        };
+        let col = |state: usize| match i + 1 == n {
+            true => (state == DONE).then_some(0),
+            false => Some(state),
+        };
+
+        let mut w = Tensor4::zeros(wl, 2, wr);
+        for (from, to) in [(START, START), (DONE, DONE)] {
+            if let (Some(l), Some(r)) = (row(from), col(to)) {
+                w.add_op(l, r, 1.0, identity());
+            }
+        }
+        for (t, (coeff, local)) in terms.iter().enumerate() {
+            let (first, last) = (local[0].0, local[local.len() - 1].0);
+            if i < first || i > last {
+                continue;
+            }
+            let op = local
+                .iter()
+                .find(|&&(k, _)| k == i)
+                .map_or_else(identity, |&(_, m)| m);
+            let (from, c) = if i == first {
+                (START, *coeff)
+            } else {
+                (slot[i - 1][t].unwrap(), 1.0)
+            };
+            let to = if i == last { DONE } else { slot[i][t].unwrap() };
+            if let (Some(l), Some(r)) = (row(from), col(to)) {
+                w.add_op(l, r, c, op);
+            }
+        }
+        sites.push(w);
+    }
+
+    Mpo { sites }
+}
+
+type Mat2 = [[C64; 2]; 2];
+
+/// Splits a two-site gate into Σ_a σ_a ⊗ B_a using the Pauli basis on the left site.
+fn split_two_site(g: [[C64; 4]; 4]) -> Vec<(Mat2, Mat2)> {
+    let basis = [identity(), pauli_x(), pauli_y(), pauli_z()];
+    let mut terms = Vec::with_capacity(4);
+    for &sa in &basis {
+        let mut b = [[C64::new(0.0, 0.0); 2]; 2];
+        let mut nonzero = false;
+        for &sb in &basis {
+            // c_ab = Tr((σ_a ⊗ σ_b)† G) / 4
+            let mut c = C64::new(0.0, 0.0);
+            for i in 0..2 {
+                for j in 0..2 {
+                    for k in 0..2 {
+                        for l in 0..2 {
+                            c += (sa[i][j] * sb[k][l]).conj() * g[i * 2 + k][j * 2 + l];
+                        }
+                    }
+                }
+            }
+            c /= 4.0;
+            if c.norm() > 1e-15 {
+                nonzero = true;
+                for k in 0..2 {
+                    for l in 0..2 {
+                        b[k][l] += c * sb[k][l];
+                    }
+                }
+            }
+        }
+        if nonzero {
+            terms.push((sa, b));
+        }
+    }
+    terms
+}
+
+/// One brick layer of two-site gates on bonds start, start+2, ... as an MPO.
+fn bond_layer_mpo(n: usize, start: usize, gate: impl Fn(usize) -> [[C64; 4]; 4]) -> Vec<Tensor4> {
+    let mut sites: Vec<Tensor4> = (0..n)
+        .map(|_| {
+            let mut w = Tensor4::zeros(1, 2, 1);
+            w.add_op(0, 0, 1.0, identity());
+            w
+        })
+        .collect();
+
+    let mut b = start;
+    while b + 1 < n {
+        let terms = split_two_site(gate(b));
+        let chi = terms.len();
+        let mut left = Tensor4::zeros(1, 2, chi);
+        let mut right = Tensor4::zeros(chi, 2, 1);
+        for (t, (a_op, b_op)) in terms.into_iter().enumerate() {
+            left.add_op(0, t, 1.0, a_op);
+            right.add_op(t, 0, 1.0, b_op);
+        }
+        sites[b] = left;
+        sites[b + 1] = right;
+        b += 2;
+    }
+    sites
+}
+
+/// Operator product outer · inner on a single site (inner acts first).
+fn compose(outer: &Tensor4, inner: &Tensor4) -> Tensor4 {
+    let dp = outer.dp;
+    let mut out = Tensor4::zeros(outer.wl * inner.wl, dp, outer.wr * inner.wr);
+    for lo in 0..outer.wl {
+        for li in 0..inner.wl {
+            for ro in 0..outer.wr {
+                for ri in 0..inner.wr {
+                    for po in 0..dp {
+                        for pi in 0..dp {
+                            let mut acc = C64::new(0.0, 0.0);
+                            for q in 0..dp {
+                                acc += outer.get(lo, po, q, ro) * inner.get(li, q, pi, ri);
+                            }
+                            out.set(lo * inner.wl + li, po, pi, ro * inner.wr + ri, acc);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    out
+}
+
+/// First-order Trotter step exp(-i dt H_odd) exp(-i dt H_even) as a single MPO.
+///
+/// Matches `trotter::trotter_step_heisenberg`, but `Mpo::apply` contracts it in one pass.
+pub fn evolution_mpo_heisenberg(h: &Heisenberg, dt: f64) -> Mpo {
+    let n = num_bonds(h) + 1;
+    let gate = |b: usize| {
+        let (jx, jy, jz) = bond_couplings(h, b);
+        heisenberg_bond_gate(jx, jy, jz, dt)
+    };
+
+    let even = bond_layer_mpo(n, 0, gate);
+    let odd = bond_layer_mpo(n, 1, gate);
+    let sites = odd
+        .iter()
+        .zip(even.iter())
+        .map(|(o, e)| compose(o, e))
+        .collect();
+
+    Mpo { sites }
+}
+
+/// Left operator environment over sites 0..k, indexed [(a * w_dim + w) * a_dim + a'].
+pub(crate) fn mpo_left_env(sites: &[Tensor3], w: &[Tensor4], k: usize) -> Vec<C64> {
+    let mut env = vec![C64::new(1.0, 0.0)];
+    let mut wd = 1usize;
+    for i in 0..k {
+        let a = &sites[i];
+        let o = &w[i];
+        let mut next = vec![C64::new(0.0, 0.0); a.dr * o.wr * a.dr];
+        for l in 0..a.dl {
+            for wl in 0..o.wl {
+                for lp in 0..a.dl {
+                    let lval = env[(l * wd + wl) * a.dl + lp];
+                    if lval == C64::new(0.0, 0.0) {
+                        continue;
+                    }
+                    for wr in 0..o.wr {
+                        for po in 0..a.dp {
+                            for pi in 0..a.dp {
+                                let wval = o.get(wl, po, pi, wr);
+                                if wval == C64::new(0.0, 0.0) {
+                                    continue;
+                                }
+                                for r in 0..a.dr {
+                                    let ket = lval * wval * a.get(l, pi, r);
+                                    for rp in 0..a.dr {
+                                        let idx = (r * o.wr + wr) * a.dr + rp;
+                                        next[idx] += ket * a.get(lp, po, rp).conj();
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        env = next;
+        wd = o.wr;
+    }
+    env
+}
+
+/// Right operator environment over sites k+1..n, indexed [(a * w_dim + w) * a_dim + a'].
+pub(crate) fn mpo_right_env(sites: &[Tensor3], w: &[Tensor4], k: usize) -> Vec<C64> {
+    let mut env = vec![C64::new(1.0, 0.0)];
+    let mut wd = 1usize;
+    for i in (k + 1..sites.len()).rev() {
+        let a = &sites[i];
+        let o = &w[i];
+        let mut next = vec![C64::new(0.0, 0.0); a.dl * o.wl * a.dl];
+        for r in 0..a.dr {
+            for wr in 0..o.wr {
+                for rp in 0..a.dr {
+                    let rval = env[(r * wd + wr) * a.dr + rp];
+                    if rval == C64::new(0.0, 0.0) {
+                        continue;
+                    }
+                    for wl in 0..o.wl {
+                        for po in 0..a.dp {
+                            for pi in 0..a.dp {
+                                let wval = o.get(wl, po, pi, wr);
+                                if wval == C64::new(0.0, 0.0) {
+                                    continue;
+                                }
+                                for l in 0..a.dl {
+                                    let ket = rval * wval * a.get(l, pi, r);
+                                    for lp in 0..a.dl {
+                                        let idx = (l * o.wl + wl) * a.dl + lp;
+                                        next[idx] += ket * a.get(lp, po, rp).conj();
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        env = next;
+        wd = o.wl;
+    }
+    env
+}
+
+/// Expectation value ⟨ψ|W|ψ⟩ / ⟨ψ|ψ⟩ via a single left/right environment sweep.
+pub fn expect_mpo(psi: &MPS, w: &Mpo) -> f64 {
+    assert_eq!(psi.sites().len(), w.sites.len(), "MPS/MPO length mismatch");
+    let n = psi.sites().len();
+    if n == 0 {
+        return 0.0;
+    }
+    let psi = &*open_view(psi);
+
+    let k = n / 2;
+    let a = &psi.sites()[k];
+    let o = &w.sites[k];
+    let left = mpo_left_env(psi.sites(), &w.sites, k);
+    let right = mpo_right_env(psi.sites(), &w.sites, k);
+
+    let mut acc = C64::new(0.0, 0.0);
+    for l in 0..a.dl {
+        for wl in 0..o.wl {
+            for lp in 0..a.dl {
+                let lval = left[(l * o.wl + wl) * a.dl + lp];
+                if lval == C64::new(0.0, 0.0) {
+                    continue;
+                }
+                for wr in 0..o.wr {
+                    for po in 0..a.dp {
+                        for pi in 0..a.dp {
+                            let wval = o.get(wl, po, pi, wr);
+                            if wval == C64::new(0.0, 0.0) {
+                                continue;
+                            }
+                            for r in 0..a.dr {
+                                let ket = lval * wval * a.get(l, pi, r);
+                                for rp in 0..a.dr {
+                                    let rval = right[(r * o.wr + wr) * a.dr + rp];
+                                    acc += ket * a.get(lp, po, rp).conj() * rval;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let norm = left_env(psi.sites(), n)[0].re;
+    if norm == 0.0 {
+        return 0.0;
+    }
+
+    acc.re / norm
+}
@@ -1,6 +1,14 @@
-use crate::gates::{pauli_x, pauli_y, pauli_z};
+use crate::gates::{kron, matmul4, pauli_x, pauli_y, pauli_z, C64};
 use rng::ONDRng;
-use tn::mps::MPS;
+use tn::{mps::MPS, truncation::Truncation};
+
+/// RNG context for a noise draw on site k, so each qubit gets its own substream label.
+fn site_ctx(tag: &[u8], k: usize) -> Vec<u8> {
+    let mut ctx = Vec::with_capacity(tag.len() + 8);
+    ctx.extend_from_slice(tag);
+    ctx.extend_from_slice(&(k as u64).to_be_bytes());
+    ctx
+}
 
 /// Single-qubit depolarizing channel implemented via random Pauli kicks.
 pub fn depolarizing_1q(psi: &mut MPS, k: usize, p: f64, rng: &mut ONDRng) {
@@ -8,7 +16,7 @@ pub fn depolarizing_1q(psi: &mut MPS, k: usize, p: f64, rng: &mut ONDRng) {
         return;
     }
 
-    let x = rng.next_f64(b"DEPOL_1Q");
+    let x = rng.next_f64(&site_ctx(b"DEPOL_1Q", k));
     if x >= p {
         return;
     }
@@ -22,3 +30,114 @@ pub fn depolarizing_1q(psi: &mut MPS, k: usize, p: f64, rng: &mut ONDRng) {
         psi.apply_1q(k, pauli_z());
     }
 }
+
+/// I, X, Y, Z for i = 0..4.
+fn pauli(i: usize) -> [[C64; 2]; 2] {
+    match i {
+        0 => [
+            [C64::new(1.0, 0.0), C64::new(0.0, 0.0)],
+            [C64::new(0.0, 0.0), C64::new(1.0, 0.0)],
+        ],
+        1 => pauli_x(),
+        2 => pauli_y(),
+        _ => pauli_z(),
+    }
+}
+
+/// I, X, Y, Z for a uniform draw in [0, 1).
+fn random_pauli(u: f64) -> [[C64; 2]; 2] {
+    pauli(((u * 4.0) as usize).min(3))
+}
+
+/// Two-qubit depolarizing channel on (k, k+1) via random Pauli kicks.
+///
+/// With probability p one of the 15 non-identity products P_k ⊗ P_{k+1} is applied,
+/// each equally likely. The kicks are single-site, so no truncation happens.
+pub fn depolarizing_2q(psi: &mut MPS, k: usize, p: f64, rng: &mut ONDRng) {
+    psi.assert_bond(k, "depolarizing_2q");
+    if p <= 0.0 {
+        return;
+    }
+
+    let x = rng.next_f64(&site_ctx(b"DEPOL_2Q", k));
+    if x >= p {
+        return;
+    }
+
+    let idx = 1 + ((x / p * 15.0) as usize).min(14);
+    let (a, b) = (idx / 4, idx % 4);
+    if a != 0 {
+        psi.apply_1q(k, pauli(a));
+    }
+    if b != 0 {
+        psi.apply_1q(k + 1, pauli(b));
+    }
+}
+
+fn dagger4(a: [[C64; 4]; 4]) -> [[C64; 4]; 4] {
+    let mut out = [[C64::new(0.0, 0.0); 4]; 4];
+    for i in 0..4 {
+        for j in 0..4 {
+            out[i][j] = a[j][i].conj();
+        }
+    }
+    out
+}
+
+/// Split `u` as phase · (P_a ⊗ P_b) when it is a Pauli product up to phase.
+fn pauli_product(u: [[C64; 4]; 4]) -> Option<(C64, usize, usize)> {
+    for a in 0..4 {
+        for b in 0..4 {
+            let p = kron(pauli(a), pauli(b));
+            // Paulis are orthonormal under tr(P† U) / 4, so |overlap| = 1 iff U ∝ P.
+            let mut overlap = C64::new(0.0, 0.0);
+            for i in 0..4 {
+                for j in 0..4 {
+                    overlap += p[i][j].conj() * u[i][j];
+                }
+            }
+            overlap /= 4.0;
+            if (overlap.norm() - 1.0).abs() < 1e-12 {
+                return Some((overlap, a, b));
+            }
+        }
+    }
+    None
+}
+
+/// Pauli-twirled two-qubit gate on (k, k+1).
+///
+/// Draws a random Pauli frame P = P_k ⊗ P_{k+1}, applies P, then `actual`, then the
+/// correction `ideal · P · ideal†`. When `actual == ideal` the net operation is exactly
+/// `ideal`; averaged over trajectories, any error in `actual` becomes a Pauli channel.
+/// For Clifford gates the correction is itself a Pauli product and is applied as two
+/// single-site gates; otherwise it costs a second SVD. Returns true when a truncation
+/// saturated `max_bond`.
+pub fn pauli_twirl_2q(
+    psi: &mut MPS,
+    k: usize,
+    ideal: [[C64; 4]; 4],
+    actual: [[C64; 4]; 4],
+    trunc: Truncation,
+    rng: &mut ONDRng,
+) -> bool {
+    let pa = random_pauli(rng.next_f64(&site_ctx(b"TWIRL_2Q", k)));
+    let pb = random_pauli(rng.next_f64(&site_ctx(b"TWIRL_2Q", k + 1)));
+    let correction = matmul4(matmul4(ideal, kron(pa, pb)), dagger4(ideal));
+
+    psi.apply_1q(k, pa);
+    psi.apply_1q(k + 1, pb);
+    let mut saturated = psi.apply_2q_svd(k, actual, trunc);
+    match pauli_product(correction) {
+        Some((phase, a, b)) => {
+            let mut qa = pauli(a);
+            for v in qa.iter_mut().flatten() {
+                *v *= phase;
+            }
+            psi.apply_1q(k, qa);
+            psi.apply_1q(k + 1, pauli(b));
+        }
+        None => saturated |= psi.apply_2q_svd(k, correction, trunc),
+    }
+    saturated
+}
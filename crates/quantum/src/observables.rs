@@ -1,11 +1,66 @@
-use crate::env::{left_env, right_env};
-use crate::gates::{pauli_x, pauli_y};
+use crate::env::{left_env, open_view, right_env};
+use crate::gates::{kron, pauli_x, pauli_y, pauli_z, Pauli};
+use crate::hamiltonian::{PauliSum, PauliTerm};
+use crate::mpo::{expect_mpo, Mpo};
 use tn::mps::{C64, MPS};
 
+/// A measurable quantity ⟨ψ|O|ψ⟩ / ⟨ψ|ψ⟩.
+///
+/// Implement it for custom operators to feed them to `energy::energy_observables`
+/// alongside the built-in single-site, two-site and Pauli-string observables.
+pub trait Observable {
+    fn expectation(&self, psi: &MPS) -> f64;
+}
+
+/// A Hermitian one-qubit operator on site `k`.
+#[derive(Clone, Copy, Debug)]
+pub struct SingleSite {
+    pub k: usize,
+    pub op: [[C64; 2]; 2],
+}
+
+impl Observable for SingleSite {
+    fn expectation(&self, psi: &MPS) -> f64 {
+        expect_single_site(psi, self.k, self.op, "SingleSite")
+    }
+}
+
+/// A Hermitian two-qubit operator on (k, k+1), indexed like `kron(a, b)`.
+#[derive(Clone, Copy, Debug)]
+pub struct TwoSite {
+    pub k: usize,
+    pub op: [[C64; 4]; 4],
+}
+
+impl Observable for TwoSite {
+    fn expectation(&self, psi: &MPS) -> f64 {
+        expect_two_site(psi, self.k, self.k + 1, self.op, "TwoSite")
+    }
+}
+
+impl Observable for PauliTerm {
+    fn expectation(&self, psi: &MPS) -> f64 {
+        self.coeff * expect_pauli_string(psi, &self.ops)
+    }
+}
+
+impl Observable for PauliSum {
+    fn expectation(&self, psi: &MPS) -> f64 {
+        self.terms.iter().map(|t| t.expectation(psi)).sum()
+    }
+}
+
+#[track_caller]
+fn assert_site(psi: &MPS, k: usize, op: &str) {
+    let n = psi.sites().len();
+    assert!(k < n, "{}: site {} out of range for {}-site MPS", op, k, n);
+}
+
 fn site_weight(psi: &MPS, k: usize, p: usize) -> f64 {
-    let s = &psi.sites[k];
-    let left = left_env(&psi.sites, k);
-    let right = right_env(&psi.sites, k);
+    let psi = &*open_view(psi);
+    let s = &psi.sites()[k];
+    let left = left_env(psi.sites(), k);
+    let right = right_env(psi.sites(), k);
 
     let mut acc = C64::new(0.0, 0.0);
     for l in 0..s.dl {
@@ -25,9 +80,10 @@ fn site_weight(psi: &MPS, k: usize, p: usize) -> f64 {
 }
 
 fn site_element(psi: &MPS, k: usize, p: usize, pp: usize) -> C64 {
-    let s = &psi.sites[k];
-    let left = left_env(&psi.sites, k);
-    let right = right_env(&psi.sites, k);
+    let psi = &*open_view(psi);
+    let s = &psi.sites()[k];
+    let left = left_env(psi.sites(), k);
+    let right = right_env(psi.sites(), k);
 
     let mut acc = C64::new(0.0, 0.0);
     for l in 0..s.dl {
@@ -44,10 +100,47 @@ fn site_element(psi: &MPS, k: usize, p: usize, pp: usize) -> C64 {
     acc
 }
 
-fn expect_single_site(psi: &MPS, k: usize, op: [[C64; 2]; 2]) -> f64 {
-    let s = &psi.sites[k];
+/// Unnormalized reduced density matrix of site k, when k is the orthogonality center.
+///
+/// Both environments are identities there, so this is O(χ²) instead of two full sweeps.
+fn center_rho(psi: &MPS, k: usize) -> Option<[[C64; 2]; 2]> {
+    if psi.orthogonality_center() != Some(k) {
+        return None;
+    }
+    let s = &psi.sites()[k];
+    let mut rho = [[C64::new(0.0, 0.0); 2]; 2];
+    for (p, row) in rho.iter_mut().enumerate() {
+        for (pp, v) in row.iter_mut().enumerate() {
+            for l in 0..s.dl {
+                for r in 0..s.dr {
+                    *v += s.get(l, p, r) * s.get(l, pp, r).conj();
+                }
+            }
+        }
+    }
+    Some(rho)
+}
+
+fn expect_single_site(psi: &MPS, k: usize, op: [[C64; 2]; 2], name: &str) -> f64 {
+    assert_site(psi, k, name);
+    let s = &psi.sites()[k];
     assert!(s.dp == 2, "expect_single_site supports qubits only");
 
+    if let Some(rho) = center_rho(psi, k) {
+        let denom = rho[0][0].re + rho[1][1].re;
+        if denom == 0.0 {
+            return 0.0;
+        }
+        // Tr(ρ O) with ρ[p][pp] = ψ_p ψ*_pp.
+        let mut numer = C64::new(0.0, 0.0);
+        for (p, row) in rho.iter().enumerate() {
+            for (pp, &r) in row.iter().enumerate() {
+                numer += op[pp][p] * r;
+            }
+        }
+        return numer.re / denom;
+    }
+
     let w0 = site_weight(psi, k, 0);
     let w1 = site_weight(psi, k, 1);
     let denom = w0 + w1;
@@ -55,10 +148,11 @@ fn expect_single_site(psi: &MPS, k: usize, op: [[C64; 2]; 2]) -> f64 {
         return 0.0;
     }
 
+    // site_element(p, pp) = ψ_p ψ*_pp pairs with ⟨pp|O|p⟩.
     let mut numer = C64::new(0.0, 0.0);
-    for p in 0..2 {
-        for pp in 0..2 {
-            numer += op[p][pp] * site_element(psi, k, p, pp);
+    for (pp, row) in op.iter().enumerate() {
+        for (p, &o) in row.iter().enumerate() {
+            numer += o * site_element(psi, k, p, pp);
         }
     }
 
@@ -67,11 +161,14 @@ fn expect_single_site(psi: &MPS, k: usize, op: [[C64; 2]; 2]) -> f64 {
 
 /// Expectation value ⟨Z_k⟩ for a qubit at site k.
 pub fn expect_z(psi: &MPS, k: usize) -> f64 {
-    let s = &psi.sites[k];
+    assert_site(psi, k, "expect_z");
+    let s = &psi.sites()[k];
     assert!(s.dp == 2, "expect_z supports qubits only");
 
-    let w0 = site_weight(psi, k, 0);
-    let w1 = site_weight(psi, k, 1);
+    let (w0, w1) = match center_rho(psi, k) {
+        Some(rho) => (rho[0][0].re, rho[1][1].re),
+        None => (site_weight(psi, k, 0), site_weight(psi, k, 1)),
+    };
     let denom = w0 + w1;
 
     if denom == 0.0 {
@@ -83,29 +180,38 @@ pub fn expect_z(psi: &MPS, k: usize) -> f64 {
 
 /// Expectation value ⟨X_k⟩ for a qubit at site k.
 pub fn expect_x(psi: &MPS, k: usize) -> f64 {
-    expect_single_site(psi, k, pauli_x())
+    expect_single_site(psi, k, pauli_x(), "expect_x")
 }
 
 /// Expectation value ⟨Y_k⟩ for a qubit at site k.
 pub fn expect_y(psi: &MPS, k: usize) -> f64 {
-    expect_single_site(psi, k, pauli_y())
+    expect_single_site(psi, k, pauli_y(), "expect_y")
+}
+
+/// Total Z-magnetization Σ_k ⟨Z_k⟩ (twice the spin-½ S^z_tot); n - 2·(number of ones).
+///
+/// Conserved by any evolution with U(1) symmetry (XXZ with jx = jy, or ZZ-only).
+pub fn total_sz(psi: &MPS) -> f64 {
+    (0..psi.sites().len()).map(|k| expect_z(psi, k)).sum()
 }
 
 /// Expectation value ⟨Z_i Z_j⟩ for nearest neighbors (i, i+1).
 pub fn expect_zz(psi: &MPS, i: usize, j: usize) -> f64 {
     assert!(j == i + 1, "expect_zz supports nearest neighbors only");
+    assert_site(psi, j, "expect_zz");
+    let psi = &*open_view(psi);
 
-    let a = &psi.sites[i];
-    let b = &psi.sites[j];
+    let a = &psi.sites()[i];
+    let b = &psi.sites()[j];
     assert!(a.dp == 2 && b.dp == 2, "expect_zz supports qubits only");
 
-    let left = left_env(&psi.sites, i);
-    let right = right_env(&psi.sites, j);
+    let left = left_env(psi.sites(), i);
+    let right = right_env(psi.sites(), j);
 
     let mut weights = [[0.0f64; 2]; 2];
 
-    for pi in 0..2 {
-        for pj in 0..2 {
+    for (pi, row) in weights.iter_mut().enumerate() {
+        for (pj, w) in row.iter_mut().enumerate() {
             let mut acc = C64::new(0.0, 0.0);
             for l in 0..a.dl {
                 for lp in 0..a.dl {
@@ -128,7 +234,7 @@ pub fn expect_zz(psi: &MPS, i: usize, j: usize) -> f64 {
                 }
             }
             let val = acc.re;
-            weights[pi][pj] = if val < 0.0 { 0.0 } else { val };
+            *w = if val < 0.0 { 0.0 } else { val };
         }
     }
 
@@ -141,15 +247,27 @@ pub fn expect_zz(psi: &MPS, i: usize, j: usize) -> f64 {
     numer / denom
 }
 
-fn expect_two_site(psi: &MPS, i: usize, j: usize, op: [[C64; 4]; 4]) -> f64 {
-    assert!(j == i + 1, "expect_two_site supports nearest neighbors only");
+fn expect_two_site(psi: &MPS, i: usize, j: usize, op: [[C64; 4]; 4], name: &str) -> f64 {
+    assert!(j == i + 1, "{} supports nearest neighbors only", name);
+    assert_site(psi, j, name);
 
-    let a = &psi.sites[i];
-    let b = &psi.sites[j];
-    assert!(a.dp == 2 && b.dp == 2, "expect_two_site supports qubits only");
+    let psi = &*open_view(psi);
+    let left = left_env(psi.sites(), i);
+    let right = right_env(psi.sites(), j);
+    expect_two_site_env(psi, i, op, &left, &right)
+}
 
-    let left = left_env(&psi.sites, i);
-    let right = right_env(&psi.sites, j);
+/// `expect_two_site` on bond (i, i+1) with precomputed `left_env(i)` / `right_env(i + 1)`.
+pub(crate) fn expect_two_site_env(
+    psi: &MPS,
+    i: usize,
+    op: [[C64; 4]; 4],
+    left: &[C64],
+    right: &[C64],
+) -> f64 {
+    let a = &psi.sites()[i];
+    let b = &psi.sites()[i + 1];
+    assert!(a.dp == 2 && b.dp == 2, "expect_two_site supports qubits only");
 
     let mut denom = 0.0f64;
     let mut numer = C64::new(0.0, 0.0);
@@ -158,7 +276,8 @@ fn expect_two_site(psi: &MPS, i: usize, j: usize, op: [[C64; 4]; 4]) -> f64 {
         for pj in 0..2 {
             for qi in 0..2 {
                 for qj in 0..2 {
-                    let op_val = op[pi * 2 + pj][qi * 2 + qj];
+                    // Ket (pi, pj), bra (qi, qj): the term is ⟨qi qj|O|pi pj⟩.
+                    let op_val = op[qi * 2 + qj][pi * 2 + pj];
                     let mut acc = C64::new(0.0, 0.0);
                     for l in 0..a.dl {
                         for lp in 0..a.dl {
@@ -198,26 +317,146 @@ fn expect_two_site(psi: &MPS, i: usize, j: usize, op: [[C64; 4]; 4]) -> f64 {
     numer.re / denom
 }
 
-fn kron(a: [[C64; 2]; 2], b: [[C64; 2]; 2]) -> [[C64; 4]; 4] {
-    let mut out = [[C64::new(0.0, 0.0); 4]; 4];
+/// Expectation value ⟨X_i X_j⟩ for nearest neighbors.
+pub fn expect_xx(psi: &MPS, i: usize, j: usize) -> f64 {
+    expect_two_site(psi, i, j, kron(pauli_x(), pauli_x()), "expect_xx")
+}
+
+/// Expectation value ⟨Y_i Y_j⟩ for nearest neighbors.
+pub fn expect_yy(psi: &MPS, i: usize, j: usize) -> f64 {
+    expect_two_site(psi, i, j, kron(pauli_y(), pauli_y()), "expect_yy")
+}
+
+/// CHSH combination ⟨A0 B0⟩ + ⟨A0 B1⟩ + ⟨A1 B0⟩ - ⟨A1 B1⟩ for qubits (0, 1),
+/// with A0 = Z, A1 = X, B0 = (Z + X)/√2, B1 = (Z - X)/√2.
+///
+/// Local hidden-variable models satisfy |S| ≤ 2; |Φ+⟩ reaches 2√2.
+pub fn chsh_value(psi: &MPS) -> f64 {
+    let z = pauli_z();
+    let x = pauli_x();
+    let h = std::f64::consts::FRAC_1_SQRT_2;
+
+    let mut b0 = [[C64::new(0.0, 0.0); 2]; 2];
+    let mut b1 = [[C64::new(0.0, 0.0); 2]; 2];
     for i in 0..2 {
         for j in 0..2 {
-            for k in 0..2 {
-                for l in 0..2 {
-                    out[i * 2 + k][j * 2 + l] = a[i][j] * b[k][l];
+            b0[i][j] = (z[i][j] + x[i][j]) * h;
+            b1[i][j] = (z[i][j] - x[i][j]) * h;
+        }
+    }
+
+    expect_two_site(psi, 0, 1, kron(z, b0), "chsh_value")
+        + expect_two_site(psi, 0, 1, kron(z, b1), "chsh_value")
+        + expect_two_site(psi, 0, 1, kron(x, b0), "chsh_value")
+        - expect_two_site(psi, 0, 1, kron(x, b1), "chsh_value")
+}
+
+/// Expectation value ⟨A_i B_{i+1}⟩ for nearest neighbors with arbitrary local dimension.
+#[allow(clippy::needless_range_loop)] // ket and bra indices address the operators transposed
+pub fn expect_pair(psi: &MPS, i: usize, op_a: &[Vec<C64>], op_b: &[Vec<C64>]) -> f64 {
+    assert_site(psi, i + 1, "expect_pair");
+    let psi = &*open_view(psi);
+    let a = &psi.sites()[i];
+    let b = &psi.sites()[i + 1];
+    assert!(
+        op_a.len() == a.dp && op_b.len() == b.dp,
+        "expect_pair operator/physical dimension mismatch"
+    );
+
+    let left = left_env(psi.sites(), i);
+    let right = right_env(psi.sites(), i + 1);
+
+    let mut denom = 0.0f64;
+    let mut numer = C64::new(0.0, 0.0);
+
+    for pi in 0..a.dp {
+        for pj in 0..b.dp {
+            for qi in 0..a.dp {
+                for qj in 0..b.dp {
+                    let op_val = op_a[qi][pi] * op_b[qj][pj];
+                    let diag = pi == qi && pj == qj;
+                    if op_val == C64::new(0.0, 0.0) && !diag {
+                        continue;
+                    }
+                    let mut acc = C64::new(0.0, 0.0);
+                    for l in 0..a.dl {
+                        for lp in 0..a.dl {
+                            let lval = left[l * a.dl + lp];
+                            for r in 0..b.dr {
+                                for rp in 0..b.dr {
+                                    let rval = right[r * b.dr + rp];
+                                    for m in 0..a.dr {
+                                        for mp in 0..a.dr {
+                                            acc += lval
+                                                * a.get(l, pi, m)
+                                                * b.get(m, pj, r)
+                                                * a.get(lp, qi, mp).conj()
+                                                * b.get(mp, qj, rp).conj()
+                                                * rval;
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    numer += op_val * acc;
+                    if diag {
+                        let val = acc.re;
+                        denom += if val < 0.0 { 0.0 } else { val };
+                    }
                 }
             }
         }
     }
-    out
+
+    if denom == 0.0 {
+        return 0.0;
+    }
+
+    numer.re / denom
 }
 
-/// Expectation value ⟨X_i X_j⟩ for nearest neighbors.
-pub fn expect_xx(psi: &MPS, i: usize, j: usize) -> f64 {
-    expect_two_site(psi, i, j, kron(pauli_x(), pauli_x()))
+/// Jordan-Wigner hopping ⟨c_i† c_j + c_j† c_i⟩ between fermionic modes i and j.
+///
+/// Maps to ½ ⟨X_i Z_{i+1} ... Z_{j-1} X_j + Y_i Z_{i+1} ... Z_{j-1} Y_j⟩ for i < j; the
+/// term is symmetric, so the argument order does not matter.
+pub fn expect_jw_hopping(psi: &MPS, i: usize, j: usize) -> f64 {
+    assert!(i != j, "expect_jw_hopping: modes must differ, got {} twice", i);
+    let (i, j) = (i.min(j), i.max(j));
+    assert_site(psi, j, "expect_jw_hopping");
+
+    let string = |end: Pauli| {
+        let mut ops = Vec::with_capacity(j - i + 1);
+        ops.push((i, end));
+        ops.extend((i + 1..j).map(|k| (k, Pauli::Z)));
+        ops.push((j, end));
+        ops
+    };
+    0.5 * (expect_pauli_string(psi, &string(Pauli::X))
+        + expect_pauli_string(psi, &string(Pauli::Y)))
 }
 
-/// Expectation value ⟨Y_i Y_j⟩ for nearest neighbors.
-pub fn expect_yy(psi: &MPS, i: usize, j: usize) -> f64 {
-    expect_two_site(psi, i, j, kron(pauli_y(), pauli_y()))
+/// Expectation value ⟨∏ P_k⟩ of a Pauli string given as (site, Pauli) pairs.
+///
+/// Sites may be arbitrary (non-adjacent, unordered); repeated sites multiply in order.
+pub fn expect_pauli_string(psi: &MPS, ops: &[(usize, Pauli)]) -> f64 {
+    let z = C64::new(0.0, 0.0);
+    let o = C64::new(1.0, 0.0);
+    let mut local = vec![[[o, z], [z, o]]; psi.sites().len()];
+    for &(k, p) in ops {
+        assert_site(psi, k, "expect_pauli_string");
+        let m = p.matrix();
+        let cur = local[k];
+        let mut next = [[z; 2]; 2];
+        for i in 0..2 {
+            for j in 0..2 {
+                for q in 0..2 {
+                    next[i][j] += m[i][q] * cur[q][j];
+                }
+            }
+        }
+        local[k] = next;
+    }
+    expect_mpo(psi, &Mpo::product(&local))
 }
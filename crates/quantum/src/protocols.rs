@@ -0,0 +1,191 @@
+use crate::gates::{hadamard, pauli_x, pauli_y, pauli_z};
+pub use crate::gates::Pauli;
+use crate::measurement::measure_z;
+use crate::mpo::{Mpo, Tensor4};
+use crate::shot_estimator::estimate_z_shots;
+use rng::ONDRng;
+use tn::mps::{C64, MPS, Tensor3};
+use tn::truncation::Truncation;
+
+/// Register |0>_anc ⊗ a ⊗ b with the sites interleaved as anc, a0, b0, a1, b1, ...
+fn interleave_with_ancilla(a: &MPS, b: &MPS) -> MPS {
+    let mut sites = Vec::with_capacity(2 * a.sites().len() + 1);
+    let mut anc = Tensor3::zeros(1, 2, 1);
+    anc.set(0, 0, 0, C64::new(1.0, 0.0));
+    sites.push(anc);
+
+    for (sa, sb) in a.sites().iter().zip(b.sites().iter()) {
+        // a-site carries b's left bond through unchanged.
+        let mut ta = Tensor3::zeros(sa.dl * sb.dl, sa.dp, sa.dr * sb.dl);
+        for la in 0..sa.dl {
+            for ra in 0..sa.dr {
+                for p in 0..sa.dp {
+                    let v = sa.get(la, p, ra);
+                    for lb in 0..sb.dl {
+                        ta.set(la * sb.dl + lb, p, ra * sb.dl + lb, v);
+                    }
+                }
+            }
+        }
+
+        // b-site carries a's right bond through unchanged.
+        let mut tb = Tensor3::zeros(sa.dr * sb.dl, sb.dp, sa.dr * sb.dr);
+        for lb in 0..sb.dl {
+            for rb in 0..sb.dr {
+                for p in 0..sb.dp {
+                    let v = sb.get(lb, p, rb);
+                    for ra in 0..sa.dr {
+                        tb.set(ra * sb.dl + lb, p, ra * sb.dr + rb, v);
+                    }
+                }
+            }
+        }
+
+        sites.push(ta);
+        sites.push(tb);
+    }
+
+    MPS::from_sites(sites)
+}
+
+/// Controlled-SWAP of every (a_i, b_i) pair on the interleaved register, controlled by site 0.
+///
+/// Bond channel 0 carries "control = 0" (identity); channel 1 carries "control = 1",
+/// and each pair applies SWAP = ½ (II + XX + YY + ZZ) through four extra channels.
+fn controlled_swap_mpo(n_pairs: usize) -> Mpo {
+    let z = C64::new(0.0, 0.0);
+    let o = C64::new(1.0, 0.0);
+    let paulis = [[[o, z], [z, o]], pauli_x(), pauli_y(), pauli_z()];
+
+    let mut sites = Vec::with_capacity(2 * n_pairs + 1);
+    let mut ctrl = Tensor4::zeros(1, 2, 2);
+    ctrl.set(0, 0, 0, 0, o);
+    ctrl.set(0, 1, 1, 1, o);
+    sites.push(ctrl);
+
+    for i in 0..n_pairs {
+        let mut wa = Tensor4::zeros(2, 2, 5);
+        let mut wb = Tensor4::zeros(5, 2, if i + 1 == n_pairs { 1 } else { 2 });
+        let out = |c: usize| if i + 1 == n_pairs { 0 } else { c };
+
+        for p in 0..2 {
+            wa.set(0, p, p, 0, o);
+            wb.set(0, p, p, out(0), o);
+        }
+        for (t, sigma) in paulis.iter().enumerate() {
+            for (po, row) in sigma.iter().enumerate() {
+                for (pi, &s) in row.iter().enumerate() {
+                    wa.set(1, po, pi, 1 + t, s * 0.5);
+                    wb.set(1 + t, po, pi, out(1), s);
+                }
+            }
+        }
+
+        sites.push(wa);
+        sites.push(wb);
+    }
+
+    Mpo { sites }
+}
+
+/// Swap-test estimate of |⟨a|b⟩|² from `shots` ancilla measurements.
+///
+/// Runs H · CSWAP · H on an ancilla; P(0) = (1 + |⟨a|b⟩|²) / 2, so ⟨Z_anc⟩ = |⟨a|b⟩|².
+pub fn swap_test(a: &MPS, b: &MPS, shots: usize, rng: &mut ONDRng) -> f64 {
+    assert_eq!(a.sites().len(), b.sites().len(), "swap_test needs equal-length registers");
+
+    let mut psi = interleave_with_ancilla(a, b);
+    psi.apply_1q(0, hadamard());
+    controlled_swap_mpo(a.sites().len()).apply(&mut psi, Truncation::exact());
+    psi.apply_1q(0, hadamard());
+
+    estimate_z_shots(&psi, 0, rng, shots).max(0.0)
+}
+
+/// One randomized measurement: the basis used on each site and the ±1 outcome.
+#[derive(Clone, Debug)]
+pub struct Snapshot {
+    pub bases: Vec<Pauli>,
+    pub outcomes: Vec<u8>,
+}
+
+/// Classical shadow from random single-qubit Pauli-basis measurements.
+#[derive(Clone, Debug)]
+pub struct Shadow {
+    pub snapshots: Vec<Snapshot>,
+}
+
+/// Rotation taking the eigenbasis of `p` onto the computational basis.
+pub(crate) fn basis_rotation(p: Pauli) -> [[C64; 2]; 2] {
+    let s = 1.0 / 2.0_f64.sqrt();
+    match p {
+        Pauli::X => hadamard(),
+        // H · S†
+        Pauli::Y => [
+            [C64::new(s, 0.0), C64::new(0.0, -s)],
+            [C64::new(s, 0.0), C64::new(0.0, s)],
+        ],
+        Pauli::Z => {
+            let z = C64::new(0.0, 0.0);
+            let o = C64::new(1.0, 0.0);
+            [[o, z], [z, o]]
+        }
+    }
+}
+
+/// Collect `n_shadows` snapshots, each measuring every site in a uniformly random Pauli basis.
+pub fn classical_shadow(psi: &MPS, n_shadows: usize, rng: &mut ONDRng) -> Shadow {
+    let n = psi.sites().len();
+    let mut snapshots = Vec::with_capacity(n_shadows);
+
+    for _ in 0..n_shadows {
+        let mut psi_copy = psi.clone();
+        let mut bases = Vec::with_capacity(n);
+        for k in 0..n {
+            let basis = match (rng.next_f64(b"SHADOW_BASIS") * 3.0) as usize {
+                0 => Pauli::X,
+                1 => Pauli::Y,
+                _ => Pauli::Z,
+            };
+            if basis != Pauli::Z {
+                psi_copy.apply_1q(k, basis_rotation(basis));
+            }
+            bases.push(basis);
+        }
+
+        let mut outcomes = Vec::with_capacity(n);
+        for k in 0..n {
+            outcomes.push(measure_z(&mut psi_copy, k, rng));
+        }
+
+        snapshots.push(Snapshot { bases, outcomes });
+    }
+
+    Shadow { snapshots }
+}
+
+impl Shadow {
+    /// Estimate ⟨∏ P_k⟩ for the Pauli string given as (site, Pauli) pairs.
+    ///
+    /// Each snapshot contributes ∏ 3·(±1) when every basis matches, and 0 otherwise.
+    pub fn estimate_pauli(&self, ops: &[(usize, Pauli)]) -> f64 {
+        if self.snapshots.is_empty() {
+            return 0.0;
+        }
+
+        let mut sum = 0.0;
+        for snap in &self.snapshots {
+            let mut v = 1.0;
+            for &(k, p) in ops {
+                if snap.bases[k] != p {
+                    v = 0.0;
+                    break;
+                }
+                v *= if snap.outcomes[k] == 0 { 3.0 } else { -3.0 };
+            }
+            sum += v;
+        }
+
+        sum / self.snapshots.len() as f64
+    }
+}
@@ -0,0 +1,120 @@
+//! Typed qubit handles over the raw `usize` site API.
+//!
+//! The functions here validate their `Qubit` arguments (range, distinctness,
+//! adjacency) and then forward to the index-based layer, so a control/target mix-up
+//! is either honored as written or rejected, never silently reinterpreted.
+
+use crate::gates;
+use tn::{
+    mps::{C64, MPS},
+    truncation::Truncation,
+};
+
+/// A site of the chain, named so it cannot be confused with a bond index or a count.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Qubit(pub usize);
+
+impl Qubit {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A contiguous block of `len` qubits starting at site `start`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Register {
+    pub start: usize,
+    pub len: usize,
+}
+
+impl Register {
+    /// Sites 0..n.
+    pub fn new(n: usize) -> Self {
+        Self { start: 0, len: n }
+    }
+
+    /// The `i`-th qubit of the register.
+    #[track_caller]
+    pub fn qubit(&self, i: usize) -> Qubit {
+        assert!(i < self.len, "qubit {} out of range for {}-qubit register", i, self.len);
+        Qubit(self.start + i)
+    }
+
+    pub fn qubits(&self) -> impl Iterator<Item = Qubit> {
+        (self.start..self.start + self.len).map(Qubit)
+    }
+
+    /// Split into the first `mid` qubits and the rest.
+    pub fn split_at(&self, mid: usize) -> (Register, Register) {
+        assert!(mid <= self.len, "split point {} beyond {}-qubit register", mid, self.len);
+        (
+            Register {
+                start: self.start,
+                len: mid,
+            },
+            Register {
+                start: self.start + mid,
+                len: self.len - mid,
+            },
+        )
+    }
+}
+
+#[track_caller]
+fn check(psi: &MPS, q: Qubit, op: &str) {
+    let n = psi.sites().len();
+    assert!(q.0 < n, "{}: qubit {} out of range for {}-site MPS", op, q.0, n);
+}
+
+/// Lower site of the bond joining `a` and `b`, which must be distinct neighbours.
+#[track_caller]
+fn bond(psi: &MPS, a: Qubit, b: Qubit, op: &str) -> usize {
+    check(psi, a, op);
+    check(psi, b, op);
+    assert!(a != b, "{}: both arguments are qubit {}", op, a.0);
+    assert!(
+        a.0.abs_diff(b.0) == 1,
+        "{}: qubits {} and {} are not nearest neighbours",
+        op,
+        a.0,
+        b.0
+    );
+    a.0.min(b.0)
+}
+
+/// CNOT with the control on the right site of the pair: flips site k when k+1 is 1.
+fn cnot_reversed() -> [[C64; 4]; 4] {
+    let z = C64::new(0.0, 0.0);
+    let o = C64::new(1.0, 0.0);
+    [
+        [o, z, z, z],
+        [z, z, z, o],
+        [z, z, o, z],
+        [z, o, z, z],
+    ]
+}
+
+#[track_caller]
+pub fn apply_1q(psi: &mut MPS, q: Qubit, u: [[C64; 2]; 2]) {
+    check(psi, q, "apply_1q");
+    psi.apply_1q(q.0, u);
+}
+
+/// CNOT from `control` to `target` in either orientation; they must be neighbours.
+#[track_caller]
+pub fn apply_cnot(psi: &mut MPS, control: Qubit, target: Qubit, trunc: Truncation) -> bool {
+    let k = bond(psi, control, target, "apply_cnot");
+    let u = if control.0 == k {
+        gates::cnot()
+    } else {
+        cnot_reversed()
+    };
+    psi.apply_2q_svd(k, u, trunc)
+}
+
+/// CZ on two neighbouring qubits (symmetric, so argument order does not matter).
+#[track_caller]
+pub fn apply_cz(psi: &mut MPS, a: Qubit, b: Qubit, trunc: Truncation) -> bool {
+    let k = bond(psi, a, b, "apply_cz");
+    psi.apply_2q_svd(k, gates::cz(), trunc)
+}
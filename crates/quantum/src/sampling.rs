@@ -0,0 +1,68 @@
+use crate::gates::Pauli;
+use crate::measurement::measure_z;
+use crate::protocols::basis_rotation;
+use rayon::prelude::*;
+use rng::ONDRng;
+use std::collections::BTreeMap;
+use tn::mps::{bits_to_index, MPS};
+
+/// One bitstring sample with qubit k measured in basis `bases[k]` ('X', 'Y' or 'Z').
+///
+/// Each site is rotated into the computational basis, then the sites are measured in
+/// order on a single copy, so the joint distribution is sampled exactly. Outcome 0 is
+/// the +1 eigenvalue of the chosen Pauli.
+pub fn sample_in_bases(psi: &MPS, bases: &[char], rng: &mut ONDRng) -> Vec<u8> {
+    let n = psi.sites().len();
+    assert_eq!(bases.len(), n, "sample_in_bases needs one basis per qubit");
+
+    let mut work = psi.clone();
+    for (k, &b) in bases.iter().enumerate() {
+        let p = match b {
+            'X' => Pauli::X,
+            'Y' => Pauli::Y,
+            'Z' => Pauli::Z,
+            other => panic!("sample_in_bases: unknown basis {:?} for qubit {}", other, k),
+        };
+        if p != Pauli::Z {
+            work.apply_1q(k, basis_rotation(p));
+        }
+    }
+
+    (0..n).map(|k| measure_z(&mut work, k, rng)).collect()
+}
+
+/// One exact Z-basis bitstring: every site measured in order on a copy of ψ.
+pub fn sample_bitstring(psi: &MPS, rng: &mut ONDRng) -> Vec<u8> {
+    let mut work = psi.clone();
+    (0..psi.sites().len())
+        .map(|k| measure_z(&mut work, k, rng))
+        .collect()
+}
+
+/// Histogram of `shots` Z-basis samples, keyed by bitstring index (site 0 most significant).
+///
+/// Samples run in parallel; sample s draws from `rng.fork(s)`, so the counts depend only
+/// on the RNG state and not on the Rayon pool size. `rng` is advanced by one draw so
+/// the next call samples fresh bitstrings.
+pub fn sample_counts(psi: &MPS, shots: usize, rng: &mut ONDRng) -> BTreeMap<usize, usize> {
+    assert!(
+        psi.sites().len() < usize::BITS as usize,
+        "sample_counts: too many qubits to index bitstrings"
+    );
+    let base = rng.clone();
+    rng.next_f64(b"SAMPLE_COUNTS");
+
+    let indices: Vec<usize> = (0..shots)
+        .into_par_iter()
+        .map(|s| {
+            let mut r = base.fork(s as u64);
+            bits_to_index(&sample_bitstring(psi, &mut r))
+        })
+        .collect();
+
+    let mut counts = BTreeMap::new();
+    for idx in indices {
+        *counts.entry(idx).or_insert(0) += 1;
+    }
+    counts
+}
@@ -1,17 +1,26 @@
-use crate::measurement::measure_z;
+use crate::measurement::{measure_z, measure_z_with, pick_outcome, site_probs};
 use rng::ONDRng;
-use tn::mps::MPS;
+use tn::mps::{bits_to_index, MPS};
 
 /// Estimate ⟨Z_k⟩ via projective measurements (shots).
+///
+/// Measuring a single site only needs its marginal, so the weights are computed once
+/// and every shot is one draw against them; the RNG stream and outcomes match a
+/// per-shot `measure_z` on a fresh copy of ψ.
 pub fn estimate_z_shots(psi: &MPS, k: usize, rng: &mut ONDRng, shots: usize) -> f64 {
     if shots == 0 {
         return 0.0;
     }
 
+    let probs = site_probs(psi, k);
+    let total: f64 = probs.iter().sum();
+    if total == 0.0 {
+        return 1.0;
+    }
+
     let mut sum = 0.0;
     for _ in 0..shots {
-        let mut psi_copy = psi.clone();
-        let m = measure_z(&mut psi_copy, k, rng);
+        let m = pick_outcome(&probs, rng.next_f64(b"MEASURE_Z") * total);
         sum += if m == 0 { 1.0 } else { -1.0 };
     }
 
@@ -19,6 +28,9 @@ pub fn estimate_z_shots(psi: &MPS, k: usize, rng: &mut ONDRng, shots: usize) ->
 }
 
 /// Estimate ⟨Z_i Z_j⟩ via projective measurements (shots).
+///
+/// Each shot measures the lower site index first, so `(i, j)` and `(j, i)` consume the
+/// same draws and give the same estimate for a given RNG state.
 pub fn estimate_zz_shots(
     psi: &MPS,
     i: usize,
@@ -30,9 +42,11 @@ pub fn estimate_zz_shots(
         return 0.0;
     }
 
+    let (i, j) = (i.min(j), i.max(j));
+    let mut psi_copy = psi.clone();
     let mut sum = 0.0;
     for _ in 0..shots {
-        let mut psi_copy = psi.clone();
+        psi_copy.clone_from(psi);
         let mi = measure_z(&mut psi_copy, i, rng);
         let mj = measure_z(&mut psi_copy, j, rng);
 
@@ -44,3 +58,55 @@ pub fn estimate_zz_shots(
 
     sum / shots as f64
 }
+
+/// One shot: the uniform draws consumed and the outcomes, in measurement order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShotRecord {
+    pub draws: Vec<f64>,
+    pub outcomes: Vec<u8>,
+}
+
+/// Exact per-shot record of RNG consumption for a sequence of Z measurements.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShotTableau {
+    pub sites: Vec<usize>,
+    pub shots: Vec<ShotRecord>,
+}
+
+impl ShotTableau {
+    /// Counts per bitstring; `sites[0]` is the most significant bit.
+    pub fn histogram(&self) -> Vec<usize> {
+        let mut counts = vec![0usize; 1 << self.sites.len()];
+        for shot in &self.shots {
+            counts[bits_to_index(&shot.outcomes)] += 1;
+        }
+        counts
+    }
+}
+
+/// Measure `sites` in order on a fresh copy of ψ per shot, recording every draw.
+///
+/// Consumes the RNG exactly like repeated `measure_z` calls in the same order.
+pub fn record_shots(psi: &MPS, sites: &[usize], rng: &mut ONDRng, shots: usize) -> ShotTableau {
+    let mut records = Vec::with_capacity(shots);
+    let mut psi_copy = psi.clone();
+    for _ in 0..shots {
+        psi_copy.clone_from(psi);
+        let mut draws = Vec::with_capacity(sites.len());
+        let mut outcomes = Vec::with_capacity(sites.len());
+        for &k in sites {
+            let m = measure_z_with(&mut psi_copy, k, || {
+                let x = rng.next_f64(b"MEASURE_Z");
+                draws.push(x);
+                x
+            });
+            outcomes.push(m);
+        }
+        records.push(ShotRecord { draws, outcomes });
+    }
+
+    ShotTableau {
+        sites: sites.to_vec(),
+        shots: records,
+    }
+}
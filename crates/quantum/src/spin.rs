@@ -0,0 +1,51 @@
+use tn::mps::C64;
+
+/// Spin-S operators in the basis m = S, S-1, ..., -S (index 0 is m = S),
+/// parameterized by `two_s` = 2S so half-integer spins are exact.
+pub fn spin_sz(two_s: usize) -> Vec<Vec<C64>> {
+    let d = two_s + 1;
+    let s = two_s as f64 / 2.0;
+    let mut op = vec![vec![C64::new(0.0, 0.0); d]; d];
+    for (p, row) in op.iter_mut().enumerate() {
+        row[p] = C64::new(s - p as f64, 0.0);
+    }
+    op
+}
+
+/// Raising operator S+ with ⟨m+1|S+|m⟩ = sqrt(S(S+1) - m(m+1)).
+pub fn spin_splus(two_s: usize) -> Vec<Vec<C64>> {
+    let d = two_s + 1;
+    let s = two_s as f64 / 2.0;
+    let mut op = vec![vec![C64::new(0.0, 0.0); d]; d];
+    for p in 1..d {
+        let m = s - p as f64;
+        op[p - 1][p] = C64::new((s * (s + 1.0) - m * (m + 1.0)).sqrt(), 0.0);
+    }
+    op
+}
+
+/// Sx = (S+ + S-) / 2.
+pub fn spin_sx(two_s: usize) -> Vec<Vec<C64>> {
+    let sp = spin_splus(two_s);
+    let d = sp.len();
+    let mut op = vec![vec![C64::new(0.0, 0.0); d]; d];
+    for i in 0..d {
+        for j in 0..d {
+            op[i][j] = (sp[i][j] + sp[j][i].conj()) * 0.5;
+        }
+    }
+    op
+}
+
+/// Sy = (S+ - S-) / 2i.
+pub fn spin_sy(two_s: usize) -> Vec<Vec<C64>> {
+    let sp = spin_splus(two_s);
+    let d = sp.len();
+    let mut op = vec![vec![C64::new(0.0, 0.0); d]; d];
+    for i in 0..d {
+        for j in 0..d {
+            op[i][j] = (sp[i][j] - sp[j][i].conj()) / C64::new(0.0, 2.0);
+        }
+    }
+    op
+}
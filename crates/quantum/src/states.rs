@@ -0,0 +1,88 @@
+use crate::gates::hadamard;
+use rng::ONDRng;
+use tn::{
+    mps::{C64, MPS, Tensor3},
+    truncation::Truncation,
+};
+
+fn binomial(n: usize, k: usize) -> f64 {
+    let k = k.min(n - k);
+    (0..k).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
+
+/// Dicke state: equal superposition of all n-bit strings with exactly `k` ones.
+///
+/// The bond index counts the ones seen so far, so the exact state has bond dimension
+/// at most k + 1; `trunc` is used to compress away the unreachable counts.
+pub fn from_particle_number(n: usize, k: usize, trunc: Truncation) -> MPS {
+    assert!(k <= n, "cannot place {} particles on {} sites", k, n);
+
+    let mut sites = Vec::with_capacity(n);
+    for i in 0..n {
+        let dl = if i == 0 { 1 } else { k + 1 };
+        let dr = if i + 1 == n { 1 } else { k + 1 };
+        let mut t = Tensor3::zeros(dl, 2, dr);
+        for c in 0..dl {
+            for p in 0..2 {
+                let next = c + p;
+                if next > k {
+                    continue;
+                }
+                // The last site must close the count at exactly k.
+                let r = if i + 1 == n {
+                    if next != k {
+                        continue;
+                    }
+                    0
+                } else {
+                    next
+                };
+                t.set(c, p, r, C64::new(1.0, 0.0));
+            }
+        }
+        sites.push(t);
+    }
+
+    let mut psi = MPS::from_sites(sites);
+    if let Some(first) = psi.sites_mut().first_mut() {
+        let scale = 1.0 / binomial(n, k).sqrt();
+        for v in first.data.iter_mut() {
+            *v *= scale;
+        }
+    }
+    psi.compress(trunc);
+    psi
+}
+
+/// 1D cluster state: CZ on every bond of |+⟩^n, stabilized by Z_{k-1} X_k Z_{k+1}.
+///
+/// The CZs are applied exactly with `apply_2q_diagonal` and then compressed, so the
+/// result has bond dimension 2 for any `trunc` that allows it.
+pub fn cluster_state(n: usize, trunc: Truncation) -> MPS {
+    let o = C64::new(1.0, 0.0);
+    let mut psi = MPS::new_zero(n);
+    psi.apply_1q_all(hadamard());
+    for k in 0..n.saturating_sub(1) {
+        psi.apply_2q_diagonal(k, [o, o, o, -o]);
+    }
+    psi.compress(trunc);
+    psi
+}
+
+/// Haar-random single-qubit gate applied to every site of |0...0⟩.
+///
+/// Each site's state (first column of the gate) is uniform on the Bloch sphere, drawn
+/// with Shoemake's three-uniform construction; every bond stays at dimension 1.
+pub fn random_product_state(n: usize, rng: &mut ONDRng) -> MPS {
+    let mut psi = MPS::new_zero(n);
+    for k in 0..n {
+        let u1 = rng.next_f64(b"HAAR_1Q");
+        let u2 = rng.next_f64(b"HAAR_1Q");
+        let u3 = rng.next_f64(b"HAAR_1Q");
+        let tau = 2.0 * std::f64::consts::PI;
+        let a = C64::from_polar((1.0 - u1).sqrt(), tau * u2);
+        let b = C64::from_polar(u1.sqrt(), tau * u3);
+        psi.apply_1q(k, [[a, -b.conj()], [b, a.conj()]]);
+    }
+    psi
+}
@@ -0,0 +1,114 @@
+use crate::gates::{kron, matmul4, pauli_x, pauli_y, pauli_z};
+use crate::hamiltonian::Heisenberg;
+use crate::observables::total_sz;
+use std::ops::Range;
+use tn::{
+    mps::{C64, MPS},
+    truncation::Truncation,
+};
+
+/// exp(-i θ P) = cos θ I - i sin θ P for a two-qubit Pauli string P (P² = I).
+fn pauli_exp(theta: f64, p: [[C64; 4]; 4]) -> [[C64; 4]; 4] {
+    let c = theta.cos();
+    let s = C64::new(0.0, -theta.sin());
+    let mut out = [[C64::new(0.0, 0.0); 4]; 4];
+    for i in 0..4 {
+        for j in 0..4 {
+            out[i][j] = s * p[i][j];
+        }
+        out[i][i] += c;
+    }
+    out
+}
+
+/// exp(-i dt (jx XX + jy YY + jz ZZ)) for a single bond.
+///
+/// XX, YY and ZZ commute, so the exponential factorizes exactly.
+pub fn heisenberg_bond_gate(jx: f64, jy: f64, jz: f64, dt: f64) -> [[C64; 4]; 4] {
+    let gx = pauli_exp(jx * dt, kron(pauli_x(), pauli_x()));
+    let gy = pauli_exp(jy * dt, kron(pauli_y(), pauli_y()));
+    let gz = pauli_exp(jz * dt, kron(pauli_z(), pauli_z()));
+    matmul4(matmul4(gx, gy), gz)
+}
+
+pub(crate) fn bond_couplings(h: &Heisenberg, b: usize) -> (f64, f64, f64) {
+    (
+        h.jx.get(b).copied().unwrap_or(0.0),
+        h.jy.get(b).copied().unwrap_or(0.0),
+        h.jz.get(b).copied().unwrap_or(0.0),
+    )
+}
+
+pub(crate) fn num_bonds(h: &Heisenberg) -> usize {
+    h.jx.len().max(h.jy.len()).max(h.jz.len())
+}
+
+/// exp(-i dt H_b) on bonds start, start + 2, ... (start = 0: even layer, 1: odd layer).
+pub fn trotter_layer_heisenberg(
+    psi: &mut MPS,
+    h: &Heisenberg,
+    start: usize,
+    dt: f64,
+    trunc: Truncation,
+) {
+    trotter_layer_heisenberg_in(psi, h, start, 0..usize::MAX, dt, trunc);
+}
+
+/// `trotter_layer_heisenberg` restricted to the bonds in `range`.
+pub fn trotter_layer_heisenberg_in(
+    psi: &mut MPS,
+    h: &Heisenberg,
+    start: usize,
+    range: Range<usize>,
+    dt: f64,
+    trunc: Truncation,
+) {
+    let bonds = num_bonds(h).min(psi.sites().len().saturating_sub(1)).min(range.end);
+    let mut b = start;
+    while b < range.start {
+        b += 2;
+    }
+    while b < bonds {
+        let (jx, jy, jz) = bond_couplings(h, b);
+        psi.apply_2q_svd(b, heisenberg_bond_gate(jx, jy, jz, dt), trunc);
+        b += 2;
+    }
+}
+
+/// First-order Trotter step: all even bonds, then all odd bonds.
+pub fn trotter_step_heisenberg(psi: &mut MPS, h: &Heisenberg, dt: f64, trunc: Truncation) {
+    trotter_layer_heisenberg(psi, h, 0, dt, trunc);
+    trotter_layer_heisenberg(psi, h, 1, dt, trunc);
+}
+
+/// `trotter_step_heisenberg` that, in debug builds, asserts total S^z drifts by less
+/// than `sz_tol` whenever the couplings conserve it (jx = jy on every bond).
+///
+/// A drift means truncation is breaking the U(1) symmetry. Release builds skip the
+/// check and its O(n) observable cost entirely.
+pub fn trotter_step_heisenberg_checked(
+    psi: &mut MPS,
+    h: &Heisenberg,
+    dt: f64,
+    trunc: Truncation,
+    sz_tol: f64,
+) {
+    let conserving = cfg!(debug_assertions)
+        && (0..num_bonds(h)).all(|b| {
+            let (jx, jy, _) = bond_couplings(h, b);
+            jx == jy
+        });
+    let before = if conserving { total_sz(psi) } else { 0.0 };
+
+    trotter_step_heisenberg(psi, h, dt, trunc);
+
+    if conserving {
+        let drift = (total_sz(psi) - before).abs();
+        debug_assert!(
+            drift < sz_tol,
+            "total Sz drifted by {:.3e} (tolerance {:.3e}) in one Trotter step",
+            drift,
+            sz_tol
+        );
+    }
+}
@@ -4,10 +4,7 @@ use tn::{mps::MPS, truncation::Truncation};
 
 #[test]
 fn bell_state_z_correlation() {
-    let trunc = Truncation {
-        max_bond: 8,
-        cutoff: 1e-12,
-    };
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-12).build();
 
     let mut counts = [[0usize; 2]; 2];
 
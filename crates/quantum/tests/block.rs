@@ -0,0 +1,51 @@
+use quantum::gates::{cnot, fsim, hadamard, rotation, rx, two_qubit_block};
+use tn::mps::C64;
+
+/// Apply a 4×4 gate to a dense two-qubit vector (index = 2·q0 + q1).
+fn apply4(u: [[C64; 4]; 4], v: [C64; 4]) -> [C64; 4] {
+    let mut out = [C64::new(0.0, 0.0); 4];
+    for i in 0..4 {
+        for j in 0..4 {
+            out[i] += u[i][j] * v[j];
+        }
+    }
+    out
+}
+
+/// Apply a single-qubit gate to `wire` (0 = most significant) of a dense two-qubit vector.
+fn apply1(g: [[C64; 2]; 2], wire: usize, v: [C64; 4]) -> [C64; 4] {
+    let mut out = [C64::new(0.0, 0.0); 4];
+    for (idx, amp) in v.iter().enumerate() {
+        let bit = if wire == 0 { idx >> 1 } else { idx & 1 };
+        for (b, row) in g.iter().enumerate() {
+            let target = if wire == 0 { (b << 1) | (idx & 1) } else { (idx & 2) | b };
+            out[target] += row[bit] * amp;
+        }
+    }
+    out
+}
+
+#[test]
+fn block_matches_sequential_application() {
+    let v = [
+        C64::new(0.4, -0.1),
+        C64::new(0.2, 0.5),
+        C64::new(-0.3, 0.2),
+        C64::new(0.1, 0.6),
+    ];
+    let (pre0, pre1) = (rx(0.7), rotation([0.2, 1.0, -0.5], 1.3));
+    let (post0, post1) = (hadamard(), rotation([0.0, 0.0, 1.0], -0.4));
+
+    for entangler in [cnot(), fsim(0.9, 0.4)] {
+        let mut seq = apply1(pre0, 0, v);
+        seq = apply1(pre1, 1, seq);
+        seq = apply4(entangler, seq);
+        seq = apply1(post0, 0, seq);
+        seq = apply1(post1, 1, seq);
+
+        let block = apply4(two_qubit_block(pre0, pre1, entangler, post0, post1), v);
+        for (a, b) in block.iter().zip(&seq) {
+            assert!((a - b).norm() < 1e-12, "{} vs {}", a, b);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use quantum::{
+    apply_cnot, apply_cz,
+    gates::hadamard,
+    noise::depolarizing_2q,
+    observables::{expect_x, expect_xx, expect_yy, expect_z, expect_zz},
+};
+use rng::ONDRng;
+use tn::{
+    mps::{C64, MPS},
+    truncation::Truncation,
+};
+
+#[test]
+#[should_panic(expected = "expect_zz: site 3 out of range for 3-site MPS")]
+fn expect_zz_past_the_end() {
+    expect_zz(&MPS::new_zero(3), 2, 3);
+}
+
+#[test]
+#[should_panic(expected = "expect_xx: site 5 out of range for 3-site MPS")]
+fn expect_xx_past_the_end() {
+    expect_xx(&MPS::new_zero(3), 4, 5);
+}
+
+#[test]
+#[should_panic(expected = "expect_yy: site 1 out of range for 1-site MPS")]
+fn expect_yy_on_single_site() {
+    expect_yy(&MPS::new_zero(1), 0, 1);
+}
+
+#[test]
+#[should_panic(expected = "expect_z: site 4 out of range for 4-site MPS")]
+fn expect_z_past_the_end() {
+    expect_z(&MPS::new_zero(4), 4);
+}
+
+#[test]
+#[should_panic(expected = "expect_x: site 0 out of range for 0-site MPS")]
+fn expect_x_on_empty_chain() {
+    expect_x(&MPS::new_zero(0), 0);
+}
+
+#[test]
+#[should_panic(expected = "apply_cnot: bond (3, 4) out of range for 4-site MPS")]
+fn apply_cnot_on_last_site() {
+    let n = 4;
+    apply_cnot(&mut MPS::new_zero(n), n - 1, Truncation::default());
+}
+
+#[test]
+#[should_panic(expected = "depolarizing_2q: bond (3, 4) out of range for 4-site MPS")]
+fn depolarizing_2q_on_last_site_even_without_noise() {
+    let mut rng = ONDRng::new(b"bounds");
+    depolarizing_2q(&mut MPS::new_zero(4), 3, 0.0, &mut rng);
+}
+
+#[test]
+#[should_panic(expected = "apply_cz: bond (0, 1) out of range for 1-site MPS")]
+fn apply_cz_on_single_site() {
+    apply_cz(&mut MPS::new_zero(1), 0, Truncation::default());
+}
+
+#[test]
+#[should_panic(expected = "apply_2q_diagonal: bond (2, 3) out of range for 3-site MPS")]
+fn apply_2q_diagonal_past_the_end() {
+    MPS::new_zero(3).apply_2q_diagonal(2, [C64::new(1.0, 0.0); 4]);
+}
+
+#[test]
+#[should_panic(expected = "apply_1q: site 2 out of range for 2-site MPS")]
+fn apply_1q_past_the_end() {
+    MPS::new_zero(2).apply_1q(2, hadamard());
+}
+
+#[test]
+fn last_valid_bond_still_works() {
+    let n = 4;
+    let mut psi = MPS::new_zero(n);
+    psi.apply_1q(n - 2, hadamard());
+    apply_cnot(&mut psi, n - 2, Truncation::default());
+    assert!((expect_zz(&psi, n - 2, n - 1) - 1.0).abs() < 1e-12);
+}
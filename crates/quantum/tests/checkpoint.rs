@@ -0,0 +1,127 @@
+mod common;
+
+use common::random_rotation;
+use quantum::{
+    apply_cnot,
+    measurement::measure_z,
+    observables::{expect_x, expect_z, expect_zz},
+};
+use rng::ONDRng;
+use tn::{mps::MPS, truncation::Truncation};
+
+const N: usize = 6;
+
+fn trunc() -> Truncation {
+    Truncation::builder().max_bond(8).cutoff(1e-12).build()
+}
+
+/// One random layer: rotations on every site, CNOTs on alternating bonds, and a
+/// mid-circuit measurement on a random site.
+fn step(psi: &mut MPS, rng: &mut ONDRng, layer: usize) {
+    for k in 0..N {
+        psi.apply_1q(k, random_rotation(rng));
+    }
+    for k in (layer % 2..N - 1).step_by(2) {
+        apply_cnot(psi, k, trunc());
+    }
+    let site = ((rng.next_f64(b"site") * N as f64) as usize).min(N - 1);
+    measure_z(psi, site, rng);
+}
+
+fn observables(psi: &MPS) -> Vec<f64> {
+    let mut out = Vec::new();
+    for k in 0..N {
+        out.push(expect_z(psi, k));
+        out.push(expect_x(psi, k));
+    }
+    for k in 0..N - 1 {
+        out.push(expect_zz(psi, k, k + 1));
+    }
+    out
+}
+
+#[test]
+fn checkpoint_round_trip_replays_bit_exactly() {
+    let mut rng = ONDRng::new(b"checkpoint");
+    let mut psi = MPS::new_zero(N);
+    for layer in 0..4 {
+        step(&mut psi, &mut rng, layer);
+    }
+    psi.canonicalize_at(2);
+
+    let mut mps_bytes = Vec::new();
+    psi.save(&mut mps_bytes).unwrap();
+    let rng_bytes = rng.save_state();
+
+    for layer in 4..10 {
+        step(&mut psi, &mut rng, layer);
+    }
+    let expected = observables(&psi);
+    let expected_draw = rng.next_f64(b"tail");
+
+    let mut resumed = MPS::load(&mut mps_bytes.as_slice()).unwrap();
+    assert_eq!(resumed.orthogonality_center(), Some(2));
+    let mut resumed_rng = ONDRng::restore_state(&rng_bytes);
+    for layer in 4..10 {
+        step(&mut resumed, &mut resumed_rng, layer);
+    }
+
+    for (a, b) in expected.iter().zip(observables(&resumed)) {
+        assert!((a - b).abs() < 1e-15, "{} vs {}", a, b);
+    }
+    for (a, b) in psi.sites().iter().zip(resumed.sites()) {
+        assert_eq!(a.data, b.data);
+    }
+    assert_eq!(expected_draw, resumed_rng.next_f64(b"tail"));
+}
+
+#[test]
+fn load_rejects_foreign_bytes() {
+    match MPS::load(&mut &b"not a checkpoint at all"[..]) {
+        Err(err) => assert_eq!(err.kind(), std::io::ErrorKind::InvalidData),
+        Ok(_) => panic!("foreign bytes loaded as an MPS"),
+    }
+}
+
+/// Checkpoint header for `n` sites and no center, followed by one site header.
+fn header(n: u64, dims: [u64; 3]) -> Vec<u8> {
+    let mut bytes = b"QSIMMPS1".to_vec();
+    for x in [n, u64::MAX, dims[0], dims[1], dims[2]] {
+        bytes.extend_from_slice(&x.to_le_bytes());
+    }
+    bytes
+}
+
+#[test]
+fn load_rejects_oversized_headers_without_allocating() {
+    for (bytes, kind) in [
+        (header(1, [1, 1 << 62, 1]), std::io::ErrorKind::InvalidData),
+        (header(2, [1, 2, 1 << 63]), std::io::ErrorKind::InvalidData),
+        // Plausible shape, but the file ends long before 2^41 entries.
+        (header(2, [1, 2, 1 << 40]), std::io::ErrorKind::UnexpectedEof),
+    ] {
+        match MPS::load(&mut bytes.as_slice()) {
+            Err(err) => assert_eq!(err.kind(), kind),
+            Ok(_) => panic!("corrupt header loaded as an MPS"),
+        }
+    }
+}
+
+#[test]
+fn load_drops_a_center_the_tensors_do_not_support() {
+    let mut rng = ONDRng::new(b"checkpoint-center");
+    let mut psi = MPS::new_zero(N);
+    for layer in 0..4 {
+        step(&mut psi, &mut rng, layer);
+    }
+    psi.canonicalize_at(0);
+    let mut bytes = Vec::new();
+    psi.save(&mut bytes).unwrap();
+    assert_eq!(MPS::load(&mut bytes.as_slice()).unwrap().orthogonality_center(), Some(0));
+
+    // Claim the center sits on the last site instead.
+    bytes[16..24].copy_from_slice(&(N as u64 - 1).to_le_bytes());
+    let loaded = MPS::load(&mut bytes.as_slice()).unwrap();
+    assert_eq!(loaded.orthogonality_center(), None);
+    assert!((expect_z(&loaded, N - 1) - expect_z(&psi, N - 1)).abs() < 1e-12);
+}
@@ -0,0 +1,152 @@
+mod common;
+
+use common::random_rotation;
+use quantum::{
+    circuit::{dense_mps_fidelity, haar_brickwork, haar_u4, Circuit, Gate},
+    gates::{cnot, fsim, hadamard, rotation},
+};
+use rng::ONDRng;
+use tn::{
+    mps::{fidelity, C64, MPS},
+    truncation::Truncation,
+};
+
+fn random_circuit(n: usize, depth: usize, seed: &[u8]) -> Circuit {
+    let mut rng = ONDRng::new(seed);
+    let mut c = Circuit::new();
+    for layer in 0..depth {
+        for k in 0..n {
+            c.push_1q(k, random_rotation(&mut rng));
+        }
+        let mut k = layer % 2;
+        while k + 1 < n {
+            if k % 2 == 0 {
+                c.push_2q(k, cnot());
+            } else {
+                c.push_2q(k, fsim(rng.next_f64(b"fs-theta"), rng.next_f64(b"fs-phi")));
+            }
+            k += 2;
+        }
+    }
+    c
+}
+
+#[test]
+fn unlimited_bond_reproduces_dense_state() {
+    let c = random_circuit(4, 6, b"dense-check");
+    let f = dense_mps_fidelity(&c, 4, Truncation::exact());
+    assert!((f - 1.0).abs() < 1e-12, "fidelity = {}", f);
+}
+
+#[test]
+fn bond_one_loses_fidelity() {
+    let c = random_circuit(6, 6, b"dense-check");
+    let exact = dense_mps_fidelity(&c, 6, Truncation::exact());
+    let product = dense_mps_fidelity(&c, 6, Truncation::builder().max_bond(1).build());
+    assert!((exact - 1.0).abs() < 1e-12);
+    assert!(product < 0.99, "fidelity = {}", product);
+    assert!(product >= 0.0);
+}
+
+/// (peak bond, peak memory_bytes) over the run, and the final state.
+fn run_tracking_peaks(c: &Circuit, n: usize) -> (usize, usize, MPS) {
+    let mut psi = MPS::new_zero(n);
+    let (mut chi_max, mut bytes) = (1, psi.memory_bytes());
+    for gate in &c.gates {
+        match *gate {
+            Gate::One { k, u } => psi.apply_1q(k, u),
+            Gate::Two { k, u } => {
+                psi.apply_2q_svd(k, u, Truncation::exact());
+            }
+        }
+        chi_max = chi_max.max(psi.sites().iter().map(|s| s.dr).max().unwrap());
+        bytes = bytes.max(psi.memory_bytes());
+    }
+    (chi_max, bytes, psi)
+}
+
+#[test]
+fn low_memory_schedule_keeps_state_and_lowers_peak_size() {
+    // Entangle-then-undo on three separate pairs, with the phases interleaved so all
+    // three pairs are entangled at once in the original order.
+    let n = 6;
+    let pairs = [0, 2, 4];
+    let mut c = Circuit::new();
+    for &k in &pairs {
+        c.push_1q(k, hadamard());
+        c.push_2q(k, cnot());
+    }
+    for &k in &pairs {
+        c.push_2q(k, cnot());
+        c.push_1q(k, rotation([0.0, 1.0, 0.0], 0.4));
+    }
+
+    let scheduled = c.schedule_low_memory(n, Truncation::exact());
+    assert_eq!(scheduled.gates.len(), c.gates.len());
+    assert_ne!(scheduled, c);
+
+    let (chi0, bytes0, psi0) = run_tracking_peaks(&c, n);
+    let (chi1, bytes1, psi1) = run_tracking_peaks(&scheduled, n);
+    assert!((fidelity(&psi0, &psi1) - 1.0).abs() < 1e-12);
+    let dense0 = c.run_dense(n);
+    for (a, b) in dense0.iter().zip(scheduled.run_dense(n)) {
+        assert!((a - b).norm() < 1e-12);
+    }
+
+    assert!(bytes1 < bytes0, "peak bytes {} vs {}", bytes1, bytes0);
+    // Reordering cannot lower peak χ: a single cut's peak bond is fixed by the gates on
+    // that cut, so only the total size goes down.
+    assert_eq!((chi0, chi1), (2, 2));
+}
+
+#[test]
+fn low_memory_schedule_does_not_simulate_the_circuit() {
+    // Exact simulation of this brickwork would reach χ = 2^12 mid-chain; the bond bound does not.
+    let n = 32;
+    let c = haar_brickwork(n, 24, "schedule");
+    let scheduled = c.schedule_low_memory(n, Truncation::exact());
+    assert_eq!(scheduled.gates.len(), c.gates.len());
+
+    let sorted = |c: &Circuit| {
+        let mut keys: Vec<String> = c.gates.iter().map(|g| format!("{:?}", g)).collect();
+        keys.sort();
+        keys
+    };
+    assert_eq!(sorted(&scheduled), sorted(&c));
+}
+
+#[test]
+fn haar_u4_is_unitary() {
+    let mut rng = ONDRng::new(b"haar-u4");
+    for _ in 0..8 {
+        let u = haar_u4(&mut rng);
+        for i in 0..4 {
+            for j in 0..4 {
+                let dot: C64 = (0..4).map(|r| u[r][i].conj() * u[r][j]).sum();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((dot - expected).norm() < 1e-12, "U^H U [{}][{}] = {}", i, j, dot);
+            }
+        }
+    }
+}
+
+#[test]
+fn haar_brickwork_is_reproducible_and_prefix_stable() {
+    let c = haar_brickwork(8, 6, "fixture");
+    assert_eq!(c, haar_brickwork(8, 6, "fixture"));
+    assert_ne!(c, haar_brickwork(8, 6, "other"));
+    assert_eq!(c.gates.len(), 3 * 4 + 3 * 3);
+    assert!(c.gates.iter().all(|g| matches!(g, Gate::Two { .. })));
+
+    let deeper = haar_brickwork(8, 9, "fixture");
+    assert_eq!(c.gates[..], deeper.gates[..c.gates.len()]);
+}
+
+#[test]
+fn haar_brickwork_saturates_the_middle_bond() {
+    let n = 10;
+    let mut psi = MPS::new_zero(n);
+    haar_brickwork(n, 12, "fixture").apply(&mut psi, Truncation::exact());
+    let chi_max = psi.sites().iter().map(|s| s.dr).max().unwrap();
+    assert!(chi_max >= 16, "chi_max = {}", chi_max);
+}
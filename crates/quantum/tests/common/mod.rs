@@ -0,0 +1,27 @@
+// Each test binary uses its own subset of these helpers.
+#![allow(dead_code)]
+
+use quantum::{
+    apply_cnot,
+    gates::{hadamard, rotation, C64},
+};
+use rng::ONDRng;
+use tn::{mps::MPS, truncation::Truncation};
+
+/// Rotation about a random axis by a uniform angle in [0, 2π).
+pub fn random_rotation(rng: &mut ONDRng) -> [[C64; 2]; 2] {
+    let axis = [
+        rng.next_f64(b"ax") - 0.5,
+        rng.next_f64(b"ay") - 0.5,
+        rng.next_f64(b"az") - 0.5,
+    ];
+    rotation(axis, 2.0 * std::f64::consts::PI * rng.next_f64(b"th"))
+}
+
+/// |Φ+⟩ = (|00⟩ + |11⟩)/√2.
+pub fn bell() -> MPS {
+    let mut psi = MPS::new_zero(2);
+    psi.apply_1q(0, hadamard());
+    apply_cnot(&mut psi, 0, Truncation::exact());
+    psi
+}
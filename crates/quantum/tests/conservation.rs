@@ -0,0 +1,67 @@
+use quantum::{
+    gates::{pauli_x, rx},
+    hamiltonian::Heisenberg,
+    observables::total_sz,
+    trotter::{trotter_step_heisenberg, trotter_step_heisenberg_checked},
+};
+use tn::{mps::MPS, truncation::Truncation};
+
+fn neel(n: usize) -> MPS {
+    let mut psi = MPS::new_zero(n);
+    for k in (1..n).step_by(2) {
+        psi.apply_1q(k, pauli_x());
+    }
+    psi
+}
+
+#[test]
+fn total_sz_counts_up_spins() {
+    assert!((total_sz(&MPS::new_zero(4)) - 4.0).abs() < 1e-12);
+    assert!(total_sz(&neel(4)).abs() < 1e-12);
+    assert!((total_sz(&MPS::from_bitstring(&[1, 1, 0])) + 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn heisenberg_evolution_conserves_total_sz() {
+    let n = 8;
+    let h = Heisenberg::uniform(n, 1.0);
+    let trunc = Truncation::builder().max_bond(32).cutoff(1e-12).build();
+
+    // Néel plus a tilted site: not an Sz eigenstate, but Sz is still conserved.
+    let mut psi = neel(n);
+    psi.apply_1q(3, rx(0.7));
+    let sz0 = total_sz(&psi);
+
+    for _ in 0..10 {
+        trotter_step_heisenberg_checked(&mut psi, &h, 0.1, trunc, 1e-8);
+    }
+    assert!((total_sz(&psi) - sz0).abs() < 1e-8);
+
+    // An anisotropic XY chain (jx != jy) does not conserve Sz, so the check is skipped.
+    let xy = Heisenberg {
+        jx: vec![1.0; n - 1],
+        jy: vec![0.2; n - 1],
+        jz: vec![0.0; n - 1],
+    };
+    let mut psi = neel(n);
+    trotter_step_heisenberg_checked(&mut psi, &xy, 0.3, trunc, 1e-12);
+    let mut reference = neel(n);
+    trotter_step_heisenberg(&mut reference, &xy, 0.3, trunc);
+    assert!((total_sz(&psi) - total_sz(&reference)).abs() < 1e-12);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "total Sz drifted")]
+fn checked_step_flags_drift_beyond_tolerance() {
+    let n = 4;
+    let h = Heisenberg::uniform(n, 1.0);
+    let trunc = Truncation::builder().max_bond(1).cutoff(0.0).build();
+    let mut psi = neel(n);
+    psi.apply_1q(1, rx(1.2));
+
+    // Bond dimension 1 cannot hold the entangled state; the truncation breaks the symmetry.
+    for _ in 0..5 {
+        trotter_step_heisenberg_checked(&mut psi, &h, 0.3, trunc, 1e-6);
+    }
+}
@@ -0,0 +1,44 @@
+use quantum::{
+    energy::{energy, energy_heisenberg, energy_pauli_sum, EnergyCache},
+    gates::{hadamard, rx},
+    hamiltonian::{Hamiltonian, Heisenberg, PauliSum},
+    observables::{expect_x, expect_z},
+};
+use tn::{mps::MPS, truncation::Truncation};
+
+#[test]
+fn empty_chain_has_zero_energy() {
+    let psi = MPS::new_zero(0);
+
+    assert_eq!(energy(&psi, &Hamiltonian::ising(0, 1.0, 1.0)), 0.0);
+    assert_eq!(energy_heisenberg(&psi, &Heisenberg::uniform(0, 1.0)), 0.0);
+    assert_eq!(energy_pauli_sum(&psi, &PauliSum::new(0)), 0.0);
+    assert_eq!(EnergyCache::new(&psi, &Heisenberg::uniform(0, 1.0)).energy(), 0.0);
+
+    let mut psi = psi;
+    psi.compress(Truncation::builder().max_bond(4).cutoff(1e-12).build());
+    assert!(psi.sites().is_empty());
+}
+
+#[test]
+fn single_site_observables() {
+    let mut psi = MPS::new_zero(1);
+    assert!((expect_z(&psi, 0) - 1.0).abs() < 1e-12);
+
+    psi.apply_1q(0, hadamard());
+    assert!(expect_z(&psi, 0).abs() < 1e-12);
+    assert!((expect_x(&psi, 0) - 1.0).abs() < 1e-12);
+
+    // One site, no bonds: only the field term contributes.
+    let h = Hamiltonian::ising(1, 0.5, 1.0);
+    assert!(h.zz_couplings.is_empty());
+    let mut psi = MPS::new_zero(1);
+    psi.apply_1q(0, rx(std::f64::consts::PI));
+    assert!((energy(&psi, &h) + 0.5).abs() < 1e-12);
+
+    let heis = Heisenberg::uniform(1, 1.0);
+    assert_eq!(energy_heisenberg(&psi, &heis), 0.0);
+    let mut cache = EnergyCache::new(&psi, &heis);
+    cache.apply_1q(&mut psi, 0, hadamard());
+    assert_eq!(cache.energy(), 0.0);
+}
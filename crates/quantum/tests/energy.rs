@@ -1,12 +1,18 @@
-use quantum::{apply_cnot, energy::energy, gates::hadamard, hamiltonian::Hamiltonian};
+mod common;
+
+use common::random_rotation;
+use quantum::{
+    apply_cnot,
+    energy::{energy, energy_diagonal_fast},
+    gates::hadamard,
+    hamiltonian::Hamiltonian,
+};
+use rng::ONDRng;
 use tn::{mps::MPS, truncation::Truncation};
 
 #[test]
 fn bell_energy_ising() {
-    let trunc = Truncation {
-        max_bond: 8,
-        cutoff: 1e-12,
-    };
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-12).build();
     let mut psi = MPS::new_zero(2);
 
     psi.apply_1q(0, hadamard());
@@ -20,3 +26,48 @@ fn bell_energy_ising() {
     let e = energy(&psi, &h);
     assert!((e - 1.0).abs() < 1e-12);
 }
+
+fn random_state(n: usize, depth: usize, rng: &mut ONDRng) -> MPS {
+    let trunc = Truncation::builder().max_bond(16).cutoff(1e-14).build();
+    let mut psi = MPS::new_zero(n);
+    for layer in 0..depth {
+        for k in 0..n {
+            psi.apply_1q(k, random_rotation(rng));
+        }
+        let mut k = layer % 2;
+        while k + 1 < n {
+            apply_cnot(&mut psi, k, trunc);
+            k += 2;
+        }
+    }
+    psi
+}
+
+#[test]
+fn diagonal_fast_matches_energy_on_random_states() {
+    let mut rng = ONDRng::new(b"energy-fast");
+    for (n, depth) in [(1, 1), (2, 3), (5, 4), (8, 6)] {
+        let psi = random_state(n, depth, &mut rng);
+        let h = Hamiltonian {
+            z_fields: (0..n).map(|_| rng.next_f64(b"h") - 0.5).collect(),
+            zz_couplings: (0..n - 1).map(|_| rng.next_f64(b"j") - 0.5).collect(),
+        };
+
+        let slow = energy(&psi, &h);
+        let fast = energy_diagonal_fast(&psi, &h);
+        assert!((slow - fast).abs() < 1e-12, "n = {n}: {slow} vs {fast}");
+    }
+}
+
+#[test]
+fn diagonal_fast_treats_missing_terms_as_zero() {
+    let mut rng = ONDRng::new(b"energy-fast-short");
+    let psi = random_state(4, 3, &mut rng);
+    let h = Hamiltonian {
+        z_fields: vec![0.3],
+        zz_couplings: vec![0.0, -0.7],
+    };
+
+    let fast = energy_diagonal_fast(&psi, &h);
+    assert!((energy(&psi, &h) - fast).abs() < 1e-12);
+}
@@ -1,8 +1,8 @@
 use quantum::{
     apply_cnot,
     energy::energy,
-    energy_shots::estimate_energy_shots,
-    gates::hadamard,
+    energy_shots::{control_variate_energy, estimate_energy_shots, shot_analytic_gap},
+    gates::{hadamard, rx},
     hamiltonian::Hamiltonian,
 };
 use rng::ONDRng;
@@ -10,10 +10,7 @@ use tn::{mps::MPS, truncation::Truncation};
 
 #[test]
 fn shot_energy_converges() {
-    let trunc = Truncation {
-        max_bond: 8,
-        cutoff: 1e-12,
-    };
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-12).build();
     let mut psi = MPS::new_zero(2);
 
     psi.apply_1q(0, hadamard());
@@ -31,3 +28,77 @@ fn shot_energy_converges() {
 
     assert!((est - exact).abs() < 0.05);
 }
+
+#[test]
+fn shot_gap_shrinks_with_more_shots() {
+    let mut psi = MPS::new_zero(2);
+    psi.apply_1q(0, rx(1.1));
+    psi.apply_1q(1, rx(0.4));
+
+    let h = Hamiltonian {
+        z_fields: vec![0.5, -0.3],
+        zz_couplings: vec![1.0],
+    };
+
+    let mut gap_small = 0.0;
+    let mut gap_large = 0.0;
+    for seed in 0..2 {
+        let mut rng = ONDRng::new(format!("gap-small-{}", seed).as_bytes());
+        gap_small += shot_analytic_gap(&psi, &h, &mut rng, 100);
+        let mut rng = ONDRng::new(format!("gap-large-{}", seed).as_bytes());
+        gap_large += shot_analytic_gap(&psi, &h, &mut rng, 10000);
+    }
+
+    assert!(
+        gap_large < gap_small,
+        "gap(10000) = {}, gap(100) = {}",
+        gap_large / 2.0,
+        gap_small / 2.0
+    );
+}
+
+#[test]
+fn control_variate_is_exact_for_identical_surrogate() {
+    let mut psi = MPS::new_zero(3);
+    psi.apply_1q(0, rx(1.1));
+    psi.apply_1q(2, rx(0.4));
+
+    let h = Hamiltonian {
+        z_fields: vec![0.5, -0.3, 0.2],
+        zz_couplings: vec![1.0, -0.7],
+    };
+
+    let exact = energy(&psi, &h);
+    for seed in 0..3 {
+        let mut rng = ONDRng::new(format!("cv-{}", seed).as_bytes());
+        let est = control_variate_energy(&psi, &psi.clone(), &h, &mut rng, 50);
+        assert_eq!(est, exact);
+    }
+}
+
+#[test]
+fn control_variate_reduces_error_for_nearby_surrogate() {
+    let mut psi = MPS::new_zero(2);
+    psi.apply_1q(0, rx(1.1));
+    psi.apply_1q(1, rx(0.4));
+    let mut surrogate = MPS::new_zero(2);
+    surrogate.apply_1q(0, rx(1.15));
+    surrogate.apply_1q(1, rx(0.45));
+
+    let h = Hamiltonian {
+        z_fields: vec![0.5, -0.3],
+        zz_couplings: vec![1.0],
+    };
+    let exact = energy(&psi, &h);
+
+    let mut err_plain = 0.0;
+    let mut err_cv = 0.0;
+    for seed in 0..10 {
+        let mut rng = ONDRng::new(format!("cv-plain-{}", seed).as_bytes());
+        err_plain += (estimate_energy_shots(&psi, &h, &mut rng, 200) - exact).powi(2);
+        let mut rng = ONDRng::new(format!("cv-plain-{}", seed).as_bytes());
+        err_cv += (control_variate_energy(&psi, &surrogate, &h, &mut rng, 200) - exact).powi(2);
+    }
+
+    assert!(err_cv < err_plain, "cv = {}, plain = {}", err_cv, err_plain);
+}
@@ -0,0 +1,43 @@
+use quantum::gates::{cz, fsim, iswap};
+use std::f64::consts::PI;
+use tn::mps::C64;
+
+fn max_diff(a: [[C64; 4]; 4], b: [[C64; 4]; 4]) -> f64 {
+    let mut d = 0.0f64;
+    for i in 0..4 {
+        for j in 0..4 {
+            d = d.max((a[i][j] - b[i][j]).norm());
+        }
+    }
+    d
+}
+
+fn adjoint(u: [[C64; 4]; 4]) -> [[C64; 4]; 4] {
+    let mut out = [[C64::new(0.0, 0.0); 4]; 4];
+    for i in 0..4 {
+        for j in 0..4 {
+            out[i][j] = u[j][i].conj();
+        }
+    }
+    out
+}
+
+#[test]
+fn fsim_limits_match_named_gates() {
+    // Google's fSim uses -i on the swap block, so θ = π/2 lands on iSWAP†.
+    assert!(max_diff(fsim(PI / 2.0, 0.0), adjoint(iswap())) < 1e-12);
+    assert!(max_diff(fsim(0.0, PI), cz()) < 1e-12);
+}
+
+#[test]
+fn fsim_is_unitary() {
+    let u = fsim(0.7, 1.9);
+    let uh = adjoint(u);
+    for (i, row) in uh.iter().enumerate() {
+        for j in 0..4 {
+            let acc: C64 = row.iter().zip(&u).map(|(&h, u_k)| h * u_k[j]).sum();
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((acc - expected).norm() < 1e-12, "U^H U [{}][{}] = {}", i, j, acc);
+        }
+    }
+}
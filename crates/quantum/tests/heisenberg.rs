@@ -1,18 +1,21 @@
 use quantum::{
     apply_cnot,
-    energy::energy_heisenberg,
-    gates::hadamard,
+    energy::{
+        energy_density_heisenberg, energy_heisenberg, energy_heisenberg_canonical, EnergyCache,
+    },
+    gates::{hadamard, pauli_x, rx},
     hamiltonian::Heisenberg,
     observables::{expect_xx, expect_yy, expect_zz},
 };
-use tn::{mps::MPS, truncation::Truncation};
+use rng::ONDRng;
+use tn::{
+    mps::{C64, MPS},
+    truncation::Truncation,
+};
 
 #[test]
 fn bell_heisenberg_observables() {
-    let trunc = Truncation {
-        max_bond: 8,
-        cutoff: 1e-12,
-    };
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-12).build();
     let mut psi = MPS::new_zero(2);
 
     psi.apply_1q(0, hadamard());
@@ -37,10 +40,7 @@ fn bell_heisenberg_observables() {
 
 #[test]
 fn heisenberg_bell_sanity_energy() {
-    let trunc = Truncation {
-        max_bond: 8,
-        cutoff: 1e-12,
-    };
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-12).build();
     let mut psi = MPS::new_zero(2);
 
     psi.apply_1q(0, hadamard());
@@ -58,3 +58,111 @@ fn heisenberg_bell_sanity_energy() {
     let expected = jx - jy + jz;
     assert!((e - expected).abs() < 1e-12, "E = {}", e);
 }
+
+#[test]
+fn disordered_heisenberg_is_reproducible() {
+    let n = 8;
+
+    let mut rng = ONDRng::new(b"disorder");
+    let clean = Heisenberg::disordered(n, 1.0, 0.0, &mut rng);
+    let uniform = Heisenberg::uniform(n, 1.0);
+    assert_eq!(clean.jx, uniform.jx);
+    assert_eq!(clean.jy, uniform.jy);
+    assert_eq!(clean.jz, uniform.jz);
+
+    let mut rng1 = ONDRng::new(b"disorder");
+    let mut rng2 = ONDRng::new(b"disorder");
+    let h1 = Heisenberg::disordered(n, 1.0, 0.5, &mut rng1);
+    let h2 = Heisenberg::disordered(n, 1.0, 0.5, &mut rng2);
+    assert_eq!(h1.jz, h2.jz);
+
+    assert_eq!(h1.jz.len(), n - 1);
+    assert!(h1.jz.iter().all(|&j| (0.5..=1.5).contains(&j)), "J = {:?}", h1.jz);
+    assert!(
+        h1.jz.windows(2).any(|w| w[0] != w[1]),
+        "couplings do not vary: {:?}",
+        h1.jz
+    );
+}
+
+#[test]
+fn energy_cache_tracks_single_site_updates() {
+    let trunc = Truncation::builder().max_bond(16).cutoff(1e-12).build();
+    let n = 5;
+    let mut psi = MPS::new_zero(n);
+    for k in 0..n {
+        psi.apply_1q(k, rx(0.4 + 0.3 * k as f64));
+    }
+    for k in 0..n - 1 {
+        apply_cnot(&mut psi, k, trunc);
+    }
+
+    let mut rng = ONDRng::new(b"energy-cache");
+    let h = Heisenberg::disordered(n, 1.0, 0.5, &mut rng);
+    let mut cache = EnergyCache::new(&psi, &h);
+    assert!((cache.energy() - energy_heisenberg(&psi, &h)).abs() < 1e-10);
+
+    for step in 0..12 {
+        let k = (step * 3) % n;
+        let u = if step % 4 == 3 { pauli_x() } else { rx(0.2 + 0.1 * step as f64) };
+        cache.apply_1q(&mut psi, k, u);
+
+        let full = energy_heisenberg(&psi, &h);
+        let cached = cache.energy();
+        assert!((cached - full).abs() < 1e-10, "step {}: {} vs {}", step, cached, full);
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "needs a unitary gate")]
+fn energy_cache_rejects_non_unitary_gates() {
+    let mut psi = MPS::new_zero(3);
+    let mut cache = EnergyCache::new(&psi, &Heisenberg::uniform(3, 1.0));
+    let half = C64::new(0.5, 0.0);
+    cache.apply_1q(&mut psi, 1, [[half, half], [half, half]]);
+}
+
+#[test]
+fn energy_density_sums_to_total() {
+    let trunc = Truncation::builder().max_bond(16).cutoff(1e-12).build();
+    let n = 6;
+    let mut psi = MPS::new_zero(n);
+    for k in 0..n {
+        psi.apply_1q(k, rx(0.3 + 0.5 * k as f64));
+    }
+    for k in 0..n - 1 {
+        apply_cnot(&mut psi, k, trunc);
+    }
+
+    let mut rng = ONDRng::new(b"energy-density");
+    let h = Heisenberg::disordered(n, 1.0, 0.8, &mut rng);
+    let density = energy_density_heisenberg(&psi, &h);
+
+    assert_eq!(density.len(), n - 1);
+    let total: f64 = density.iter().sum();
+    assert!((total - energy_heisenberg(&psi, &h)).abs() < 1e-10);
+}
+
+#[test]
+fn canonical_sweep_matches_per_term_energy() {
+    let trunc = Truncation::builder().max_bond(16).cutoff(1e-12).build();
+    let n = 7;
+    let mut psi = MPS::new_zero(n);
+    for layer in 0..3 {
+        for k in 0..n {
+            psi.apply_1q(k, rx(0.2 + 0.45 * (k + 2 * layer) as f64));
+        }
+        for k in (layer % 2..n - 1).step_by(2) {
+            apply_cnot(&mut psi, k, trunc);
+        }
+    }
+
+    let mut rng = ONDRng::new(b"canonical-energy");
+    let h = Heisenberg::disordered(n, 1.0, 0.6, &mut rng);
+    let naive = energy_heisenberg(&psi, &h);
+    let canonical = energy_heisenberg_canonical(&psi, &h);
+    assert!((naive - canonical).abs() < 1e-10, "{} vs {}", naive, canonical);
+
+    assert_eq!(energy_heisenberg_canonical(&MPS::new_zero(1), &Heisenberg::uniform(1, 1.0)), 0.0);
+}
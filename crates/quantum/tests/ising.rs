@@ -0,0 +1,21 @@
+use quantum::hamiltonian::Hamiltonian;
+use rng::ONDRng;
+
+#[test]
+fn random_field_ising_is_reproducible() {
+    let n = 10;
+
+    let mut rng1 = ONDRng::new(b"rfim");
+    let mut rng2 = ONDRng::new(b"rfim");
+    let h1 = Hamiltonian::random_field_ising(n, 1.0, 0.8, &mut rng1);
+    let h2 = Hamiltonian::random_field_ising(n, 1.0, 0.8, &mut rng2);
+
+    assert_eq!(h1.z_fields, h2.z_fields);
+    assert_eq!(h1.zz_couplings, vec![1.0; n - 1]);
+    assert!(h1.z_fields.iter().all(|&h| (-0.8..=0.8).contains(&h)), "h = {:?}", h1.z_fields);
+    assert!(h1.z_fields.windows(2).any(|w| w[0] != w[1]));
+
+    let mut rng = ONDRng::new(b"rfim");
+    let clean = Hamiltonian::random_field_ising(n, 1.0, 0.0, &mut rng);
+    assert!(clean.z_fields.iter().all(|&h| h == 0.0), "h = {:?}", clean.z_fields);
+}
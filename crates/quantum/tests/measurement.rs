@@ -0,0 +1,38 @@
+use quantum::{
+    gates::hadamard,
+    measurement::{measure_z, measure_z_checked},
+};
+use rng::ONDRng;
+use tn::mps::{C64, MPS};
+
+#[test]
+fn checked_matches_measure_z_on_normalized_state() {
+    let mut a = MPS::new_zero(3);
+    a.apply_1q(1, hadamard());
+    let mut b = a.clone();
+
+    let mut rng_a = ONDRng::new(b"checked");
+    let mut rng_b = ONDRng::new(b"checked");
+    for k in 0..3 {
+        let m = measure_z_checked(&mut a, k, &mut rng_a, 1e-9).expect("state is normalized");
+        assert_eq!(m, measure_z(&mut b, k, &mut rng_b));
+    }
+}
+
+#[test]
+fn checked_rejects_scaled_state() {
+    let mut psi = MPS::new_zero(2);
+    psi.apply_1q(0, hadamard());
+    for v in psi.site_mut(1).data.iter_mut() {
+        *v *= C64::new(0.5, 0.0);
+    }
+    let before = psi.clone();
+
+    let mut rng = ONDRng::new(b"scaled");
+    let err = measure_z_checked(&mut psi, 0, &mut rng, 1e-6).unwrap_err();
+    assert!((err.norm_sq - 0.25).abs() < 1e-12, "{err}");
+    assert_eq!(psi.sites()[0].data, before.sites()[0].data);
+
+    // The unchecked path quietly renormalizes and still returns an outcome.
+    assert!(measure_z(&mut psi, 0, &mut rng) <= 1);
+}
@@ -0,0 +1,182 @@
+use quantum::{
+    apply_cnot,
+    energy::{energy, energy_heisenberg, energy_pauli_sum, energy_terms},
+    gates::{hadamard, pauli_z, rx, Pauli},
+    hamiltonian::{Hamiltonian, Heisenberg, PauliSum},
+    mpo::{evolution_mpo_heisenberg, expect_mpo, Mpo},
+    observables::{expect_xx, expect_yy, expect_z, expect_zz},
+    trotter::trotter_step_heisenberg,
+};
+use rng::ONDRng;
+use tn::{
+    mps::{C64, MPS},
+    truncation::Truncation,
+};
+
+fn random_state(n: usize, seed: &[u8]) -> MPS {
+    let trunc = Truncation::builder().max_bond(16).cutoff(1e-12).build();
+    let mut rng = ONDRng::new(seed);
+    let mut psi = MPS::new_zero(n);
+    for _ in 0..3 {
+        for k in 0..n {
+            psi.apply_1q(k, rx(rng.next_f64(b"RX") * 2.0 * std::f64::consts::PI));
+            psi.apply_1q(k, hadamard());
+        }
+        for k in 0..n - 1 {
+            apply_cnot(&mut psi, k, trunc);
+        }
+    }
+    psi
+}
+
+#[test]
+fn mpo_energy_matches_heisenberg_on_bell() {
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-12).build();
+    let mut psi = MPS::new_zero(2);
+
+    psi.apply_1q(0, hadamard());
+    apply_cnot(&mut psi, 0, trunc);
+
+    let h = Heisenberg {
+        jx: vec![1.0],
+        jy: vec![2.0],
+        jz: vec![3.0],
+    };
+    let e = expect_mpo(&psi, &Mpo::from_heisenberg(&h));
+    let expected = heisenberg_by_terms(&psi, &h);
+    assert!((e - expected).abs() < 1e-12, "E_mpo = {}, E = {}", e, expected);
+    assert!((energy_heisenberg(&psi, &h) - expected).abs() < 1e-12);
+}
+
+#[test]
+fn zz_product_mpo_matches_expect_zz() {
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-12).build();
+    let mut bell = MPS::new_zero(2);
+    bell.apply_1q(0, hadamard());
+    apply_cnot(&mut bell, 0, trunc);
+
+    let zz = Mpo::product(&[pauli_z(), pauli_z()]);
+    assert!((expect_mpo(&bell, &zz) - 1.0).abs() < 1e-12);
+    assert!((expect_mpo(&bell, &zz) - expect_zz(&bell, 0, 1)).abs() < 1e-12);
+
+    // Identities on the other sites pick out the same pair in a longer chain.
+    let psi = random_state(4, b"mpo-zz");
+    let id = [
+        [C64::new(1.0, 0.0), C64::new(0.0, 0.0)],
+        [C64::new(0.0, 0.0), C64::new(1.0, 0.0)],
+    ];
+    let zz = Mpo::product(&[id, pauli_z(), pauli_z(), id]);
+    assert!((expect_mpo(&psi, &zz) - expect_zz(&psi, 1, 2)).abs() < 1e-12);
+}
+
+#[test]
+fn mpo_energy_matches_heisenberg_on_random_state() {
+    let psi = random_state(4, b"mpo-random");
+
+    let h = Heisenberg {
+        jx: vec![0.7, -1.1, 0.4],
+        jy: vec![1.3, 0.2, -0.6],
+        jz: vec![-0.9, 0.5, 1.7],
+    };
+    let e = expect_mpo(&psi, &Mpo::from_heisenberg(&h));
+    let expected = heisenberg_by_terms(&psi, &h);
+    assert!((e - expected).abs() < 1e-10, "E_mpo = {}, E = {}", e, expected);
+    assert!((energy_heisenberg(&psi, &h) - expected).abs() < 1e-10);
+}
+
+#[test]
+fn mpo_energy_matches_ising_on_random_state() {
+    let psi = random_state(4, b"mpo-ising");
+
+    let h = Hamiltonian {
+        z_fields: vec![0.3, -0.2, 0.8, 0.1],
+        zz_couplings: vec![1.0, -0.5, 0.25],
+    };
+    let e = expect_mpo(&psi, &Mpo::from_hamiltonian(&h));
+    let mut expected = 0.0;
+    for (i, &f) in h.z_fields.iter().enumerate() {
+        expected += f * expect_z(&psi, i);
+    }
+    for (i, &j) in h.zz_couplings.iter().enumerate() {
+        expected += j * expect_zz(&psi, i, i + 1);
+    }
+    assert!((e - expected).abs() < 1e-10, "E_mpo = {}, E = {}", e, expected);
+    assert!((energy(&psi, &h) - expected).abs() < 1e-10);
+}
+
+#[test]
+fn pauli_sum_mpo_matches_term_by_term_energy() {
+    let psi = random_state(5, b"mpo-pauli-sum");
+
+    // Long-range strings, overlapping supports, a repeated site and the identity term.
+    let mut h = PauliSum::new(5);
+    h.add(0.7, &[(0, Pauli::X), (3, Pauli::Y)]);
+    h.add(-1.2, &[(1, Pauli::Z), (2, Pauli::X), (4, Pauli::Z)]);
+    h.add(0.4, &[(2, Pauli::Y)]);
+    h.add(0.9, &[(4, Pauli::X), (1, Pauli::Y)]);
+    h.add(-0.3, &[(3, Pauli::X), (3, Pauli::X), (0, Pauli::Z)]);
+    h.add(1.5, &[]);
+
+    let expected: f64 = energy_terms(&psi, &h).iter().map(|(_, e)| e).sum();
+    let e = expect_mpo(&psi, &Mpo::from_pauli_sum(&h));
+    assert!((e - expected).abs() < 1e-10, "E_mpo = {}, E = {}", e, expected);
+    assert!((energy_pauli_sum(&psi, &h) - expected).abs() < 1e-10);
+}
+
+fn heisenberg_by_terms(psi: &MPS, h: &Heisenberg) -> f64 {
+    let mut e = 0.0;
+    for i in 0..h.jx.len() {
+        e += h.jx[i] * expect_xx(psi, i, i + 1) + h.jy[i] * expect_yy(psi, i, i + 1);
+        e += h.jz[i] * expect_zz(psi, i, i + 1);
+    }
+    e
+}
+
+#[test]
+fn all_z_mpo_matches_pauli_z_on_every_site() {
+    let n = 4;
+    let trunc = Truncation::builder().max_bond(16).cutoff(1e-14).build();
+    let psi = random_state(n, b"mpo-all-z");
+
+    let mut via_mpo = psi.clone();
+    Mpo::product(&vec![pauli_z(); n]).apply(&mut via_mpo, trunc);
+
+    let mut via_gates = psi.clone();
+    for k in 0..n {
+        via_gates.apply_1q(k, pauli_z());
+    }
+
+    let base = psi.to_statevector();
+    let a = via_mpo.to_statevector();
+    let b = via_gates.to_statevector();
+    for x in 0..base.len() {
+        let parity = if (x as u32).count_ones().is_multiple_of(2) { 1.0 } else { -1.0 };
+        assert!((a[x] - b[x]).norm() < 1e-12, "amp[{}]: {} vs {}", x, a[x], b[x]);
+        assert!((a[x] - base[x] * parity).norm() < 1e-12, "amp[{}] sign", x);
+    }
+}
+
+#[test]
+fn evolution_mpo_matches_gate_trotter_step() {
+    let n = 5;
+    let trunc = Truncation::builder().max_bond(64).cutoff(1e-14).build();
+    let psi = random_state(n, b"mpo-trotter");
+    let h = Heisenberg {
+        jx: vec![1.0, 0.8, -0.3, 0.5],
+        jy: vec![0.6, 1.2, 0.9, -0.4],
+        jz: vec![-1.0, 0.7, 0.2, 1.5],
+    };
+    let dt = 0.05;
+
+    let mut via_mpo = psi.clone();
+    evolution_mpo_heisenberg(&h, dt).apply(&mut via_mpo, trunc);
+
+    let mut via_gates = psi.clone();
+    trotter_step_heisenberg(&mut via_gates, &h, dt, trunc);
+
+    let a = via_mpo.to_statevector();
+    let b = via_gates.to_statevector();
+    for x in 0..a.len() {
+        assert!((a[x] - b[x]).norm() < 1e-10, "amp[{}]: {} vs {}", x, a[x], b[x]);
+    }
+}
@@ -0,0 +1,193 @@
+use quantum::{
+    gates::{cnot, fsim, hadamard, kron, pauli_z, rx},
+    noise::{depolarizing_1q, depolarizing_2q, pauli_twirl_2q},
+    observables::{expect_xx, expect_z, expect_zz},
+};
+use rng::ONDRng;
+use tn::{
+    mps::{fidelity, C64, MPS},
+    truncation::Truncation,
+};
+
+/// Identify which Pauli hit a qubit prepared in cos(a)|0> + sin(a)|1>.
+fn kicked_pauli(psi: &MPS, k: usize, c: f64, s: f64) -> char {
+    let a0 = psi.sites()[k].get(0, 0, 0);
+    let a1 = psi.sites()[k].get(0, 1, 0);
+    if (a0 - C64::new(s, 0.0)).norm() < 1e-12 && (a1 - C64::new(c, 0.0)).norm() < 1e-12 {
+        'X'
+    } else if (a0 - C64::new(0.0, -s)).norm() < 1e-12 && (a1 - C64::new(0.0, c)).norm() < 1e-12 {
+        'Y'
+    } else if (a0 - C64::new(c, 0.0)).norm() < 1e-12 && (a1 - C64::new(-s, 0.0)).norm() < 1e-12 {
+        'Z'
+    } else {
+        'I'
+    }
+}
+
+#[test]
+fn adjacent_qubits_get_independent_paulis() {
+    let (c, s) = (0.3f64.cos(), 0.3f64.sin());
+    let ry = [
+        [C64::new(c, 0.0), C64::new(-s, 0.0)],
+        [C64::new(s, 0.0), C64::new(c, 0.0)],
+    ];
+
+    let mut differ = 0;
+    for trial in 0..50 {
+        let mut rng = ONDRng::new(format!("noise-{}", trial).as_bytes());
+        let mut psi = MPS::new_zero(2);
+        psi.apply_1q(0, ry);
+        psi.apply_1q(1, ry);
+
+        depolarizing_1q(&mut psi, 0, 1.0, &mut rng);
+        depolarizing_1q(&mut psi, 1, 1.0, &mut rng);
+
+        let p0 = kicked_pauli(&psi, 0, c, s);
+        let p1 = kicked_pauli(&psi, 1, c, s);
+        assert!(p0 != 'I' && p1 != 'I', "p = 1 must always kick");
+        if p0 != p1 {
+            differ += 1;
+        }
+    }
+
+    assert!(differ > 0, "adjacent qubits always received the same Pauli");
+}
+
+#[test]
+fn two_qubit_depolarizing_draws_every_nonidentity_pair() {
+    let (c, s) = (0.3f64.cos(), 0.3f64.sin());
+    let ry = [
+        [C64::new(c, 0.0), C64::new(-s, 0.0)],
+        [C64::new(s, 0.0), C64::new(c, 0.0)],
+    ];
+
+    let mut seen = std::collections::BTreeSet::new();
+    for trial in 0..300 {
+        let mut rng = ONDRng::new(format!("noise-2q-{}", trial).as_bytes());
+        let mut psi = MPS::new_zero(2);
+        psi.apply_1q(0, ry);
+        psi.apply_1q(1, ry);
+
+        depolarizing_2q(&mut psi, 0, 1.0, &mut rng);
+        let pair = (kicked_pauli(&psi, 0, c, s), kicked_pauli(&psi, 1, c, s));
+        assert_ne!(pair, ('I', 'I'), "p = 1 must always kick");
+        seen.insert(pair);
+    }
+    assert_eq!(seen.len(), 15, "{:?}", seen);
+
+    let mut rng = ONDRng::new(b"noise-2q-off");
+    let mut psi = MPS::new_zero(2);
+    psi.apply_1q(0, ry);
+    let before = psi.clone();
+    depolarizing_2q(&mut psi, 0, 0.0, &mut rng);
+    assert_eq!(psi.sites()[0].data, before.sites()[0].data);
+}
+
+fn twirl_input() -> MPS {
+    let mut psi = MPS::new_zero(3);
+    psi.apply_1q(0, hadamard());
+    psi.apply_1q(1, rx(0.7));
+    psi.apply_1q(2, rx(-1.3));
+    psi
+}
+
+fn observables(psi: &MPS) -> [f64; 3] {
+    [expect_xx(psi, 0, 1), expect_z(psi, 1), expect_zz(psi, 0, 1)]
+}
+
+#[test]
+fn twirled_coherent_zz_error_averages_to_a_pauli_channel() {
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-14).build();
+    // actual = CNOT · exp(-iεZZ); twirling turns the over-rotation into ZZ flips
+    // with probability sin²ε, so the average is
+    // cos²ε <O>(CNOT ψ) + sin²ε <O>(CNOT · ZZ ψ).
+    let eps: f64 = 0.3;
+    let zz = kron(pauli_z(), pauli_z());
+    let mut err = [[C64::new(0.0, 0.0); 4]; 4];
+    for i in 0..4 {
+        err[i][i] = C64::new(eps.cos(), 0.0) - C64::new(0.0, eps.sin()) * zz[i][i];
+    }
+    let mut actual = [[C64::new(0.0, 0.0); 4]; 4];
+    for (row, c) in actual.iter_mut().zip(cnot()) {
+        for (j, x) in row.iter_mut().enumerate() {
+            *x = c[j] * err[j][j];
+        }
+    }
+
+    // Qubit 0 needs a Y component for the coherent cross term in <XX> to survive.
+    let mut psi = MPS::new_zero(2);
+    psi.apply_1q(0, rx(1.1));
+    psi.apply_1q(1, rx(0.7));
+    let mut ideal = psi.clone();
+    ideal.apply_2q_svd(0, cnot(), trunc);
+    let mut flipped = psi.clone();
+    flipped.apply_1q(0, pauli_z());
+    flipped.apply_1q(1, pauli_z());
+    flipped.apply_2q_svd(0, cnot(), trunc);
+    let (c2, s2) = (eps.cos().powi(2), eps.sin().powi(2));
+    let channel: Vec<f64> = observables(&ideal)
+        .iter()
+        .zip(observables(&flipped))
+        .map(|(a, b)| c2 * a + s2 * b)
+        .collect();
+
+    let mut coherent = psi.clone();
+    coherent.apply_2q_svd(0, actual, trunc);
+
+    let trajectories = 4000;
+    let mut avg = [0.0; 3];
+    for t in 0..trajectories {
+        let mut rng = ONDRng::new(format!("twirl-zz-{}", t).as_bytes());
+        let mut phi = psi.clone();
+        pauli_twirl_2q(&mut phi, 0, cnot(), actual, trunc, &mut rng);
+        for (a, o) in avg.iter_mut().zip(observables(&phi)) {
+            *a += o / trajectories as f64;
+        }
+    }
+
+    // Each trajectory carries a ± cross term of size 2 sin ε cos ε |<Y0 Z1>| ≈ 0.39
+    // that averages out like 1/√N, so 0.04 is several standard deviations.
+    for (a, want) in avg.iter().zip(&channel) {
+        assert!((a - want).abs() < 0.04, "twirled {} vs channel {}", a, want);
+    }
+    assert!(
+        observables(&coherent)
+            .iter()
+            .zip(&channel)
+            .any(|(raw, want)| (raw - want).abs() > 0.15),
+        "coherent error indistinguishable from the channel: {:?} vs {:?}",
+        observables(&coherent),
+        channel
+    );
+}
+
+#[test]
+fn twirled_non_clifford_gate_matches_the_plain_gate() {
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-14).build();
+    let gate = fsim(0.4, 0.9);
+    let psi = twirl_input();
+    let mut plain = psi.clone();
+    plain.apply_2q_svd(0, gate, trunc);
+
+    for t in 0..16 {
+        let mut rng = ONDRng::new(format!("twirl-fsim-{}", t).as_bytes());
+        let mut twirled = psi.clone();
+        pauli_twirl_2q(&mut twirled, 0, gate, gate, trunc, &mut rng);
+        assert!((fidelity(&plain, &twirled) - 1.0).abs() < 1e-10, "trajectory {}", t);
+    }
+}
+
+#[test]
+fn twirled_cnot_matches_plain_cnot() {
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-14).build();
+    let psi = twirl_input();
+    let mut plain = psi.clone();
+    plain.apply_2q_svd(1, cnot(), trunc);
+
+    for t in 0..16 {
+        let mut rng = ONDRng::new(format!("twirl-cnot-{}", t).as_bytes());
+        let mut twirled = psi.clone();
+        pauli_twirl_2q(&mut twirled, 1, cnot(), cnot(), trunc, &mut rng);
+        assert!((fidelity(&plain, &twirled) - 1.0).abs() < 1e-10, "trajectory {}", t);
+    }
+}
@@ -1,12 +1,21 @@
-use quantum::{apply_cnot, gates::hadamard, observables::{expect_z, expect_zz}};
-use tn::{mps::MPS, truncation::Truncation};
+use quantum::{
+    apply_cnot,
+    energy::{energy_observables, energy_pauli_sum, energy_terms, energy_variance},
+    gates::{cnot, hadamard, kron, pauli_x, pauli_y, pauli_z, rotation, rx, Pauli},
+    hamiltonian::{PauliSum, PauliTerm},
+    observables::{
+        chsh_value, expect_jw_hopping, expect_pauli_string, expect_x, expect_y, expect_z,
+        expect_zz, Observable, SingleSite, TwoSite,
+    },
+};
+use tn::{
+    mps::{C64, MPS},
+    truncation::Truncation,
+};
 
 #[test]
 fn bell_observables() {
-    let trunc = Truncation {
-        max_bond: 8,
-        cutoff: 1e-12,
-    };
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-12).build();
     let mut psi = MPS::new_zero(2);
 
     psi.apply_1q(0, hadamard());
@@ -16,3 +25,297 @@ fn bell_observables() {
     assert!(expect_z(&psi, 1).abs() < 1e-12);
     assert!((expect_zz(&psi, 0, 1) - 1.0).abs() < 1e-12);
 }
+
+#[test]
+fn chsh_bell_reaches_tsirelson_bound() {
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-12).build();
+    let mut psi = MPS::new_zero(2);
+
+    psi.apply_1q(0, hadamard());
+    apply_cnot(&mut psi, 0, trunc);
+
+    let s = chsh_value(&psi);
+    assert!((s - 2.0 * 2.0f64.sqrt()).abs() < 1e-6, "S = {}", s);
+
+    let product = MPS::new_zero(2);
+    let s = chsh_value(&product);
+    assert!(s.abs() <= 2.0 + 1e-12, "S = {}", s);
+}
+
+#[test]
+fn pauli_strings_on_ghz() {
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-12).build();
+    let mut psi = MPS::new_zero(3);
+    psi.apply_1q(0, hadamard());
+    apply_cnot(&mut psi, 0, trunc);
+    apply_cnot(&mut psi, 1, trunc);
+
+    let zz_far = expect_pauli_string(&psi, &[(0, Pauli::Z), (2, Pauli::Z)]);
+    let xxx = expect_pauli_string(&psi, &[(0, Pauli::X), (1, Pauli::X), (2, Pauli::X)]);
+    let z1 = expect_pauli_string(&psi, &[(1, Pauli::Z)]);
+    assert!((zz_far - 1.0).abs() < 1e-12);
+    assert!((xxx - 1.0).abs() < 1e-12);
+    assert!(z1.abs() < 1e-12);
+
+    let mut h = PauliSum::new(3);
+    h.add(2.0, &[(0, Pauli::Z), (2, Pauli::Z)]);
+    h.add(-0.5, &[(0, Pauli::X), (1, Pauli::X), (2, Pauli::X)]);
+    assert!((energy_pauli_sum(&psi, &h) - 1.5).abs() < 1e-12);
+}
+
+#[test]
+fn energy_variance_vanishes_only_on_eigenstates() {
+    let mut h = PauliSum::new(3);
+    h.add(1.0, &[(0, Pauli::Z), (1, Pauli::Z)]);
+    h.add(-0.5, &[(1, Pauli::Z), (2, Pauli::Z)]);
+    h.add(0.3, &[(2, Pauli::Z)]);
+
+    let basis = MPS::from_bitstring(&[0, 1, 1]);
+    assert!(energy_variance(&basis, &h).abs() < 1e-12);
+
+    let mut plus = MPS::new_zero(3);
+    plus.apply_1q(1, hadamard());
+    // Only Z1 (Z0 - 0.5 Z2) fluctuates, and Z0 - 0.5 Z2 = 0.5 on |0_0_0>.
+    let var = energy_variance(&plus, &h);
+    assert!((var - 0.25).abs() < 1e-12, "var = {}", var);
+
+    // Non-commuting terms: H = X0 + Z0 on |0> has ⟨H⟩ = 1, ⟨H²⟩ = 2.
+    let mut h = PauliSum::new(1);
+    h.add(1.0, &[(0, Pauli::X)]);
+    h.add(1.0, &[(0, Pauli::Z)]);
+    let zero = MPS::new_zero(1);
+    assert!((energy_variance(&zero, &h) - 1.0).abs() < 1e-12);
+
+    let mut rotated = MPS::new_zero(1);
+    rotated.apply_1q(0, rx(0.8));
+    assert!(energy_variance(&rotated, &h) > 0.0);
+}
+
+#[test]
+fn energy_terms_label_and_split_bell_energy() {
+    let trunc = Truncation::exact();
+    let mut psi = MPS::new_zero(2);
+    psi.apply_1q(0, hadamard());
+    apply_cnot(&mut psi, 0, trunc);
+
+    let mut h = PauliSum::new(2);
+    h.add(0.5, &[(0, Pauli::Z), (1, Pauli::Z)]);
+    h.add(-2.0, &[(0, Pauli::X), (1, Pauli::X)]);
+
+    let terms = energy_terms(&psi, &h);
+    assert_eq!(terms.len(), 2);
+    assert_eq!(terms[0].0, "Z0 Z1");
+    assert_eq!(terms[1].0, "X0 X1");
+    assert!((terms[0].1 - 0.5).abs() < 1e-12);
+    assert!((terms[1].1 + 2.0).abs() < 1e-12);
+
+    let total: f64 = terms.iter().map(|(_, e)| e).sum();
+    assert!((total - energy_pauli_sum(&psi, &h)).abs() < 1e-12);
+}
+
+#[test]
+fn center_fast_path_matches_environment_contraction() {
+    let trunc = Truncation::builder().max_bond(16).cutoff(1e-14).build();
+    let n = 6;
+    let mut psi = MPS::new_zero(n);
+    for layer in 0..3 {
+        for k in 0..n {
+            let t = (k + 3 * layer) as f64;
+            psi.apply_1q(k, rotation([t.sin(), 0.5, t.cos()], 0.4 + 0.3 * t));
+        }
+        for k in (layer % 2..n - 1).step_by(2) {
+            apply_cnot(&mut psi, k, trunc);
+        }
+    }
+
+    let mut y_max = 0.0f64;
+    for k in 0..n {
+        let mut fast = psi.clone();
+        fast.canonicalize_at(k);
+        assert_eq!(fast.orthogonality_center(), Some(k));
+        // Same tensors without the gauge record, so every call takes the slow path.
+        let slow = MPS::from_sites(fast.sites().to_vec());
+
+        // Y is not symmetric, so a transposed contraction flips its sign.
+        let y_ref = expect_pauli_string(&slow, &[(k, Pauli::Y)]);
+        y_max = y_max.max(y_ref.abs());
+        for (a, b) in [
+            (expect_z(&fast, k), expect_z(&slow, k)),
+            (expect_x(&fast, k), expect_x(&slow, k)),
+            (expect_y(&fast, k), y_ref),
+            (expect_z(&fast, k), expect_z(&psi, k)),
+        ] {
+            assert!((a - b).abs() < 1e-12, "site {}: {} vs {}", k, a, b);
+        }
+    }
+    assert!(y_max > 0.1, "max |<Y>| = {}", y_max);
+}
+
+#[test]
+fn writing_a_site_after_canonicalize_falls_back_to_the_slow_path() {
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-12).build();
+    let mut bell = MPS::new_zero(2);
+    bell.apply_1q(0, hadamard());
+    apply_cnot(&mut bell, 0, trunc);
+    bell.canonicalize_at(1);
+
+    // Project site 0 onto |0>: the pair is left in |00>, so <Z_1> = 1.
+    for (whole_slice, mut psi) in [(true, bell.clone()), (false, bell)] {
+        let s = if whole_slice {
+            &mut psi.sites_mut()[0]
+        } else {
+            psi.site_mut(0)
+        };
+        for l in 0..s.dl {
+            for r in 0..s.dr {
+                s.set(l, 1, r, C64::new(0.0, 0.0));
+            }
+        }
+        assert_eq!(psi.orthogonality_center(), None);
+
+        let slow = MPS::from_sites(psi.sites().to_vec());
+        assert!((expect_z(&psi, 1) - 1.0).abs() < 1e-12, "{}", expect_z(&psi, 1));
+        assert!((expect_z(&psi, 1) - expect_z(&slow, 1)).abs() < 1e-12);
+        assert!((expect_x(&psi, 1) - expect_x(&slow, 1)).abs() < 1e-12);
+    }
+}
+
+/// Probability of finding qubits (k, k+1) in |00>, built from Pauli strings.
+struct ProjectorOnZeros {
+    k: usize,
+}
+
+impl Observable for ProjectorOnZeros {
+    fn expectation(&self, psi: &MPS) -> f64 {
+        let (a, b) = (self.k, self.k + 1);
+        0.25 * (1.0
+            + expect_pauli_string(psi, &[(a, Pauli::Z)])
+            + expect_pauli_string(psi, &[(b, Pauli::Z)])
+            + expect_pauli_string(psi, &[(a, Pauli::Z), (b, Pauli::Z)]))
+    }
+}
+
+#[test]
+fn custom_observable_on_bell_state() {
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-12).build();
+    let mut psi = MPS::new_zero(2);
+    psi.apply_1q(0, hadamard());
+    apply_cnot(&mut psi, 0, trunc);
+
+    let p00 = ProjectorOnZeros { k: 0 };
+    assert!((p00.expectation(&psi) - 0.5).abs() < 1e-12);
+
+    let xx = TwoSite {
+        k: 0,
+        op: kron(pauli_x(), pauli_x()),
+    };
+    let x0 = SingleSite { k: 0, op: pauli_x() };
+    let zz = PauliTerm {
+        coeff: -2.0,
+        ops: vec![(0, Pauli::Z), (1, Pauli::Z)],
+    };
+    assert!((xx.expectation(&psi) - 1.0).abs() < 1e-12);
+    assert!(x0.expectation(&psi).abs() < 1e-12);
+
+    let terms: Vec<Box<dyn Observable>> =
+        vec![Box::new(p00), Box::new(xx), Box::new(x0), Box::new(zz)];
+    let e = energy_observables(&psi, &terms);
+    assert!((e - (0.5 + 1.0 + 0.0 - 2.0)).abs() < 1e-12, "E = {}", e);
+
+    // A CNOT maps the Bell state back to |+0>, where the projector reads 1/2 again.
+    psi.apply_2q_svd(0, cnot(), trunc);
+    assert!((terms[0].expectation(&psi) - 0.5).abs() < 1e-12);
+    assert!((expect_x(&psi, 0) - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn matrix_observables_agree_with_pauli_terms() {
+    let c = |re: f64, im: f64| C64::new(re, im);
+    // h = -0.1 I + 0.2 X + 0.7 Y + 0.4 Z, Hermitian with complex off-diagonals.
+    let h = [[c(0.3, 0.0), c(0.2, -0.7)], [c(0.2, 0.7), c(-0.5, 0.0)]];
+    let h_parts = [(0.2, Pauli::X), (0.7, Pauli::Y), (0.4, Pauli::Z)];
+    let term = |psi: &MPS, coeff: f64, ops: &[(usize, Pauli)]| {
+        PauliTerm {
+            coeff,
+            ops: ops.to_vec(),
+        }
+        .expectation(psi)
+    };
+
+    let mut psi = MPS::new_zero(3);
+    for k in 0..3 {
+        let t = k as f64;
+        psi.apply_1q(k, rotation([1.0, 0.2 * t, 0.3], 1.1 + 0.3 * t));
+    }
+    apply_cnot(&mut psi, 0, Truncation::exact());
+    apply_cnot(&mut psi, 1, Truncation::exact());
+
+    // Both the center fast path and the environment contraction.
+    let mut centered = psi.clone();
+    centered.canonicalize_at(1);
+    let plain = MPS::from_sites(psi.sites().to_vec());
+    for psi in [&centered, &plain] {
+        let y1 = term(psi, 1.0, &[(1, Pauli::Y)]);
+        assert!(y1.abs() > 0.1, "<Y1> = {}", y1);
+        let y = SingleSite { k: 1, op: pauli_y() };
+        assert!((y.expectation(psi) - y1).abs() < 1e-12);
+        assert!((expect_y(psi, 1) - y1).abs() < 1e-12);
+
+        let single = SingleSite { k: 1, op: h };
+        let expected = -0.1
+            + h_parts
+                .iter()
+                .map(|&(w, p)| term(psi, w, &[(1, p)]))
+                .sum::<f64>();
+        assert!((single.expectation(psi) - expected).abs() < 1e-12);
+
+        let yz = TwoSite {
+            k: 0,
+            op: kron(pauli_y(), pauli_z()),
+        };
+        let yz_ref = term(psi, 1.0, &[(0, Pauli::Y), (1, Pauli::Z)]);
+        assert!(yz_ref.abs() > 0.1, "<Y0 Z1> = {}", yz_ref);
+        assert!((yz.expectation(psi) - yz_ref).abs() < 1e-12);
+
+        // h on site 1 next to Y on site 2.
+        let hy = TwoSite {
+            k: 1,
+            op: kron(h, pauli_y()),
+        };
+        let expected = -0.1 * term(psi, 1.0, &[(2, Pauli::Y)])
+            + h_parts
+                .iter()
+                .map(|&(w, p)| term(psi, w, &[(1, p), (2, Pauli::Y)]))
+                .sum::<f64>();
+        assert!((hy.expectation(psi) - expected).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn jw_hopping_matches_pauli_strings() {
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-12).build();
+
+    // (|01> + |10>)/√2: one fermion delocalized over two modes, <c0†c1 + h.c.> = 1.
+    let mut psi = MPS::new_zero(2);
+    psi.apply_1q(0, hadamard());
+    apply_cnot(&mut psi, 0, trunc);
+    psi.apply_1q(1, pauli_x());
+    let direct = 0.5
+        * (expect_pauli_string(&psi, &[(0, Pauli::X), (1, Pauli::X)])
+            + expect_pauli_string(&psi, &[(0, Pauli::Y), (1, Pauli::Y)]));
+    assert!((expect_jw_hopping(&psi, 0, 1) - direct).abs() < 1e-12);
+    assert!((expect_jw_hopping(&psi, 1, 0) - 1.0).abs() < 1e-12);
+
+    let mut psi = MPS::new_zero(4);
+    for k in 0..4 {
+        psi.apply_1q(k, rotation([0.3, 1.0, 0.2 * k as f64], 0.5 + 0.4 * k as f64));
+    }
+    for k in 0..3 {
+        apply_cnot(&mut psi, k, trunc);
+    }
+    let z_string = |end: Pauli| vec![(0, end), (1, Pauli::Z), (2, Pauli::Z), (3, end)];
+    let direct = 0.5
+        * (expect_pauli_string(&psi, &z_string(Pauli::X))
+            + expect_pauli_string(&psi, &z_string(Pauli::Y)));
+    assert!((expect_jw_hopping(&psi, 0, 3) - direct).abs() < 1e-12);
+}
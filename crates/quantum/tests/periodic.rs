@@ -0,0 +1,132 @@
+use quantum::{
+    energy::{energy, energy_diagonal_fast, energy_heisenberg, energy_heisenberg_canonical},
+    gates::pauli_z,
+    hamiltonian::{Hamiltonian, Heisenberg},
+    mpo::Mpo,
+    observables::{expect_x, expect_z, expect_zz},
+};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use tn::{
+    mps::{fidelity, overlap, Boundary, Tensor3, C64, MPS},
+    truncation::Truncation,
+};
+
+/// Per-site amplitudes (cos a, e^{iφ} sin a) with site-dependent angles.
+fn site_amps(n: usize) -> Vec<[C64; 2]> {
+    (0..n)
+        .map(|i| {
+            let a = 0.3 + 0.4 * i as f64;
+            let phi = 0.7 * i as f64;
+            [C64::new(a.cos(), 0.0), C64::from_polar(a.sin(), phi)]
+        })
+        .collect()
+}
+
+/// The same product state as a ring with boundary bond 2: A_i[p] = c_p · I / 2^(1/n).
+fn product_ring(n: usize) -> MPS {
+    let scale = 0.5f64.powf(1.0 / n as f64);
+    let sites = site_amps(n)
+        .into_iter()
+        .map(|c| {
+            let mut t = Tensor3::zeros(2, 2, 2);
+            for (p, &amp) in c.iter().enumerate() {
+                for d in 0..2 {
+                    t.set(d, p, d, amp * scale);
+                }
+            }
+            t
+        })
+        .collect();
+    MPS::periodic(sites)
+}
+
+fn product_open(n: usize) -> MPS {
+    let sites = site_amps(n)
+        .into_iter()
+        .map(|c| {
+            let mut t = Tensor3::zeros(1, 2, 1);
+            t.set(0, 0, 0, c[0]);
+            t.set(0, 1, 0, c[1]);
+            t
+        })
+        .collect();
+    MPS::from_sites(sites)
+}
+
+#[test]
+fn periodic_product_state_norm_and_energy() {
+    let n = 6;
+    let ring = product_ring(n);
+    let open = product_open(n);
+    assert_eq!(ring.boundary(), Boundary::Periodic);
+
+    // Reading only the (0, 0) boundary entry would give 1/4 here, not 1.
+    assert!((overlap(&ring, &ring).re - 1.0).abs() < 1e-12);
+    assert!((fidelity(&ring, &open) - 1.0).abs() < 1e-12);
+    for (a, b) in ring.to_statevector().iter().zip(open.to_statevector()) {
+        assert!((a - b).norm() < 1e-12);
+    }
+
+    let h = Heisenberg::uniform(n, 1.0);
+    let e = energy_heisenberg(&open, &h);
+    assert!((energy_heisenberg(&ring, &h) - e).abs() < 1e-12);
+    assert!((energy_heisenberg_canonical(&ring, &h) - e).abs() < 1e-12);
+
+    let ising = Hamiltonian::ising(n, 0.5, 1.0);
+    let e = energy(&open, &ising);
+    assert!((energy(&ring, &ising) - e).abs() < 1e-12);
+    assert!((energy_diagonal_fast(&ring, &ising) - e).abs() < 1e-12);
+    assert!((expect_x(&ring, 2) - expect_x(&open, 2)).abs() < 1e-12);
+}
+
+#[test]
+fn ghz_ring_closes_through_the_boundary_bond() {
+    let n = 5;
+    let o = C64::new(1.0, 0.0);
+    // A[p] = |p><p| on the bond: the trace is nonzero only when every site agrees.
+    let sites = (0..n)
+        .map(|_| {
+            let mut t = Tensor3::zeros(2, 2, 2);
+            t.set(0, 0, 0, o);
+            t.set(1, 1, 1, o);
+            t
+        })
+        .collect();
+    let ring = MPS::periodic(sites);
+    assert!((overlap(&ring, &ring).re - 2.0).abs() < 1e-12);
+
+    let amps = ring.to_statevector();
+    assert_eq!(amps[0], o);
+    assert_eq!(amps[(1 << n) - 1], o);
+    assert!(amps[1..(1 << n) - 1].iter().all(|a| a.norm() == 0.0));
+
+    let open = ring.to_open();
+    assert_eq!(open.boundary(), Boundary::Open);
+    assert!((overlap(&open, &ring).re - 2.0).abs() < 1e-12);
+    assert!(expect_z(&ring, 0).abs() < 1e-12);
+    assert!((expect_zz(&ring, 3, 4) - 1.0).abs() < 1e-12);
+}
+
+#[test]
+#[should_panic(expected = "periodic: boundary bond mismatch")]
+fn periodic_needs_matching_boundary_bond() {
+    MPS::periodic(vec![Tensor3::zeros(2, 2, 1), Tensor3::zeros(1, 2, 1)]);
+}
+
+#[test]
+fn mpo_apply_rejects_a_ring_before_touching_it() {
+    let mut ring = product_ring(4);
+    let before = ring.clone();
+    let w = Mpo::product(&[pauli_z(); 4]);
+
+    let result = catch_unwind(AssertUnwindSafe(|| w.apply(&mut ring, Truncation::exact())));
+    assert!(result.is_err());
+    assert!(ring.is_periodic());
+    assert!(ring.sites().iter().zip(before.sites()).all(|(a, b)| a.data == b.data));
+
+    // The open embedding is the supported route; Z on every site flips ⟨X_0⟩.
+    let mut open = ring.to_open();
+    w.apply(&mut open, Truncation::exact());
+    assert!(expect_x(&ring, 0) > 0.5);
+    assert!((expect_x(&open, 0) + expect_x(&ring, 0)).abs() < 1e-12);
+}
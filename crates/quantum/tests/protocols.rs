@@ -0,0 +1,43 @@
+mod common;
+
+use common::bell;
+use quantum::protocols::{classical_shadow, swap_test, Pauli};
+use rng::ONDRng;
+use tn::mps::MPS;
+
+#[test]
+fn swap_test_identical_bell_states_is_one() {
+    let a = bell();
+    let b = bell();
+    let mut rng = ONDRng::new(b"swap-test");
+
+    let f = swap_test(&a, &b, 500, &mut rng);
+    assert!((f - 1.0).abs() < 1e-12, "swap test fidelity = {}", f);
+}
+
+#[test]
+fn swap_test_bell_vs_zero_is_half() {
+    let a = bell();
+    let b = MPS::new_zero(2);
+    let mut rng = ONDRng::new(b"swap-test");
+
+    let f = swap_test(&a, &b, 4000, &mut rng);
+    assert!((f - 0.5).abs() < 0.05, "swap test fidelity = {}", f);
+}
+
+#[test]
+fn shadow_recovers_bell_paulis() {
+    let psi = bell();
+    let mut rng = ONDRng::new(b"shadow-test");
+    let shadow = classical_shadow(&psi, 3000, &mut rng);
+
+    let z0 = shadow.estimate_pauli(&[(0, Pauli::Z)]);
+    let zz = shadow.estimate_pauli(&[(0, Pauli::Z), (1, Pauli::Z)]);
+    let xx = shadow.estimate_pauli(&[(0, Pauli::X), (1, Pauli::X)]);
+    let yy = shadow.estimate_pauli(&[(0, Pauli::Y), (1, Pauli::Y)]);
+
+    assert!(z0.abs() < 0.15, "<Z0> = {}", z0);
+    assert!((zz - 1.0).abs() < 0.2, "<Z0 Z1> = {}", zz);
+    assert!((xx - 1.0).abs() < 0.2, "<X0 X1> = {}", xx);
+    assert!((yy + 1.0).abs() < 0.2, "<Y0 Y1> = {}", yy);
+}
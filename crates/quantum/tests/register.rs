@@ -0,0 +1,74 @@
+use quantum::{
+    gates::pauli_x,
+    register::{apply_1q, apply_cnot, apply_cz, Qubit, Register},
+};
+use tn::{
+    mps::{bits_to_index, MPS},
+    truncation::Truncation,
+};
+
+fn basis_index(psi: &MPS) -> usize {
+    let amps = psi.to_statevector();
+    let (idx, amp) = amps
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.norm().total_cmp(&b.1.norm()))
+        .unwrap();
+    assert!((amp.norm() - 1.0).abs() < 1e-12, "not a basis state");
+    idx
+}
+
+#[test]
+fn swapped_cnot_arguments_are_honored_as_written() {
+    let trunc = Truncation::exact();
+    let reg = Register::new(3);
+
+    // |100>: control on qubit 0 flips qubit 1.
+    let mut psi = MPS::from_bitstring(&[1, 0, 0]);
+    apply_cnot(&mut psi, reg.qubit(0), reg.qubit(1), trunc);
+    assert_eq!(basis_index(&psi), bits_to_index(&[1, 1, 0]));
+
+    // Same state, arguments swapped: qubit 1 (= 0) controls, so nothing happens.
+    let mut psi = MPS::from_bitstring(&[1, 0, 0]);
+    apply_cnot(&mut psi, reg.qubit(1), reg.qubit(0), trunc);
+    assert_eq!(basis_index(&psi), bits_to_index(&[1, 0, 0]));
+
+    // Control on the right-hand site.
+    let mut psi = MPS::from_bitstring(&[0, 0, 1]);
+    apply_cnot(&mut psi, Qubit(2), Qubit(1), trunc);
+    assert_eq!(basis_index(&psi), bits_to_index(&[0, 1, 1]));
+
+    let mut psi = MPS::from_bitstring(&[0, 1, 1]);
+    apply_1q(&mut psi, Qubit(0), pauli_x());
+    apply_cz(&mut psi, Qubit(1), Qubit(0), trunc);
+    let amps = psi.to_statevector();
+    assert!((amps[bits_to_index(&[1, 1, 1])].re + 1.0).abs() < 1e-12);
+}
+
+#[test]
+#[should_panic(expected = "apply_cnot: both arguments are qubit 1")]
+fn cnot_on_the_same_qubit_is_rejected() {
+    let mut psi = MPS::new_zero(3);
+    apply_cnot(&mut psi, Qubit(1), Qubit(1), Truncation::exact());
+}
+
+#[test]
+#[should_panic(expected = "apply_cnot: qubits 0 and 2 are not nearest neighbours")]
+fn cnot_on_distant_qubits_is_rejected() {
+    let mut psi = MPS::new_zero(3);
+    apply_cnot(&mut psi, Qubit(0), Qubit(2), Truncation::exact());
+}
+
+#[test]
+#[should_panic(expected = "qubit 3 out of range for 3-qubit register")]
+fn register_bounds_are_checked() {
+    Register::new(3).qubit(3);
+}
+
+#[test]
+fn register_split_keeps_absolute_sites() {
+    let (a, b) = Register::new(5).split_at(2);
+    assert_eq!(a.qubits().collect::<Vec<_>>(), [Qubit(0), Qubit(1)]);
+    assert_eq!(b.qubit(0), Qubit(2));
+    assert_eq!(b.qubits().map(Qubit::index).collect::<Vec<_>>(), [2, 3, 4]);
+}
@@ -0,0 +1,71 @@
+use quantum::gates::{rotation, rx};
+use tn::mps::{C64, MPS};
+
+fn max_diff(a: [[C64; 2]; 2], b: [[C64; 2]; 2]) -> f64 {
+    let mut d = 0.0f64;
+    for i in 0..2 {
+        for j in 0..2 {
+            d = d.max((a[i][j] - b[i][j]).norm());
+        }
+    }
+    d
+}
+
+#[test]
+fn x_axis_matches_rx() {
+    for &theta in &[0.0, 0.4, 1.3, std::f64::consts::PI, -2.2] {
+        assert!(max_diff(rotation([1.0, 0.0, 0.0], theta), rx(theta)) < 1e-12);
+    }
+}
+
+#[test]
+fn tilted_axis_is_unitary() {
+    let u = rotation([0.3, -0.8, 0.5], 1.7);
+    for i in 0..2 {
+        for j in 0..2 {
+            let mut acc = C64::new(0.0, 0.0);
+            for row in &u {
+                acc += row[i].conj() * row[j];
+            }
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((acc - expected).norm() < 1e-12, "U^H U [{}][{}] = {}", i, j, acc);
+        }
+    }
+
+    // A full 2π turn about any axis is -I.
+    let full = rotation([0.3, -0.8, 0.5], 2.0 * std::f64::consts::PI);
+    let minus_i = [
+        [C64::new(-1.0, 0.0), C64::new(0.0, 0.0)],
+        [C64::new(0.0, 0.0), C64::new(-1.0, 0.0)],
+    ];
+    assert!(max_diff(full, minus_i) < 1e-12);
+}
+
+#[test]
+fn rotation_layer_matches_per_site_application() {
+    let rz = |theta: f64| rotation([0.0, 0.0, 1.0], theta);
+    let gates = [rx(0.3), rz(1.1), rx(-0.7), rz(2.4)];
+
+    let mut layered = MPS::new_zero(4);
+    layered.apply_1q_all(rx(0.9));
+    layered.apply_1q_layer(&gates);
+
+    let mut single = MPS::new_zero(4);
+    for (k, &u) in gates.iter().enumerate() {
+        single.apply_1q(k, rx(0.9));
+        single.apply_1q(k, u);
+    }
+
+    let a = layered.to_statevector();
+    let b = single.to_statevector();
+    for (x, y) in a.iter().zip(&b) {
+        assert!((x - y).norm() < 1e-15);
+    }
+}
+
+#[test]
+#[should_panic(expected = "one gate per site")]
+fn rotation_layer_rejects_wrong_length() {
+    let mut psi = MPS::new_zero(3);
+    psi.apply_1q_layer(&[rx(0.1), rx(0.2)]);
+}
@@ -0,0 +1,82 @@
+mod common;
+
+use common::bell;
+use quantum::{
+    apply_cnot,
+    gates::rx,
+    sampling::{sample_bitstring, sample_counts, sample_in_bases},
+};
+use rng::ONDRng;
+use tn::{mps::MPS, truncation::Truncation};
+
+#[test]
+fn bell_state_is_correlated_in_xx_basis() {
+    let psi = bell();
+    let mut rng = ONDRng::new(b"xx-basis");
+    let mut ones = 0;
+    for _ in 0..200 {
+        let s = sample_in_bases(&psi, &['X', 'X'], &mut rng);
+        assert_eq!(s[0], s[1], "XX outcomes must agree on |Φ+⟩");
+        ones += s[0] as usize;
+    }
+    // Each outcome is individually uniform.
+    assert!(ones > 60 && ones < 140, "ones = {}", ones);
+}
+
+#[test]
+fn bell_state_is_anticorrelated_in_yy_basis() {
+    // |Φ+⟩ has ⟨YY⟩ = -1.
+    let psi = bell();
+    let mut rng = ONDRng::new(b"yy-basis");
+    for _ in 0..100 {
+        let s = sample_in_bases(&psi, &['Y', 'Y'], &mut rng);
+        assert_ne!(s[0], s[1]);
+    }
+}
+
+#[test]
+#[should_panic(expected = "unknown basis 'W'")]
+fn unknown_basis_is_rejected() {
+    let mut rng = ONDRng::new(b"bad");
+    sample_in_bases(&bell(), &['Z', 'W'], &mut rng);
+}
+
+#[test]
+fn sample_counts_do_not_depend_on_thread_count() {
+    let mut psi = MPS::new_zero(4);
+    for k in 0..4 {
+        psi.apply_1q(k, rx(0.6 + 0.5 * k as f64));
+    }
+    for k in 0..3 {
+        apply_cnot(&mut psi, k, Truncation::exact());
+    }
+
+    let run = |threads: usize| {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+        let mut rng = ONDRng::new(b"sample-counts");
+        pool.install(|| sample_counts(&psi, 256, &mut rng))
+    };
+    let serial = run(1);
+    assert_eq!(serial, run(4));
+    assert_eq!(serial.values().sum::<usize>(), 256);
+
+    // Same as drawing sample s from fork(s) one after another.
+    let rng = ONDRng::new(b"sample-counts");
+    let mut manual = std::collections::BTreeMap::new();
+    for s in 0..256u64 {
+        let bits = sample_bitstring(&psi, &mut rng.fork(s));
+        *manual.entry(tn::mps::bits_to_index(&bits)).or_insert(0) += 1;
+    }
+    assert_eq!(serial, manual);
+}
+
+#[test]
+fn bell_counts_only_hit_correlated_outcomes() {
+    let mut rng = ONDRng::new(b"bell-counts");
+    let first = sample_counts(&bell(), 200, &mut rng);
+    assert!(first.keys().all(|&idx| idx == 0b00 || idx == 0b11), "{:?}", first);
+    assert!(first[&0b00] > 60 && first[&0b11] > 60, "{:?}", first);
+
+    // The caller's RNG moved on, so a second call is a fresh batch.
+    assert_ne!(first, sample_counts(&bell(), 200, &mut rng));
+}
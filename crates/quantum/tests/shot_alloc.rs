@@ -0,0 +1,82 @@
+//! Allocation check for `estimate_z_shots` at n = 40, 1000 shots; the timing of the
+//! same comparison is the `z_shots` group of the `hot_paths` bench.
+//!
+//! Lives in its own test binary so the counting allocator sees no other tests.
+
+use quantum::{
+    apply_cnot,
+    gates::{hadamard, rx},
+    measurement::measure_z,
+    shot_estimator::estimate_z_shots,
+};
+use rng::ONDRng;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tn::{mps::MPS, truncation::Truncation};
+
+struct Counting;
+
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        BYTES.fetch_add(new_size, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn measure_bytes<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = BYTES.load(Ordering::Relaxed);
+    let out = f();
+    (out, BYTES.load(Ordering::Relaxed) - before)
+}
+
+#[test]
+fn z_shots_do_not_clone_the_state() {
+    let n = 40;
+    let shots = 1000;
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-12).build();
+
+    let mut psi = MPS::new_zero(n);
+    for layer in 0..3 {
+        for k in 0..n {
+            psi.apply_1q(k, rx(0.2 + 0.37 * ((k + layer) % 7) as f64));
+        }
+        psi.apply_1q(0, hadamard());
+        let mut k = layer % 2;
+        while k + 1 < n {
+            apply_cnot(&mut psi, k, trunc);
+            k += 2;
+        }
+    }
+
+    let k = n / 2;
+    let (slow, cloned) = measure_bytes(|| {
+        let mut rng = ONDRng::new(b"alloc");
+        let mut sum = 0.0;
+        for _ in 0..shots {
+            let mut psi_copy = psi.clone();
+            sum += if measure_z(&mut psi_copy, k, &mut rng) == 0 { 1.0 } else { -1.0 };
+        }
+        sum / shots as f64
+    });
+
+    let (fast, direct) = measure_bytes(|| {
+        let mut rng = ONDRng::new(b"alloc");
+        estimate_z_shots(&psi, k, &mut rng, shots)
+    });
+    assert_eq!(fast, slow);
+    assert!(direct * 100 < cloned, "direct = {} B, cloned = {} B", direct, cloned);
+}
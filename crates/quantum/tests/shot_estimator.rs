@@ -0,0 +1,76 @@
+use quantum::{
+    apply_cnot,
+    gates::{hadamard, rx},
+    measurement::measure_z,
+    shot_estimator::{estimate_z_shots, estimate_zz_shots},
+};
+use rng::ONDRng;
+use tn::{mps::MPS, truncation::Truncation};
+
+fn entangled(n: usize) -> MPS {
+    let trunc = Truncation::builder().max_bond(16).cutoff(1e-12).build();
+    let mut psi = MPS::new_zero(n);
+    psi.apply_1q(0, hadamard());
+    for k in 0..n - 1 {
+        psi.apply_1q(k + 1, rx(0.4 + 0.3 * k as f64));
+        apply_cnot(&mut psi, k, trunc);
+    }
+    psi
+}
+
+fn clone_per_shot_z(psi: &MPS, k: usize, rng: &mut ONDRng, shots: usize) -> f64 {
+    let mut sum = 0.0;
+    for _ in 0..shots {
+        let mut psi_copy = psi.clone();
+        sum += if measure_z(&mut psi_copy, k, rng) == 0 { 1.0 } else { -1.0 };
+    }
+    sum / shots as f64
+}
+
+fn clone_per_shot_zz(psi: &MPS, i: usize, j: usize, rng: &mut ONDRng, shots: usize) -> f64 {
+    let mut sum = 0.0;
+    for _ in 0..shots {
+        let mut psi_copy = psi.clone();
+        let mi = measure_z(&mut psi_copy, i, rng);
+        let mj = measure_z(&mut psi_copy, j, rng);
+        sum += if mi == mj { 1.0 } else { -1.0 };
+    }
+    sum / shots as f64
+}
+
+#[test]
+fn z_estimator_matches_clone_per_shot() {
+    let psi = entangled(6);
+    for k in [0, 3, 5] {
+        let mut a = ONDRng::new(b"z-shots");
+        let mut b = ONDRng::new(b"z-shots");
+        let fast = estimate_z_shots(&psi, k, &mut a, 500);
+        let slow = clone_per_shot_z(&psi, k, &mut b, 500);
+        assert_eq!(fast, slow, "site {}", k);
+        // Both must leave the RNG in the same state.
+        assert_eq!(a.next_f64(b"CHECK"), b.next_f64(b"CHECK"));
+    }
+}
+
+#[test]
+fn zz_estimator_matches_clone_per_shot() {
+    let psi = entangled(6);
+    let mut a = ONDRng::new(b"zz-shots");
+    let mut b = ONDRng::new(b"zz-shots");
+    let fast = estimate_zz_shots(&psi, 1, 4, &mut a, 300);
+    let slow = clone_per_shot_zz(&psi, 1, 4, &mut b, 300);
+    assert_eq!(fast, slow);
+}
+
+#[test]
+fn zz_estimator_is_symmetric_in_sites() {
+    let psi = entangled(6);
+    for (i, j) in [(0, 1), (1, 4), (2, 5)] {
+        let mut a = ONDRng::new(b"zz-order");
+        let mut b = ONDRng::new(b"zz-order");
+        let forward = estimate_zz_shots(&psi, i, j, &mut a, 200);
+        let backward = estimate_zz_shots(&psi, j, i, &mut b, 200);
+        assert_eq!(forward, backward, "sites {} and {}", i, j);
+        assert_eq!(a.next_f64(b"CHECK"), b.next_f64(b"CHECK"));
+    }
+}
@@ -0,0 +1,30 @@
+mod common;
+
+use common::bell;
+use quantum::{measurement::measure_z, shot_estimator::record_shots};
+use rng::ONDRng;
+
+#[test]
+fn bell_histogram_is_pinned() {
+    let psi = bell();
+    let mut rng = ONDRng::new(b"hist-test");
+    let tableau = record_shots(&psi, &[0, 1], &mut rng, 64);
+
+    assert_eq!(tableau.histogram(), vec![36, 0, 0, 28]);
+    assert!(tableau.shots.iter().all(|s| s.draws.len() == 2));
+}
+
+#[test]
+fn tableau_consumes_rng_like_measure_z() {
+    let psi = bell();
+    let mut rng = ONDRng::new(b"hist-test");
+    let tableau = record_shots(&psi, &[0, 1], &mut rng, 16);
+
+    let mut rng = ONDRng::new(b"hist-test");
+    for shot in &tableau.shots {
+        let mut psi_copy = psi.clone();
+        let m0 = measure_z(&mut psi_copy, 0, &mut rng);
+        let m1 = measure_z(&mut psi_copy, 1, &mut rng);
+        assert_eq!(shot.outcomes, vec![m0, m1]);
+    }
+}
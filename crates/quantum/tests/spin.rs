@@ -0,0 +1,47 @@
+use quantum::{
+    apply_cnot,
+    energy::{energy_heisenberg, energy_spin_chain},
+    gates::hadamard,
+    hamiltonian::{Heisenberg, SpinChain},
+};
+use tn::{
+    mps::{C64, MPS, Tensor3},
+    truncation::Truncation,
+};
+
+/// Two-site spin-1 singlet (|+1,-1> - |0,0> + |-1,+1>) / sqrt(3).
+fn spin1_singlet() -> MPS {
+    let c = 1.0 / 3.0f64.sqrt();
+    let mut a = Tensor3::zeros(1, 3, 3);
+    let mut b = Tensor3::zeros(3, 3, 1);
+    for p in 0..3 {
+        a.set(0, p, p, C64::new(1.0, 0.0));
+    }
+    b.set(0, 2, 0, C64::new(c, 0.0));
+    b.set(1, 1, 0, C64::new(-c, 0.0));
+    b.set(2, 0, 0, C64::new(c, 0.0));
+    MPS::from_sites(vec![a, b])
+}
+
+#[test]
+fn spin1_singlet_energy() {
+    let psi = spin1_singlet();
+    let h = SpinChain::uniform(2, 2, 1.0);
+
+    // S1·S2 = [S_tot(S_tot+1) - 2 S(S+1)] / 2 = -2 for the spin-1 singlet.
+    let e = energy_spin_chain(&psi, &h);
+    assert!((e + 2.0).abs() < 1e-12, "E = {}", e);
+}
+
+#[test]
+fn spin_half_chain_matches_heisenberg() {
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-12).build();
+    let mut psi = MPS::new_zero(2);
+    psi.apply_1q(0, hadamard());
+    apply_cnot(&mut psi, 0, trunc);
+
+    // S = σ/2, so each coupling picks up a factor 1/4.
+    let e = energy_spin_chain(&psi, &SpinChain::uniform(2, 1, 1.0));
+    let expected = 0.25 * energy_heisenberg(&psi, &Heisenberg::uniform(2, 1.0));
+    assert!((e - expected).abs() < 1e-12, "E = {}, expected = {}", e, expected);
+}
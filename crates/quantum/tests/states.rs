@@ -0,0 +1,88 @@
+use quantum::{
+    gates::{cz, hadamard, Pauli},
+    observables::{expect_pauli_string, total_sz},
+    states::{cluster_state, from_particle_number, random_product_state},
+};
+use rng::ONDRng;
+use tn::{
+    mps::{fidelity, index_to_bits, overlap, MPS},
+    truncation::Truncation,
+};
+
+#[test]
+fn dicke_state_has_fixed_magnetization_and_unit_norm() {
+    for (n, k) in [(6, 0), (6, 2), (6, 3), (7, 5), (5, 5), (1, 1)] {
+        let psi = from_particle_number(n, k, Truncation::exact());
+        assert!((overlap(&psi, &psi).re - 1.0).abs() < 1e-12, "n={} k={}", n, k);
+        let expected = n as f64 - 2.0 * k as f64;
+        assert!((total_sz(&psi) - expected).abs() < 1e-10, "n={} k={}", n, k);
+    }
+}
+
+#[test]
+fn dicke_amplitudes_are_uniform_on_the_sector() {
+    let (n, k) = (5, 2);
+    let psi = from_particle_number(n, k, Truncation::exact());
+    let amps = psi.to_statevector();
+    let expected = 1.0 / 10.0_f64.sqrt();
+
+    for (idx, a) in amps.iter().enumerate() {
+        let ones = index_to_bits(idx, n).iter().filter(|&&b| b == 1).count();
+        if ones == k {
+            assert!((a.norm() - expected).abs() < 1e-12, "{}: {}", idx, a);
+        } else {
+            assert!(a.norm() < 1e-12, "{}: {}", idx, a);
+        }
+    }
+
+    let max_bond = psi.sites().iter().map(|s| s.dr).max().unwrap();
+    assert!(max_bond <= k + 1);
+}
+
+#[test]
+fn dicke_with_zero_particles_is_all_zeros() {
+    let psi = from_particle_number(4, 0, Truncation::exact());
+    assert!((overlap(&psi, &MPS::new_zero(4)).norm() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn brickwork_cz_layers_build_the_cluster_state() {
+    let n = 6;
+    let trunc = Truncation::exact();
+
+    let mut psi = MPS::new_zero(n);
+    psi.apply_1q_all(hadamard());
+    psi.apply_2q_layer(cz(), 0, trunc);
+    psi.apply_2q_layer(cz(), 1, trunc);
+
+    let cluster = cluster_state(n, trunc);
+    assert!((fidelity(&psi, &cluster) - 1.0).abs() < 1e-12);
+    assert!(psi.sites().iter().all(|s| s.dr <= 2));
+
+    for k in 0..n {
+        let mut ops = vec![(k, Pauli::X)];
+        if k > 0 {
+            ops.push((k - 1, Pauli::Z));
+        }
+        if k + 1 < n {
+            ops.push((k + 1, Pauli::Z));
+        }
+        let s = expect_pauli_string(&psi, &ops);
+        assert!((s - 1.0).abs() < 1e-12, "stabilizer at {} = {}", k, s);
+    }
+}
+
+#[test]
+fn random_product_state_is_normalized_with_unit_bonds() {
+    let mut rng = ONDRng::new(b"random-product");
+    let n = 8;
+    let psi = random_product_state(n, &mut rng);
+    assert!((overlap(&psi, &psi).re - 1.0).abs() < 1e-12);
+    assert!(psi.sites().iter().all(|s| s.dl == 1 && s.dr == 1));
+
+    // Same seed, same state; the next draw is a different one.
+    let again = random_product_state(n, &mut ONDRng::new(b"random-product"));
+    assert!((fidelity(&psi, &again) - 1.0).abs() < 1e-12);
+    let other = random_product_state(n, &mut rng);
+    assert!(fidelity(&psi, &other) < 0.99);
+}
@@ -1,5 +1,6 @@
 use sha3::{digest::{ExtendableOutput, Update, XofReader}, Shake256};
 
+#[derive(Clone)]
 pub struct ONDRng {
     state: [u8; 32],
     step: u64,
@@ -33,6 +34,78 @@ impl ONDRng {
 
         (u64::from_be_bytes(out) as f64) / (u64::MAX as f64)
     }
+
+    /// Independent child stream keyed by `index`, derived from the current state.
+    ///
+    /// Does not advance `self`, so forking children 0..n from the same parent state gives
+    /// the same streams no matter which order (or which thread) they are created in.
+    pub fn fork(&self, index: u64) -> ONDRng {
+        let mut state = [0u8; 32];
+        shake(
+            &[&self.state, &self.step.to_be_bytes(), b"FORK", &index.to_be_bytes()],
+            &mut state,
+        );
+        Self { state, step: 0 }
+    }
+
+    /// Snapshot of the generator: the 32-byte state followed by the big-endian step count.
+    pub fn save_state(&self) -> [u8; 40] {
+        let mut out = [0u8; 40];
+        out[..32].copy_from_slice(&self.state);
+        out[32..].copy_from_slice(&self.step.to_be_bytes());
+        out
+    }
+
+    /// Resume from a `save_state` snapshot; the next draws match the original stream.
+    pub fn restore_state(bytes: &[u8; 40]) -> Self {
+        let mut state = [0u8; 32];
+        state.copy_from_slice(&bytes[..32]);
+        let mut step = [0u8; 8];
+        step.copy_from_slice(&bytes[32..]);
+        Self {
+            state,
+            step: u64::from_be_bytes(step),
+        }
+    }
+}
+
+/// Domain-separated seed labels for derived RNG streams.
+///
+/// Every label starts with a fixed scheme prefix, the algorithm name and a
+/// run salt, and all components are length-prefixed, so labels from different
+/// runs or algorithms cannot collide by string concatenation.
+#[derive(Clone)]
+pub struct SeedContext {
+    base: Vec<u8>,
+}
+
+impl SeedContext {
+    pub fn new(algorithm: &str, run_salt: &[u8]) -> Self {
+        let mut base = Vec::new();
+        push_field(&mut base, b"OND-SEED-v1");
+        push_field(&mut base, algorithm.as_bytes());
+        push_field(&mut base, run_salt);
+        Self { base }
+    }
+
+    /// Label for a substream identified by named integer indices, e.g. `[("step", 3), ("traj", 7)]`.
+    pub fn label(&self, indices: &[(&str, u64)]) -> Vec<u8> {
+        let mut out = self.base.clone();
+        for (name, value) in indices {
+            push_field(&mut out, name.as_bytes());
+            out.extend_from_slice(&value.to_be_bytes());
+        }
+        out
+    }
+
+    pub fn rng(&self, indices: &[(&str, u64)]) -> ONDRng {
+        ONDRng::new(&self.label(indices))
+    }
+}
+
+fn push_field(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(&(bytes.len() as u64).to_be_bytes());
+    out.extend_from_slice(bytes);
 }
 
 fn shake(parts: &[&[u8]], out: &mut [u8]) {
@@ -0,0 +1,21 @@
+use rng::ONDRng;
+
+#[test]
+fn fork_is_keyed_and_leaves_parent_untouched() {
+    let mut parent = ONDRng::new(b"fork");
+    parent.next_f64(b"warm-up");
+
+    let mut a = parent.fork(3);
+    let mut b = parent.fork(3);
+    let mut c = parent.fork(4);
+    let first = a.next_f64(b"X");
+    assert_eq!(first, b.next_f64(b"X"));
+    assert_ne!(first, c.next_f64(b"X"));
+
+    let mut twin = ONDRng::new(b"fork");
+    twin.next_f64(b"warm-up");
+    assert_eq!(parent.next_f64(b"Y"), twin.next_f64(b"Y"));
+
+    // A fork taken after the parent moved on is a different stream.
+    assert_ne!(parent.fork(3).next_f64(b"X"), first);
+}
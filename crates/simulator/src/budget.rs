@@ -0,0 +1,110 @@
+use quantum::circuit::{Circuit, Gate};
+use tn::{
+    mps::{C64, MPS},
+    truncation::Truncation,
+};
+
+/// Fidelity-loss budget for a whole circuit, shared out across its two-site gates.
+///
+/// Each gate may discard an equal share of what is left of the budget, so weight one
+/// gate does not use rolls over to the gates after it. Gates are applied with the
+/// orthogonality center on the bond, where the discarded weight is exactly the
+/// fidelity lost by that truncation; the state is renormalized after every gate.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CircuitBudget {
+    /// Hard cap on the bond dimension, on top of the budget.
+    pub max_bond: usize,
+    total: f64,
+    fidelity: f64,
+    gates_left: usize,
+}
+
+impl CircuitBudget {
+    /// Budget of `total` fidelity loss spread over `gates` two-site gates.
+    pub fn new(total: f64, gates: usize) -> Self {
+        assert!(
+            (0.0..1.0).contains(&total),
+            "CircuitBudget: total {} not in [0, 1)",
+            total
+        );
+        Self {
+            max_bond: usize::MAX,
+            total,
+            fidelity: 1.0,
+            gates_left: gates,
+        }
+    }
+
+    /// Budget sized for the two-site gates of `circuit`.
+    pub fn for_circuit(total: f64, circuit: &Circuit) -> Self {
+        let gates = circuit
+            .gates
+            .iter()
+            .filter(|g| matches!(g, Gate::Two { .. }))
+            .count();
+        Self::new(total, gates)
+    }
+
+    pub fn with_max_bond(mut self, max_bond: usize) -> Self {
+        self.max_bond = max_bond;
+        self
+    }
+
+    /// Fidelity lost so far, 1 - Π(1 - w) over the discarded weights w.
+    pub fn spent(&self) -> f64 {
+        1.0 - self.fidelity
+    }
+
+    /// Loss the remaining gates may still incur; negative once `max_bond` forced an overrun.
+    pub fn remaining(&self) -> f64 {
+        1.0 - (1.0 - self.total) / self.fidelity
+    }
+
+    pub fn gates_left(&self) -> usize {
+        self.gates_left
+    }
+
+    /// Truncation for the next gate on (k, k+1): its share of the remaining budget,
+    /// split evenly over the singular values the two-site split can produce.
+    pub fn next_truncation(&self, psi: &MPS, k: usize) -> Truncation {
+        let share = self.remaining().max(0.0) / self.gates_left.max(1) as f64;
+        let rank = 2 * psi.sites()[k].dl.min(psi.sites()[k + 1].dr);
+        Truncation::builder()
+            .max_bond(self.max_bond)
+            .cutoff((share / rank as f64).sqrt())
+            .build()
+    }
+
+    /// Apply `u` on (k, k+1) within the budget; returns true when `max_bond` saturated.
+    ///
+    /// `psi` must be normalized.
+    pub fn apply_2q(&mut self, psi: &mut MPS, k: usize, u: [[C64; 4]; 4]) -> bool {
+        psi.assert_bond(k, "CircuitBudget::apply_2q");
+        psi.canonicalize_at(k);
+        let trunc = self.next_truncation(psi, k);
+        let saturated = psi.apply_2q_svd(k, u, trunc);
+
+        // The center is now on site k, so its weight is what the truncation kept.
+        let kept: f64 = psi.sites()[k].data.iter().map(|v| v.norm_sqr()).sum();
+        let scale = 1.0 / kept.sqrt();
+        for v in psi.site_mut(k).data.iter_mut() {
+            *v *= scale;
+        }
+
+        self.fidelity *= kept.min(1.0);
+        self.gates_left = self.gates_left.saturating_sub(1);
+        saturated
+    }
+
+    /// Run `circuit` on `psi` within the budget; returns true when any gate saturated `max_bond`.
+    pub fn run(&mut self, circuit: &Circuit, psi: &mut MPS) -> bool {
+        let mut saturated = false;
+        for gate in &circuit.gates {
+            match *gate {
+                Gate::One { k, u } => psi.apply_1q(k, u),
+                Gate::Two { k, u } => saturated |= self.apply_2q(psi, k, u),
+            }
+        }
+        saturated
+    }
+}
@@ -0,0 +1,49 @@
+use quantum::{energy::energy, hamiltonian::Hamiltonian};
+use tn::mps::{fidelity, MPS};
+
+/// `(cutoff, 1 - fidelity)` against `reference` for each cutoff, in the given order.
+///
+/// `build_state` runs the same circuit with the SVD cutoff under test; the reference
+/// should be built with a cutoff well below the smallest one swept.
+pub fn calibrate_cutoff(
+    build_state: impl Fn(f64) -> MPS,
+    reference: &MPS,
+    cutoffs: &[f64],
+) -> Vec<(f64, f64)> {
+    cutoffs
+        .iter()
+        .map(|&cutoff| {
+            let psi = build_state(cutoff);
+            (cutoff, (1.0 - fidelity(&psi, reference)).max(0.0))
+        })
+        .collect()
+}
+
+/// Smallest bond dimension whose energy is converged to within `tol`.
+///
+/// `build` runs the circuit with `max_bond` set to the given value. Candidates are tried in
+/// increasing order and the first one whose energy differs from the next candidate's by
+/// less than `tol` is returned; if none does, the largest candidate is.
+pub fn min_chi_for_energy_tol(
+    build: impl Fn(usize) -> MPS,
+    h: &Hamiltonian,
+    tol: f64,
+    chi_candidates: &[usize],
+) -> usize {
+    let mut chis = chi_candidates.to_vec();
+    chis.sort_unstable();
+    chis.dedup();
+    let (&first, rest) = chis
+        .split_first()
+        .expect("min_chi_for_energy_tol: no bond dimension candidates");
+
+    let (mut chi, mut e) = (first, energy(&build(first), h));
+    for &next in rest {
+        let e_next = energy(&build(next), h);
+        if (e_next - e).abs() < tol {
+            return chi;
+        }
+        (chi, e) = (next, e_next);
+    }
+    chi
+}
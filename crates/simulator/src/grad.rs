@@ -1,3 +1,5 @@
+use tn::mps::MPS;
+
 pub fn parameter_shift<F>(theta: f64, energy_fn: &F) -> f64
 where
     F: Fn(f64) -> f64,
@@ -5,3 +7,66 @@ where
     let shift = std::f64::consts::FRAC_PI_2;
     0.5 * (energy_fn(theta + shift) - energy_fn(theta - shift))
 }
+
+/// Parameter-shift gradient of a multi-parameter objective, one shift pair per component.
+pub fn parameter_shift_grad<F>(theta: &[f64], energy_fn: &F) -> Vec<f64>
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let shift = std::f64::consts::FRAC_PI_2;
+    let mut shifted = theta.to_vec();
+    let mut grad = Vec::with_capacity(theta.len());
+    for i in 0..theta.len() {
+        shifted[i] = theta[i] + shift;
+        let plus = energy_fn(&shifted);
+        shifted[i] = theta[i] - shift;
+        let minus = energy_fn(&shifted);
+        shifted[i] = theta[i];
+        grad.push(0.5 * (plus - minus));
+    }
+    grad
+}
+
+/// (energy, gradient) for an ansatz built as `init` followed by one parameterized block
+/// per component, `step(psi, i, theta[i])` for i = 0, 1, ...
+///
+/// Shifting parameter i leaves blocks 0..i untouched, so the prefix states are built
+/// once and every shifted evaluation resumes from the cached state before block i; the
+/// unshifted state doubles as the energy evaluation. Matches `energy(build(theta))` and
+/// `parameter_shift_grad` on the same ansatz.
+pub fn value_and_grad_layered<S, E>(
+    theta: &[f64],
+    init: &MPS,
+    step: S,
+    energy: E,
+) -> (f64, Vec<f64>)
+where
+    S: Fn(&mut MPS, usize, f64),
+    E: Fn(&MPS) -> f64,
+{
+    let shift = std::f64::consts::FRAC_PI_2;
+
+    // prefix[i] is the state before block i; prefix[n] is the full ansatz state.
+    let mut prefix = Vec::with_capacity(theta.len() + 1);
+    prefix.push(init.clone());
+    for (i, &t) in theta.iter().enumerate() {
+        let mut psi = prefix[i].clone();
+        step(&mut psi, i, t);
+        prefix.push(psi);
+    }
+    let value = energy(&prefix[theta.len()]);
+
+    let shifted_energy = |i: usize, t: f64| {
+        let mut psi = prefix[i].clone();
+        step(&mut psi, i, t);
+        for (j, &tj) in theta.iter().enumerate().skip(i + 1) {
+            step(&mut psi, j, tj);
+        }
+        energy(&psi)
+    };
+    let grad = (0..theta.len())
+        .map(|i| 0.5 * (shifted_energy(i, theta[i] + shift) - shifted_energy(i, theta[i] - shift)))
+        .collect();
+
+    (value, grad)
+}
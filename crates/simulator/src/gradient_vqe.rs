@@ -1,4 +1,8 @@
-use crate::grad::parameter_shift;
+use crate::grad::{parameter_shift, parameter_shift_grad, value_and_grad_layered};
+use quantum::energy::{energy_heisenberg, energy_pauli_sum};
+use quantum::hamiltonian::{Heisenberg, PauliSum};
+use rng::ONDRng;
+use tn::mps::{overlap, MPS};
 
 pub fn vqe_gradient<F>(mut theta: f64, energy_fn: F, lr: f64, steps: usize) -> (f64, f64)
 where
@@ -12,3 +16,209 @@ where
     let e = energy_fn(theta);
     (theta, e)
 }
+
+/// Step-size schedule for gradient descent; `lr` in `VqeConfig` is the initial rate.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum LrSchedule {
+    #[default]
+    Constant,
+    /// lr / (1 + rate · step)
+    Decay { rate: f64 },
+    /// Cosine annealing from lr to 0 over `steps`, then held at 0.
+    Cosine { steps: usize },
+}
+
+impl LrSchedule {
+    pub fn lr(&self, lr0: f64, step: usize) -> f64 {
+        match *self {
+            LrSchedule::Constant => lr0,
+            LrSchedule::Decay { rate } => lr0 / (1.0 + rate * step as f64),
+            LrSchedule::Cosine { steps } => {
+                if steps == 0 {
+                    return lr0;
+                }
+                let frac = step.min(steps) as f64 / steps as f64;
+                0.5 * lr0 * (1.0 + (std::f64::consts::PI * frac).cos())
+            }
+        }
+    }
+}
+
+/// Settings for the multi-parameter gradient-descent optimizers.
+#[derive(Clone, Debug)]
+pub struct VqeConfig {
+    pub theta0: Vec<f64>,
+    pub lr: f64,
+    pub steps: usize,
+    pub schedule: LrSchedule,
+    /// Reduce every parameter modulo 2π after each step (the energy is 2π-periodic).
+    pub wrap_parameters: bool,
+}
+
+/// Reduce an angle into [0, 2π).
+fn wrap_angle(t: f64) -> f64 {
+    let two_pi = 2.0 * std::f64::consts::PI;
+    // rem_euclid can round up to exactly 2π for tiny negative inputs.
+    let w = t.rem_euclid(two_pi);
+    if w >= two_pi {
+        0.0
+    } else {
+        w
+    }
+}
+
+/// Parameter-shift gradient descent on a multi-parameter objective.
+///
+/// Returns the final parameters and the objective evaluated there.
+pub fn vqe_gradient_params<F>(energy_fn: F, cfg: &VqeConfig) -> (Vec<f64>, f64)
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let mut theta = cfg.theta0.clone();
+    for step in 0..cfg.steps {
+        let grad = parameter_shift_grad(&theta, &energy_fn);
+        descend(&mut theta, &grad, cfg, step);
+    }
+
+    let e = energy_fn(&theta);
+    (theta, e)
+}
+
+/// `vqe_gradient_params` for an ansatz given as `init` followed by one block per
+/// parameter, `step(psi, i, theta[i])`.
+///
+/// Gradients come from `value_and_grad_layered`, so the shifted evaluations of a step
+/// resume from cached prefix states instead of rebuilding the ansatz from `init` for
+/// each of them. Same iterates as `vqe_gradient_params` on the assembled ansatz.
+pub fn vqe_gradient_params_layered<S, E>(
+    init: &MPS,
+    step: S,
+    energy: E,
+    cfg: &VqeConfig,
+) -> (Vec<f64>, f64)
+where
+    S: Fn(&mut MPS, usize, f64),
+    E: Fn(&MPS) -> f64,
+{
+    let mut theta = cfg.theta0.clone();
+    for k in 0..cfg.steps {
+        let (_, grad) = value_and_grad_layered(&theta, init, &step, &energy);
+        descend(&mut theta, &grad, cfg, k);
+    }
+
+    let mut psi = init.clone();
+    for (i, &t) in theta.iter().enumerate() {
+        step(&mut psi, i, t);
+    }
+    let e = energy(&psi);
+    (theta, e)
+}
+
+/// One gradient-descent update at the scheduled rate for `step`.
+fn descend(theta: &mut [f64], grad: &[f64], cfg: &VqeConfig, step: usize) {
+    let lr = cfg.schedule.lr(cfg.lr, step);
+    for (t, g) in theta.iter_mut().zip(grad.iter()) {
+        *t -= lr * g;
+        if cfg.wrap_parameters {
+            *t = wrap_angle(*t);
+        }
+    }
+}
+
+/// First excited state by minimizing ⟨H⟩ + penalty·|⟨ground|ψ⟩|² with parameter-shift descent.
+///
+/// Returns the optimized parameters and the unpenalized energy ⟨H⟩; subtracting the ground
+/// energy gives the gap. The penalty must exceed the gap for the minimum to be the excited state.
+/// The shift rule is exact for ansätze built from Pauli rotations, penalty term included.
+pub fn vqe_excited<A>(
+    ground: &MPS,
+    ansatz: A,
+    h: &Heisenberg,
+    penalty: f64,
+    cfg: &VqeConfig,
+) -> (Vec<f64>, f64)
+where
+    A: Fn(&[f64]) -> MPS,
+{
+    let ground_norm = overlap(ground, ground).re;
+    let objective = |theta: &[f64]| {
+        let psi = ansatz(theta);
+        let ov = overlap(ground, &psi).norm_sqr() / (ground_norm * overlap(&psi, &psi).re);
+        energy_heisenberg(&psi, h) + penalty * ov
+    };
+
+    let (theta, _) = vqe_gradient_params(objective, cfg);
+    let e = energy_heisenberg(&ansatz(&theta), h);
+    (theta, e)
+}
+
+/// Importance-sampled mini-batch of `batch` terms drawn with probability ∝ |coeff|.
+///
+/// Each draw enters as sign(c)·Λ/batch·P with Λ = Σ|c|, so the sampled sum is an
+/// unbiased estimator of `h`; the variance shrinks as 1/batch.
+pub fn sample_minibatch(h: &PauliSum, batch: usize, rng: &mut ONDRng) -> PauliSum {
+    let lambda: f64 = h.terms.iter().map(|t| t.coeff.abs()).sum();
+    let mut out = PauliSum::new(h.n);
+    if lambda == 0.0 || batch == 0 {
+        return out;
+    }
+
+    for _ in 0..batch {
+        let mut x = rng.next_f64(b"MINIBATCH") * lambda;
+        let mut pick = h.terms.len() - 1;
+        for (i, t) in h.terms.iter().enumerate() {
+            if x < t.coeff.abs() {
+                pick = i;
+                break;
+            }
+            x -= t.coeff.abs();
+        }
+        let t = &h.terms[pick];
+        out.add(t.coeff.signum() * lambda / batch as f64, &t.ops);
+    }
+    out
+}
+
+/// Parameter-shift gradient of ⟨H⟩ evaluated on a freshly sampled mini-batch of terms.
+pub fn minibatch_gradient<A>(
+    ansatz: &A,
+    h: &PauliSum,
+    theta: &[f64],
+    batch: usize,
+    rng: &mut ONDRng,
+) -> Vec<f64>
+where
+    A: Fn(&[f64]) -> MPS,
+{
+    let sampled = sample_minibatch(h, batch, rng);
+    parameter_shift_grad(theta, &|t: &[f64]| energy_pauli_sum(&ansatz(t), &sampled))
+}
+
+/// Stochastic gradient descent on ⟨H⟩ using `batch` sampled terms per step.
+///
+/// Returns the final parameters and the full-batch energy there.
+pub fn vqe_minibatch<A>(
+    ansatz: A,
+    h: &PauliSum,
+    batch: usize,
+    rng: &mut ONDRng,
+    cfg: &VqeConfig,
+) -> (Vec<f64>, f64)
+where
+    A: Fn(&[f64]) -> MPS,
+{
+    let mut theta = cfg.theta0.clone();
+    for step in 0..cfg.steps {
+        let lr = cfg.schedule.lr(cfg.lr, step);
+        let grad = minibatch_gradient(&ansatz, h, &theta, batch, rng);
+        for (t, g) in theta.iter_mut().zip(grad.iter()) {
+            *t -= lr * g;
+            if cfg.wrap_parameters {
+                *t = wrap_angle(*t);
+            }
+        }
+    }
+
+    let e = energy_pauli_sum(&ansatz(&theta), h);
+    (theta, e)
+}
@@ -1,30 +1,34 @@
-use quantum::gates::hadamard;
+use quantum::circuit::{haar_brickwork, BENCH_SEED};
 use std::time::Instant;
-use tn::mps::{C64, MPS};
+use tn::mps::MPS;
 use tn::truncation::Truncation;
 
+pub mod budget;
+pub mod calibrate;
 pub mod grad;
 pub mod gradient_vqe;
-mod output;
+pub mod meta;
+pub mod output;
+pub mod scaling;
+pub mod trotter;
 pub mod vqe;
-pub use vqe::{noisy_vqe_sweep, vqe_sweep, vqe_sweep_shots, vqe_sweep_steps};
+pub use vqe::{
+    best_point, bootstrap_ci, noisy_vqe_energies, noisy_vqe_energies_flat, noisy_vqe_sweep,
+    noisy_vqe_sweep_flat, vqe_sweep, vqe_sweep_grid, vqe_sweep_shots, vqe_sweep_steps, ThetaGrid,
+};
 
+/// Time `n` sites of the shared Haar brickwork at `depth` layers with default truncation.
+///
+/// `depth` counts brickwork layers of generic two-qubit gates, not the single gate
+/// steps of the old fixed sequence, so the cost grows quickly once the bond saturates:
+/// n = 40 takes about half a second at depth 8 and tens of seconds at depth 80.
 pub fn benchmark(n: usize, depth: usize) {
-    let trunc = Truncation {
-        max_bond: 64,
-        cutoff: 1e-8,
-    };
+    let trunc = Truncation::default();
+    let circuit = haar_brickwork(n, depth, BENCH_SEED);
     let mut psi = MPS::new_zero(n);
 
-    let ident = [[C64::new(1.0, 0.0); 4]; 4];
-
     let start = Instant::now();
-    for t in 0..depth {
-        psi.apply_1q(t % n, hadamard());
-        if t + 1 < n {
-            psi.apply_2q_svd(t % (n - 1), ident, trunc);
-        }
-    }
+    circuit.apply(&mut psi, trunc);
 
     println!(
         "Benchmark: n={}, depth={} → {:.3} s",
@@ -0,0 +1,18 @@
+use serde::Serialize;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+
+/// Sidecar path for a result file: `<out>.meta.json`.
+pub fn meta_path(out: &str) -> String {
+    format!("{}.meta.json", out)
+}
+
+/// Write all run parameters next to `out` as pretty JSON; returns the sidecar path.
+pub fn write_meta<T: Serialize>(out: &str, args: &T) -> io::Result<String> {
+    let path = meta_path(out);
+    let mut w = BufWriter::new(File::create(&path)?);
+    serde_json::to_writer_pretty(&mut w, args)?;
+    writeln!(w)?;
+    w.flush()?;
+    Ok(path)
+}
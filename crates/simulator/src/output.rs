@@ -1,7 +1,7 @@
-use std::fs::File;
-use std::io::{self, Write};
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
 
-pub fn write_csv(path: &str, rows: &[(f64, f64)]) -> io::Result<()> {
+pub(crate) fn write_csv(path: &str, rows: &[(f64, f64)]) -> io::Result<()> {
     let mut f = File::create(path)?;
     writeln!(f, "theta,energy")?;
     for (theta, energy) in rows {
@@ -9,3 +9,137 @@ pub fn write_csv(path: &str, rows: &[(f64, f64)]) -> io::Result<()> {
     }
     Ok(())
 }
+
+/// `write_csv` with the (low, high) bounds of a confidence interval on each energy.
+pub(crate) fn write_csv_ci(path: &str, rows: &[(f64, f64)], ci: &[(f64, f64)]) -> io::Result<()> {
+    let mut f = File::create(path)?;
+    writeln!(f, "theta,energy,ci_low,ci_high")?;
+    for ((theta, energy), (lo, hi)) in rows.iter().zip(ci) {
+        writeln!(f, "{},{},{},{}", theta, energy, lo, hi)?;
+    }
+    Ok(())
+}
+
+/// `<out>.gz`, the file `CsvAppender::create_gz` writes for a CSV path `out`.
+pub fn gz_path(out: &str) -> String {
+    format!("{}.gz", out)
+}
+
+/// Rows between flushes of a gzip stream; each flush ends a deflate block, so
+/// flushing every row would cost most of the compression.
+#[cfg(feature = "flate2")]
+const GZ_FLUSH_ROWS: usize = 256;
+
+enum Sink {
+    Plain(BufWriter<File>),
+    #[cfg(feature = "flate2")]
+    Gz(flate2::write::GzEncoder<BufWriter<File>>),
+}
+
+/// CSV writer that flushes every row, so a crash or Ctrl-C leaves only whole rows
+/// behind and the file can be followed with `tail -f`. Gzip output is flushed every
+/// `GZ_FLUSH_ROWS` rows instead.
+pub struct CsvAppender {
+    w: Sink,
+    rows: usize,
+}
+
+impl CsvAppender {
+    /// Start a fresh file at `path` with `header`, replacing any existing one.
+    pub fn create(path: &str, header: &str) -> io::Result<Self> {
+        Self::start(Sink::Plain(BufWriter::new(File::create(path)?)), header)
+    }
+
+    /// Like `create`, but gzip-compressed. Call `finish` to write the gzip trailer
+    /// and see any error; dropping the appender finishes the stream silently.
+    ///
+    /// Needs the `flate2` feature (on by default); without it this returns `Unsupported`.
+    pub fn create_gz(path: &str, header: &str) -> io::Result<Self> {
+        #[cfg(feature = "flate2")]
+        {
+            use flate2::{write::GzEncoder, Compression};
+            let file = BufWriter::new(File::create(path)?);
+            let gz = GzEncoder::new(file, Compression::default());
+            Self::start(Sink::Gz(gz), header)
+        }
+        #[cfg(not(feature = "flate2"))]
+        {
+            let _ = header;
+            Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("{}: gzip output needs the `flate2` feature", path),
+            ))
+        }
+    }
+
+    fn start(w: Sink, header: &str) -> io::Result<Self> {
+        let mut out = Self { w, rows: 0 };
+        out.write_row(header)?;
+        Ok(out)
+    }
+
+    /// Continue an existing file whose first line is `header`; a missing or empty
+    /// file is started fresh. A different header is an `InvalidData` error.
+    pub fn append(path: &str, header: &str) -> io::Result<Self> {
+        let existing = match File::open(path) {
+            Ok(f) => {
+                let mut first = String::new();
+                BufReader::new(f).read_line(&mut first)?;
+                Some(first)
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e),
+        };
+
+        match existing {
+            Some(first) if !first.is_empty() => {
+                if first.trim_end_matches(['\r', '\n']) != header {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "{}: header {:?} does not match {:?}",
+                            path,
+                            first.trim_end(),
+                            header
+                        ),
+                    ));
+                }
+                let f = OpenOptions::new().append(true).open(path)?;
+                Ok(Self {
+                    w: Sink::Plain(BufWriter::new(f)),
+                    rows: 0,
+                })
+            }
+            _ => Self::create(path, header),
+        }
+    }
+
+    /// Write one line (without the trailing newline); plain files are flushed to
+    /// disk at once.
+    pub fn write_row(&mut self, row: &str) -> io::Result<()> {
+        self.rows += 1;
+        match &mut self.w {
+            Sink::Plain(w) => {
+                writeln!(w, "{}", row)?;
+                w.flush()
+            }
+            #[cfg(feature = "flate2")]
+            Sink::Gz(w) => {
+                writeln!(w, "{}", row)?;
+                if self.rows.is_multiple_of(GZ_FLUSH_ROWS) {
+                    w.flush()?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Flush the remaining rows and, for gzip output, write the trailer.
+    pub fn finish(self) -> io::Result<()> {
+        match self.w {
+            Sink::Plain(mut w) => w.flush(),
+            #[cfg(feature = "flate2")]
+            Sink::Gz(w) => w.finish()?.flush(),
+        }
+    }
+}
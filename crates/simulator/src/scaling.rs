@@ -0,0 +1,98 @@
+use crate::vqe::noisy_vqe_energies;
+use quantum::{circuit::haar_brickwork, observables::expect_z};
+use rayon::prelude::*;
+use std::fs::File;
+use std::io::{self, Write};
+use std::time::Instant;
+use tn::{mps::MPS, truncation::Truncation};
+
+/// Timing of one noisy-VQE run on a dedicated Rayon pool.
+#[derive(Clone, Debug)]
+pub struct ScalingRow {
+    pub threads: usize,
+    pub seconds: f64,
+    /// Wall-clock of the first requested thread count divided by this one.
+    pub speedup: f64,
+    /// Result per work item (mean energy per theta step, or ⟨Z⟩ per circuit), for
+    /// checking results do not depend on `threads`.
+    pub energies: Vec<f64>,
+}
+
+/// Run the noisy VQE sweep once per entry of `thread_counts`, each on its own scoped pool.
+///
+/// The global pool is left untouched, so all counts run in one process.
+pub fn thread_scaling(
+    thread_counts: &[usize],
+    steps: usize,
+    trajectories: usize,
+    shots: usize,
+    p: f64,
+    seed: &str,
+) -> Vec<ScalingRow> {
+    time_on_pools(thread_counts, || {
+        noisy_vqe_energies(steps, trajectories, shots, p, seed, None)
+            .into_iter()
+            .map(|(_, e)| e)
+            .collect()
+    })
+}
+
+/// `thread_scaling` over the shared Haar brickwork: `circuits` independent instances of
+/// `haar_brickwork(n, depth, "<seed>/<i>")` run in parallel under `trunc`.
+///
+/// Each row records ⟨Z⟩ on the middle site of every circuit.
+pub fn circuit_scaling(
+    thread_counts: &[usize],
+    n: usize,
+    depth: usize,
+    circuits: usize,
+    trunc: Truncation,
+    seed: &str,
+) -> Vec<ScalingRow> {
+    time_on_pools(thread_counts, || {
+        (0..circuits)
+            .into_par_iter()
+            .map(|i| {
+                let mut psi = MPS::new_zero(n);
+                haar_brickwork(n, depth, &format!("{}/{}", seed, i)).apply(&mut psi, trunc);
+                expect_z(&psi, n / 2)
+            })
+            .collect()
+    })
+}
+
+/// Time `work` once per thread count on a dedicated pool.
+fn time_on_pools(thread_counts: &[usize], work: impl Fn() -> Vec<f64> + Sync) -> Vec<ScalingRow> {
+    let mut rows: Vec<ScalingRow> = Vec::with_capacity(thread_counts.len());
+
+    for &threads in thread_counts {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .expect("Failed to build Rayon thread pool");
+
+        let start = Instant::now();
+        let energies = pool.install(&work);
+        let seconds = start.elapsed().as_secs_f64();
+
+        let baseline = rows.first().map_or(seconds, |r| r.seconds);
+        rows.push(ScalingRow {
+            threads,
+            seconds,
+            speedup: if seconds > 0.0 { baseline / seconds } else { 1.0 },
+            energies,
+        });
+    }
+
+    rows
+}
+
+pub fn write_scaling_csv(path: &str, rows: &[ScalingRow]) -> io::Result<()> {
+    let mut f = File::create(path)?;
+    writeln!(f, "threads,seconds,speedup,min_energy")?;
+    for r in rows {
+        let min_energy = r.energies.iter().cloned().fold(f64::INFINITY, f64::min);
+        writeln!(f, "{},{},{},{}", r.threads, r.seconds, r.speedup, min_energy)?;
+    }
+    Ok(())
+}
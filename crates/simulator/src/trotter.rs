@@ -0,0 +1,66 @@
+use quantum::hamiltonian::Heisenberg;
+use quantum::trotter::{
+    trotter_layer_heisenberg, trotter_layer_heisenberg_in, trotter_step_heisenberg,
+};
+use tn::mps::{fidelity, MPS};
+use tn::truncation::Truncation;
+
+/// Symmetric (Strang) step: half even layer, full odd layer, half even layer.
+pub fn trotter_step2_heisenberg(psi: &mut MPS, h: &Heisenberg, dt: f64, trunc: Truncation) {
+    trotter_layer_heisenberg(psi, h, 0, 0.5 * dt, trunc);
+    trotter_layer_heisenberg(psi, h, 1, dt, trunc);
+    trotter_layer_heisenberg(psi, h, 0, 0.5 * dt, trunc);
+}
+
+/// First-order step that only touches bonds inside [center - max_range, center + max_range].
+///
+/// For a perturbation localized at `center` on a background that every bond gate leaves
+/// invariant (up to a phase), each step widens the causal cone by two sites, so
+/// `max_range = 2 * steps` reproduces full evolution up to a global phase.
+pub fn trotter_step_light_cone(
+    psi: &mut MPS,
+    h: &Heisenberg,
+    dt: f64,
+    trunc: Truncation,
+    center: usize,
+    max_range: usize,
+) {
+    let lo = center.saturating_sub(max_range);
+    let hi = center.saturating_add(max_range);
+    trotter_layer_heisenberg_in(psi, h, 0, lo..hi, dt, trunc);
+    trotter_layer_heisenberg_in(psi, h, 1, lo..hi, dt, trunc);
+}
+
+/// 1 - F between one first-order and one second-order step from `psi`.
+///
+/// The first-order local error dominates, so this tracks the O(dt²) splitting error
+/// and is a cheap guide for choosing `dt`.
+pub fn trotter_error_estimate(psi: &MPS, h: &Heisenberg, dt: f64, trunc: Truncation) -> f64 {
+    let mut first = psi.clone();
+    trotter_step_heisenberg(&mut first, h, dt, trunc);
+
+    let mut second = psi.clone();
+    trotter_step2_heisenberg(&mut second, h, dt, trunc);
+
+    (1.0 - fidelity(&first, &second)).max(0.0)
+}
+
+/// Evolve `psi` for `steps` first-order Trotter steps, recording `observe` as `(time, value)`.
+///
+/// The first entry is the initial state at t = 0, so the result has `steps + 1` points.
+pub fn evolve_and_record(
+    psi: &mut MPS,
+    h: &Heisenberg,
+    dt: f64,
+    steps: usize,
+    trunc: Truncation,
+    observe: impl Fn(&MPS) -> f64,
+) -> Vec<(f64, f64)> {
+    let mut series = Vec::with_capacity(steps + 1);
+    series.push((0.0, observe(psi)));
+    for step in 1..=steps {
+        trotter_step_heisenberg(psi, h, dt, trunc);
+        series.push((step as f64 * dt, observe(psi)));
+    }
+    series
+}
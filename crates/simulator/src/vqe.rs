@@ -1,4 +1,4 @@
-use crate::output::write_csv;
+use crate::output::{write_csv, write_csv_ci};
 use quantum::{
     energy::energy,
     energy_shots::estimate_energy_shots,
@@ -7,36 +7,90 @@ use quantum::{
     noise::depolarizing_1q,
 };
 use rayon::prelude::*;
-use rng::ONDRng;
+use rng::{ONDRng, SeedContext};
 use tn::mps::MPS;
 
+/// (theta, energy) of the lowest-energy row, or None for no rows.
+///
+/// Ties break deterministically: lowest energy, then smallest theta, then smallest row
+/// index. `prefer` is associative and commutative, so a parallel reduction over rows
+/// picks the same point as this sequential fold whatever the reduction order.
+pub fn best_point(rows: &[(f64, f64)]) -> Option<(f64, f64)> {
+    rows.iter()
+        .enumerate()
+        .map(|(i, &(theta, e))| (i, theta, e))
+        .reduce(prefer)
+        .map(|(_, theta, e)| (theta, e))
+}
+
+/// The better of two (index, theta, energy) candidates under the `best_point` order.
+pub fn prefer(a: (usize, f64, f64), b: (usize, f64, f64)) -> (usize, f64, f64) {
+    let order = a.2.total_cmp(&b.2).then(a.1.total_cmp(&b.1)).then(a.0.cmp(&b.0));
+    if order.is_le() {
+        a
+    } else {
+        b
+    }
+}
+
 pub fn vqe_sweep() {
     vqe_sweep_steps(200);
 }
 
+/// Placement of the theta grid of the analytic sweep on [0, 2π].
+///
+/// A landscape whose minimum falls between aligned grid points is missed by up to half
+/// a step; the offset grid covers those midpoints, and `Interleaved` runs both.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ThetaGrid {
+    /// steps + 1 points 2π i / steps, both ends included.
+    #[default]
+    Aligned,
+    /// steps points 2π (i + ½) / steps.
+    HalfOffset,
+    /// Both grids merged in theta order: 2 steps + 1 points half a step apart.
+    Interleaved,
+}
+
+impl ThetaGrid {
+    pub fn points(self, steps: usize) -> Vec<f64> {
+        match self {
+            ThetaGrid::Aligned => (0..=steps).map(|i| theta_at(i, steps)).collect(),
+            ThetaGrid::HalfOffset => (0..steps).map(|i| theta_at(2 * i + 1, 2 * steps)).collect(),
+            ThetaGrid::Interleaved => (0..=2 * steps).map(|i| theta_at(i, 2 * steps)).collect(),
+        }
+    }
+}
+
+/// (theta, energy_fn(theta)) over `grid`; pass the rows to `best_point` for the minimum.
+pub fn grid_energies(
+    steps: usize,
+    grid: ThetaGrid,
+    energy_fn: impl Fn(f64) -> f64,
+) -> Vec<(f64, f64)> {
+    grid.points(steps)
+        .into_iter()
+        .map(|theta| (theta, energy_fn(theta)))
+        .collect()
+}
+
 pub fn vqe_sweep_steps(steps: usize) {
+    vqe_sweep_grid(steps, ThetaGrid::Aligned);
+}
+
+/// `vqe_sweep_steps` on the given grid placement.
+pub fn vqe_sweep_grid(steps: usize, grid: ThetaGrid) {
     let h = Hamiltonian {
         z_fields: vec![0.0, 0.0],
         zz_couplings: vec![1.0],
     };
 
-    let mut best_theta = 0.0;
-    let mut best_energy = f64::INFINITY;
-    let mut rows = Vec::with_capacity(steps + 1);
-
-    for i in 0..=steps {
-        let theta = 2.0 * std::f64::consts::PI * (i as f64) / (steps as f64);
-
+    let rows = grid_energies(steps, grid, |theta| {
         let mut psi = MPS::new_zero(2);
         psi.apply_1q(0, rx(theta));
-
-        let e = energy(&psi, &h);
-        rows.push((theta, e));
-        if e < best_energy {
-            best_energy = e;
-            best_theta = theta;
-        }
-    }
+        energy(&psi, &h)
+    });
+    let (best_theta, best_energy) = best_point(&rows).unwrap_or((0.0, f64::INFINITY));
 
     if let Err(err) = write_csv("vqe_analytic.csv", &rows) {
         eprintln!("Failed to write CSV to vqe_analytic.csv: {}", err);
@@ -54,8 +108,6 @@ pub fn vqe_sweep_shots(steps: usize, shots: usize, seed: &str) {
         zz_couplings: vec![1.0],
     };
 
-    let mut best_theta = 0.0;
-    let mut best_energy = f64::INFINITY;
     let mut rows = Vec::with_capacity(steps + 1);
 
     for i in 0..=steps {
@@ -64,16 +116,11 @@ pub fn vqe_sweep_shots(steps: usize, shots: usize, seed: &str) {
         let mut psi = MPS::new_zero(2);
         psi.apply_1q(0, rx(theta));
 
-        let seed_str = format!("{}-vqe-shots-{}", seed, i);
-        let mut rng = ONDRng::new(seed_str.as_bytes());
+        let mut rng = SeedContext::new("vqe-shots", seed.as_bytes()).rng(&[("step", i as u64)]);
         let e = estimate_energy_shots(&psi, &h, &mut rng, shots);
         rows.push((theta, e));
-
-        if e < best_energy {
-            best_energy = e;
-            best_theta = theta;
-        }
     }
+    let (best_theta, best_energy) = best_point(&rows).unwrap_or((0.0, f64::INFINITY));
 
     if let Err(err) = write_csv("vqe_shots.csv", &rows) {
         eprintln!("Failed to write CSV to vqe_shots.csv: {}", err);
@@ -85,64 +132,254 @@ pub fn vqe_sweep_shots(steps: usize, shots: usize, seed: &str) {
     );
 }
 
-fn noisy_vqe_energy(
+/// RNG streams of the noisy sweep: `circuit` drives shot sampling and, unless a separate
+/// `noise` context is given, the noise channels as well.
+struct NoisySeeds {
+    circuit: SeedContext,
+    noise: Option<SeedContext>,
+}
+
+impl NoisySeeds {
+    fn new(seed: &str, noise_seed: Option<&str>) -> Self {
+        Self {
+            circuit: SeedContext::new("noisy-vqe", seed.as_bytes()),
+            noise: noise_seed.map(|s| SeedContext::new("noisy-vqe-noise", s.as_bytes())),
+        }
+    }
+}
+
+/// One noisy trajectory at `theta`; its RNGs depend only on the seeds and (step, traj).
+fn noisy_trajectory_energy(
     theta: f64,
     h: &Hamiltonian,
-    trajectories: usize,
     shots: usize,
     p: f64,
-    seed: &str,
+    seeds: &NoisySeeds,
     step: usize,
+    traj: usize,
 ) -> f64 {
-    let energies: Vec<f64> = (0..trajectories)
-        .into_par_iter()
-        .map(|t| {
-            let seed_str = format!("{}-theta-{}-traj-{}", seed, step, t);
-            let mut rng = ONDRng::new(seed_str.as_bytes());
-            let mut psi = MPS::new_zero(2);
-            psi.apply_1q(0, rx(theta));
-            depolarizing_1q(&mut psi, 0, p, &mut rng);
-
-            estimate_energy_shots(&psi, h, &mut rng, shots)
-        })
-        .collect();
+    let indices = [("step", step as u64), ("traj", traj as u64)];
+    let mut rng = seeds.circuit.rng(&indices);
+    let mut noise_rng = seeds.noise.as_ref().map(|ctx| ctx.rng(&indices));
+    let mut psi = MPS::new_zero(2);
+    psi.apply_1q(0, rx(theta));
+    depolarizing_1q(&mut psi, 0, p, noise_rng.as_mut().unwrap_or(&mut rng));
 
+    estimate_energy_shots(&psi, h, &mut rng, shots)
+}
+
+/// Mean of trajectory energies, summed in trajectory order.
+fn trajectory_mean(energies: &[f64]) -> f64 {
     let mut total = 0.0;
     for e in energies {
         total += e;
     }
 
-    total / trajectories as f64
+    total / energies.len() as f64
 }
 
-pub fn noisy_vqe_sweep(
+/// Percentile-bootstrap (1 - alpha) confidence interval for the mean of `energies`.
+///
+/// Each of the `resamples` draws picks `energies.len()` trajectories with replacement;
+/// the interval runs between the alpha/2 and 1 - alpha/2 quantiles of their means.
+pub fn bootstrap_ci(
+    energies: &[f64],
+    resamples: usize,
+    rng: &mut ONDRng,
+    alpha: f64,
+) -> (f64, f64) {
+    assert!(!energies.is_empty(), "bootstrap_ci needs at least one energy");
+    assert!(resamples > 0, "bootstrap_ci needs at least one resample");
+    assert!(alpha > 0.0 && alpha < 1.0, "bootstrap_ci: alpha {} not in (0, 1)", alpha);
+
+    let n = energies.len();
+    let mut means: Vec<f64> = (0..resamples)
+        .map(|_| {
+            let mut total = 0.0;
+            for _ in 0..n {
+                let i = ((rng.next_f64(b"BOOTSTRAP") * n as f64) as usize).min(n - 1);
+                total += energies[i];
+            }
+            total / n as f64
+        })
+        .collect();
+    means.sort_by(f64::total_cmp);
+
+    let quantile = |q: f64| means[(q * (resamples - 1) as f64).round() as usize];
+    (quantile(alpha / 2.0), quantile(1.0 - alpha / 2.0))
+}
+
+/// Energies of every trajectory at `theta`, in trajectory order.
+fn noisy_trajectory_energies(
+    theta: f64,
+    h: &Hamiltonian,
+    trajectories: usize,
+    shots: usize,
+    p: f64,
+    seeds: &NoisySeeds,
+    step: usize,
+) -> Vec<f64> {
+    (0..trajectories)
+        .into_par_iter()
+        .map(|t| noisy_trajectory_energy(theta, h, shots, p, seeds, step, t))
+        .collect()
+}
+
+fn theta_at(i: usize, steps: usize) -> f64 {
+    2.0 * std::f64::consts::PI * (i as f64) / (steps as f64)
+}
+
+/// (theta, mean energy) for each theta step of the noisy sweep; the same seed gives the
+/// same numbers regardless of the size of the Rayon pool running it.
+///
+/// `seed` drives the shot sampling; `noise_seed`, when given, gives the depolarizing
+/// draws their own stream, so the noise can be resampled with the shots held fixed.
+/// Without it both come from `seed`.
+pub fn noisy_vqe_energies(
     steps: usize,
     trajectories: usize,
     shots: usize,
     p: f64,
     seed: &str,
-) {
+    noise_seed: Option<&str>,
+) -> Vec<(f64, f64)> {
+    let seeds = NoisySeeds::new(seed, noise_seed);
+    let energies = noisy_step_energies(steps, trajectories, shots, p, &seeds);
+    step_means(steps, &energies)
+}
+
+/// Trajectory energies for every theta step, parallel over trajectories within a step.
+fn noisy_step_energies(
+    steps: usize,
+    trajectories: usize,
+    shots: usize,
+    p: f64,
+    seeds: &NoisySeeds,
+) -> Vec<Vec<f64>> {
     let h = Hamiltonian {
         z_fields: vec![0.0, 0.0],
         zz_couplings: vec![1.0],
     };
+    (0..=steps)
+        .map(|i| {
+            let theta = theta_at(i, steps);
+            noisy_trajectory_energies(theta, &h, trajectories, shots, p, seeds, i)
+        })
+        .collect()
+}
 
-    let mut best_theta = 0.0;
-    let mut best_energy = f64::INFINITY;
-    let mut rows = Vec::with_capacity(steps + 1);
+/// (theta, mean energy) per step; a step without trajectories has a NaN mean.
+fn step_means(steps: usize, energies: &[Vec<f64>]) -> Vec<(f64, f64)> {
+    energies
+        .iter()
+        .enumerate()
+        .map(|(i, e)| (theta_at(i, steps), trajectory_mean(e)))
+        .collect()
+}
 
-    for i in 0..=steps {
-        let theta = 2.0 * std::f64::consts::PI * (i as f64) / (steps as f64);
-        let e = noisy_vqe_energy(theta, &h, trajectories, shots, p, seed, i);
-        rows.push((theta, e));
+/// `noisy_vqe_energies` with every (theta, trajectory) pair in one parallel iterator.
+///
+/// The nested version only parallelizes trajectories inside each theta step, so a
+/// handful of trajectories leaves most of the pool idle. Here all the work is handed
+/// to Rayon at once; the energies come back in (step, traj) order and each step's
+/// mean is summed in trajectory order, so the result matches the nested sweep bit for bit.
+pub fn noisy_vqe_energies_flat(
+    steps: usize,
+    trajectories: usize,
+    shots: usize,
+    p: f64,
+    seed: &str,
+    noise_seed: Option<&str>,
+) -> Vec<(f64, f64)> {
+    let seeds = NoisySeeds::new(seed, noise_seed);
+    let energies = noisy_step_energies_flat(steps, trajectories, shots, p, &seeds);
+    step_means(steps, &energies)
+}
 
-        if e < best_energy {
-            best_energy = e;
-            best_theta = theta;
-        }
+/// `noisy_step_energies` with every (theta, trajectory) pair in one parallel iterator.
+fn noisy_step_energies_flat(
+    steps: usize,
+    trajectories: usize,
+    shots: usize,
+    p: f64,
+    seeds: &NoisySeeds,
+) -> Vec<Vec<f64>> {
+    let h = Hamiltonian {
+        z_fields: vec![0.0, 0.0],
+        zz_couplings: vec![1.0],
+    };
+    if trajectories == 0 {
+        return vec![Vec::new(); steps + 1];
     }
 
-    if let Err(err) = write_csv("vqe_noisy.csv", &rows) {
+    let energies: Vec<f64> = (0..(steps + 1) * trajectories)
+        .into_par_iter()
+        .map(|job| {
+            let (i, t) = (job / trajectories, job % trajectories);
+            noisy_trajectory_energy(theta_at(i, steps), &h, shots, p, seeds, i, t)
+        })
+        .collect();
+
+    energies.chunks(trajectories).map(<[f64]>::to_vec).collect()
+}
+
+/// Noisy sweep with CSV output; seeds as in `noisy_vqe_energies`.
+pub fn noisy_vqe_sweep(
+    steps: usize,
+    trajectories: usize,
+    shots: usize,
+    p: f64,
+    seed: &str,
+    noise_seed: Option<&str>,
+) {
+    let seeds = NoisySeeds::new(seed, noise_seed);
+    let energies = noisy_step_energies(steps, trajectories, shots, p, &seeds);
+    report_noisy(steps, &energies, seed, trajectories, shots, p);
+}
+
+/// `noisy_vqe_sweep` scheduled with `noisy_vqe_energies_flat`; same CSV and output.
+pub fn noisy_vqe_sweep_flat(
+    steps: usize,
+    trajectories: usize,
+    shots: usize,
+    p: f64,
+    seed: &str,
+    noise_seed: Option<&str>,
+) {
+    let seeds = NoisySeeds::new(seed, noise_seed);
+    let energies = noisy_step_energies_flat(steps, trajectories, shots, p, &seeds);
+    report_noisy(steps, &energies, seed, trajectories, shots, p);
+}
+
+/// Bootstrap resamples behind each confidence interval in `vqe_noisy.csv`.
+const NOISY_CI_RESAMPLES: usize = 1000;
+
+fn report_noisy(
+    steps: usize,
+    energies: &[Vec<f64>],
+    seed: &str,
+    trajectories: usize,
+    shots: usize,
+    p: f64,
+) {
+    let rows = step_means(steps, energies);
+    let (best_theta, best_energy) = best_point(&rows).unwrap_or((0.0, f64::INFINITY));
+
+    // 95% interval over trajectories, from its own stream so shots and noise are untouched.
+    let ctx = SeedContext::new("noisy-vqe-bootstrap", seed.as_bytes());
+    let ci: Vec<(f64, f64)> = energies
+        .iter()
+        .enumerate()
+        .map(|(i, e)| {
+            if e.is_empty() {
+                return (f64::NAN, f64::NAN);
+            }
+            let mut rng = ctx.rng(&[("step", i as u64)]);
+            bootstrap_ci(e, NOISY_CI_RESAMPLES, &mut rng, 0.05)
+        })
+        .collect();
+
+    if let Err(err) = write_csv_ci("vqe_noisy.csv", &rows, &ci) {
         eprintln!("Failed to write CSV to vqe_noisy.csv: {}", err);
     }
 
@@ -154,7 +391,7 @@ pub fn noisy_vqe_sweep(
 
 #[cfg(test)]
 mod tests {
-    use super::noisy_vqe_energy;
+    use super::{noisy_trajectory_energies, NoisySeeds};
     use quantum::hamiltonian::Hamiltonian;
 
     #[test]
@@ -164,9 +401,10 @@ mod tests {
             zz_couplings: vec![1.0],
         };
 
-        let e1 = noisy_vqe_energy(0.7, &h, 8, 20, 0.01, "seed", 3);
-        let e2 = noisy_vqe_energy(0.7, &h, 8, 20, 0.01, "seed", 3);
+        let seeds = NoisySeeds::new("seed", None);
+        let e1 = noisy_trajectory_energies(0.7, &h, 8, 20, 0.01, &seeds, 3);
+        let e2 = noisy_trajectory_energies(0.7, &h, 8, 20, 0.01, &seeds, 3);
 
-        assert!((e1 - e2).abs() < 1e-12, "e1 = {}, e2 = {}", e1, e2);
+        assert_eq!(e1, e2);
     }
 }
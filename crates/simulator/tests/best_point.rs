@@ -0,0 +1,63 @@
+use rayon::prelude::*;
+use simulator::{
+    best_point,
+    vqe::{grid_energies, prefer},
+    ThetaGrid,
+};
+
+#[test]
+fn constant_energy_selects_theta_zero() {
+    let steps = 16;
+    let rows: Vec<(f64, f64)> = (0..=steps)
+        .map(|i| (2.0 * std::f64::consts::PI * i as f64 / steps as f64, -1.0))
+        .collect();
+    assert_eq!(best_point(&rows), Some((0.0, -1.0)));
+
+    // Reversed input still picks the smallest theta, not the first row.
+    let reversed: Vec<(f64, f64)> = rows.iter().rev().copied().collect();
+    assert_eq!(best_point(&reversed), Some((0.0, -1.0)));
+
+    assert_eq!(best_point(&[]), None);
+}
+
+#[test]
+fn parallel_reduction_matches_sequential_choice() {
+    // Two exact minima at theta = 1 and theta = 3, plus a duplicate theta = 1 row.
+    let rows = vec![(3.0, -2.0), (0.5, 1.0), (1.0, -2.0), (2.0, 0.0), (1.0, -2.0)];
+    let seq = best_point(&rows).unwrap();
+    assert_eq!(seq, (1.0, -2.0));
+
+    for threads in [1, 2, 4] {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+        let par = pool.install(|| {
+            rows.par_iter()
+                .enumerate()
+                .map(|(i, &(theta, e))| (i, theta, e))
+                .reduce_with(prefer)
+                .unwrap()
+        });
+        assert_eq!(par, (2, 1.0, -2.0), "threads = {}", threads);
+    }
+}
+
+#[test]
+fn offset_grid_finds_minimum_between_aligned_points() {
+    // Period commensurate with the step: every aligned point sits on a maximum.
+    let steps = 8;
+    let energy_fn = |theta: f64| (steps as f64 * theta).cos();
+
+    let aligned = grid_energies(steps, ThetaGrid::Aligned, energy_fn);
+    let offset = grid_energies(steps, ThetaGrid::HalfOffset, energy_fn);
+    let both = grid_energies(steps, ThetaGrid::Interleaved, energy_fn);
+    assert_eq!((aligned.len(), offset.len(), both.len()), (9, 8, 17));
+
+    let (_, e_aligned) = best_point(&aligned).unwrap();
+    let (theta, e_offset) = best_point(&offset).unwrap();
+    assert!((e_aligned - 1.0).abs() < 1e-12);
+    assert!((e_offset + 1.0).abs() < 1e-12);
+    assert!((theta - std::f64::consts::PI / steps as f64).abs() < 1e-12);
+    assert_eq!(best_point(&both), Some((theta, e_offset)));
+}
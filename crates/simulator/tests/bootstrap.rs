@@ -0,0 +1,36 @@
+use rng::ONDRng;
+use simulator::vqe::bootstrap_ci;
+
+/// Deterministic stand-in for per-trajectory energies: -1 plus uniform noise in [-0.5, 0.5).
+fn trajectory_energies(n: usize) -> Vec<f64> {
+    let mut rng = ONDRng::new(b"bootstrap-energies");
+    (0..n).map(|_| -1.0 + rng.next_f64(b"E") - 0.5).collect()
+}
+
+fn mean(xs: &[f64]) -> f64 {
+    xs.iter().sum::<f64>() / xs.len() as f64
+}
+
+#[test]
+fn bootstrap_interval_contains_mean_and_narrows() {
+    let mut widths = Vec::new();
+    for n in [10, 100, 1000] {
+        let energies = trajectory_energies(n);
+        let m = mean(&energies);
+        let mut rng = ONDRng::new(b"bootstrap-ci");
+        let (lo, hi) = bootstrap_ci(&energies, 400, &mut rng, 0.05);
+        assert!(lo <= m && m <= hi, "n = {}: {} not in [{}, {}]", n, m, lo, hi);
+        widths.push(hi - lo);
+
+        // Same seed, same interval.
+        let again = bootstrap_ci(&energies, 400, &mut ONDRng::new(b"bootstrap-ci"), 0.05);
+        assert_eq!((lo, hi), again);
+    }
+    assert!(widths[0] > widths[1] && widths[1] > widths[2], "{:?}", widths);
+}
+
+#[test]
+fn constant_energies_give_a_point_interval() {
+    let mut rng = ONDRng::new(b"bootstrap-const");
+    assert_eq!(bootstrap_ci(&[-0.5; 8], 50, &mut rng, 0.1), (-0.5, -0.5));
+}
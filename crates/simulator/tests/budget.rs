@@ -0,0 +1,53 @@
+mod common;
+
+use common::brickwork;
+use simulator::budget::CircuitBudget;
+use tn::{
+    mps::{fidelity, MPS},
+    truncation::Truncation,
+};
+
+fn max_bond(psi: &MPS) -> usize {
+    psi.sites().iter().map(|s| s.dr).max().unwrap()
+}
+
+#[test]
+fn loose_budget_stays_above_target_fidelity() {
+    let (n, depth) = (10, 10);
+    let circuit = brickwork(n, depth);
+
+    let mut exact = MPS::new_zero(n);
+    circuit.apply(&mut exact, Truncation::exact());
+
+    let total = 0.05;
+    let mut budget = CircuitBudget::for_circuit(total, &circuit);
+    assert_eq!(budget.gates_left(), depth * (n - 1) / 2);
+    let mut psi = MPS::new_zero(n);
+    assert!(!budget.run(&circuit, &mut psi));
+
+    let f = fidelity(&psi, &exact);
+    assert!(f >= 1.0 - total, "F = {}", f);
+    assert!(budget.spent() <= total, "spent = {}", budget.spent());
+    assert!(budget.spent() > 0.0);
+    assert_eq!(budget.gates_left(), 0);
+    // The budget was actually used to shrink the state.
+    assert!(
+        max_bond(&psi) < max_bond(&exact),
+        "{} vs {}",
+        max_bond(&psi),
+        max_bond(&exact)
+    );
+}
+
+#[test]
+fn bond_cap_overruns_are_reported() {
+    let (n, depth) = (8, 8);
+    let circuit = brickwork(n, depth);
+
+    let mut budget = CircuitBudget::for_circuit(1e-3, &circuit).with_max_bond(2);
+    let mut psi = MPS::new_zero(n);
+    assert!(budget.run(&circuit, &mut psi));
+    assert!(max_bond(&psi) <= 2);
+    assert!(budget.remaining() < 0.0);
+    assert!(budget.spent() > 1e-3, "spent = {}", budget.spent());
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use common::brickwork;
+use quantum::{apply_cnot, gates::rx, hamiltonian::Hamiltonian};
+use simulator::calibrate::{calibrate_cutoff, min_chi_for_energy_tol};
+use sweep_common::rz;
+use tn::{mps::MPS, truncation::Truncation};
+
+fn run_brickwork(n: usize, depth: usize, cutoff: f64) -> MPS {
+    let trunc = Truncation::builder().max_bond(64).cutoff(cutoff).build();
+    let mut psi = MPS::new_zero(n);
+    brickwork(n, depth).apply(&mut psi, trunc);
+    psi
+}
+
+#[test]
+fn smaller_cutoffs_give_smaller_infidelity() {
+    let (n, depth) = (10, 8);
+    let reference = run_brickwork(n, depth, 0.0);
+    let cutoffs = [3e-1, 1e-1, 3e-2, 1e-2, 1e-4];
+
+    let rows = calibrate_cutoff(|c| run_brickwork(n, depth, c), &reference, &cutoffs);
+
+    assert_eq!(rows.len(), cutoffs.len());
+    for w in rows.windows(2) {
+        assert!(w[1].1 <= w[0].1 + 1e-12, "{:?}", rows);
+    }
+    assert!(rows[0].1 > rows[rows.len() - 1].1, "{:?}", rows);
+}
+
+/// Product state with a single entangling CNOT on the middle bond: exact at chi = 2.
+fn one_cnot(n: usize, max_bond: usize) -> MPS {
+    let trunc = Truncation::builder().max_bond(max_bond).cutoff(0.0).build();
+    let mut psi = MPS::new_zero(n);
+    for k in 0..n {
+        psi.apply_1q(k, rx(0.9 + 0.2 * k as f64));
+    }
+    apply_cnot(&mut psi, n / 2 - 1, trunc);
+    for k in 0..n {
+        psi.apply_1q(k, rz(0.5 * k as f64));
+        psi.apply_1q(k, rx(0.3));
+    }
+    psi
+}
+
+#[test]
+fn low_entanglement_circuit_selects_small_bond() {
+    let n = 8;
+    let h = Hamiltonian::ising(n, 0.7, 1.0);
+    let chi = min_chi_for_energy_tol(|chi| one_cnot(n, chi), &h, 1e-10, &[16, 1, 4, 2, 8]);
+    assert_eq!(chi, 2);
+
+    // A tolerance no candidate meets falls back to the largest bond.
+    assert_eq!(min_chi_for_energy_tol(|chi| one_cnot(n, chi), &h, -1.0, &[1, 2, 4]), 4);
+}
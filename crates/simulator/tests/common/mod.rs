@@ -0,0 +1,24 @@
+use quantum::{
+    circuit::Circuit,
+    gates::{cnot, rx},
+};
+use sweep_common::rz;
+
+/// Deterministic brickwork: fixed rx and rz angles on every site, then CNOTs on
+/// alternating bonds.
+pub fn brickwork(n: usize, depth: usize) -> Circuit {
+    let mut c = Circuit::new();
+    for layer in 0..depth {
+        for k in 0..n {
+            let a = 0.37 * (k * 7 + layer * 3) as f64;
+            c.push_1q(k, rx(a.sin() * 2.0));
+            c.push_1q(k, rz(a.cos() * 3.0));
+        }
+        let mut k = layer % 2;
+        while k + 1 < n {
+            c.push_2q(k, cnot());
+            k += 2;
+        }
+    }
+    c
+}
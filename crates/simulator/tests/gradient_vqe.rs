@@ -1,6 +1,40 @@
-use quantum::{energy::energy_heisenberg, gates::rx, hamiltonian::Heisenberg};
-use simulator::gradient_vqe::vqe_gradient;
-use tn::mps::MPS;
+use quantum::{
+    apply_cnot,
+    energy::{energy_heisenberg, energy_pauli_sum},
+    gates::{pauli_x, rx, Pauli},
+    hamiltonian::{Heisenberg, PauliSum},
+};
+use rng::ONDRng;
+use simulator::{
+    grad::{parameter_shift_grad, value_and_grad_layered},
+    gradient_vqe::{
+        minibatch_gradient, vqe_excited, vqe_gradient, vqe_gradient_params,
+        vqe_gradient_params_layered, LrSchedule, VqeConfig,
+    },
+};
+use tn::{
+    mps::{C64, MPS},
+    truncation::Truncation,
+};
+
+fn ry(theta: f64) -> [[C64; 2]; 2] {
+    let c = (theta / 2.0).cos();
+    let s = (theta / 2.0).sin();
+    [
+        [C64::new(c, 0.0), C64::new(-s, 0.0)],
+        [C64::new(s, 0.0), C64::new(c, 0.0)],
+    ]
+}
+
+/// cos(θ/2)|01> + sin(θ/2)|10>: the two-level {singlet, triplet-0} sector of two spins.
+fn two_level_ansatz(theta: &[f64]) -> MPS {
+    let trunc = Truncation::builder().max_bond(4).cutoff(1e-14).build();
+    let mut psi = MPS::new_zero(2);
+    psi.apply_1q(0, ry(theta[0]));
+    apply_cnot(&mut psi, 0, trunc);
+    psi.apply_1q(1, pauli_x());
+    psi
+}
 
 #[test]
 fn gradient_vqe_converges() {
@@ -16,3 +50,175 @@ fn gradient_vqe_converges() {
 
     assert!(e < -0.9, "E = {}", e);
 }
+
+#[test]
+fn excited_state_vqe_recovers_gap() {
+    let h = Heisenberg::uniform(2, 1.0);
+    let ground = two_level_ansatz(&[-std::f64::consts::FRAC_PI_2]);
+    let e0 = energy_heisenberg(&ground, &h);
+    assert!((e0 + 3.0).abs() < 1e-10, "E0 = {}", e0);
+
+    let cfg = VqeConfig {
+        theta0: vec![0.3],
+        lr: 0.1,
+        steps: 200,
+        schedule: LrSchedule::Constant,
+        wrap_parameters: false,
+    };
+    let (theta, e1) = vqe_excited(&ground, two_level_ansatz, &h, 10.0, &cfg);
+
+    assert_eq!(theta.len(), 1);
+    assert!((e1 - e0 - 4.0).abs() < 1e-4, "gap = {}", e1 - e0);
+}
+
+#[test]
+fn wrapped_parameters_stay_in_canonical_range() {
+    // Minima at θ0 = -1 (mod 2π) and θ1 = -π/2 (mod 2π), reached from far outside [0, 2π).
+    let energy_fn = |t: &[f64]| -(t[0] + 1.0).cos() + t[1].sin() - (t[2] - 0.5).cos();
+    let mut cfg = VqeConfig {
+        theta0: vec![-5.0, 20.0, -13.0],
+        lr: 0.3,
+        steps: 200,
+        schedule: LrSchedule::Constant,
+        wrap_parameters: true,
+    };
+
+    let (theta, e) = vqe_gradient_params(energy_fn, &cfg);
+    let two_pi = 2.0 * std::f64::consts::PI;
+    assert!(theta.iter().all(|&t| (0.0..two_pi).contains(&t)), "{:?}", theta);
+
+    cfg.wrap_parameters = false;
+    let (_, e_unwrapped) = vqe_gradient_params(energy_fn, &cfg);
+    assert!((e - e_unwrapped).abs() < 1e-9);
+    assert!((e + 3.0).abs() < 1e-6, "E = {}", e);
+}
+
+#[test]
+fn decaying_rate_beats_too_large_constant_rate() {
+    // Curvatures 100 and 1: lr = 0.02 overshoots along the stiff axis.
+    let energy_fn = |t: &[f64]| 0.5 * (100.0 * t[0] * t[0] + t[1] * t[1]);
+    let mut cfg = VqeConfig {
+        theta0: vec![1.0, 1.0],
+        lr: 0.02,
+        steps: 100,
+        schedule: LrSchedule::Constant,
+        wrap_parameters: false,
+    };
+    let (_, e_const) = vqe_gradient_params(energy_fn, &cfg);
+
+    cfg.schedule = LrSchedule::Decay { rate: 1.0 };
+    let (_, e_decay) = vqe_gradient_params(energy_fn, &cfg);
+
+    assert!(e_decay < e_const, "decay {} vs constant {}", e_decay, e_const);
+    assert!(e_decay < 50.0);
+}
+
+#[test]
+fn cosine_schedule_anneals_to_zero() {
+    let s = LrSchedule::Cosine { steps: 10 };
+    assert_eq!(s.lr(0.5, 0), 0.5);
+    assert!((s.lr(0.5, 5) - 0.25).abs() < 1e-12);
+    assert!(s.lr(0.5, 10).abs() < 1e-12);
+    assert!(s.lr(0.5, 20).abs() < 1e-12);
+}
+
+fn product_ansatz(theta: &[f64]) -> MPS {
+    let trunc = Truncation::builder().max_bond(4).cutoff(1e-14).build();
+    let mut psi = MPS::new_zero(2);
+    psi.apply_1q(0, ry(theta[0]));
+    psi.apply_1q(1, ry(theta[1]));
+    apply_cnot(&mut psi, 0, trunc);
+    psi
+}
+
+#[test]
+fn minibatch_gradient_is_unbiased() {
+    let mut h = PauliSum::new(2);
+    h.add(1.0, &[(0, Pauli::Z), (1, Pauli::Z)]);
+    h.add(0.5, &[(0, Pauli::X)]);
+    h.add(-0.3, &[(1, Pauli::X)]);
+
+    let theta = [0.7, -0.4];
+    let full = parameter_shift_grad(&theta, &|t: &[f64]| {
+        energy_pauli_sum(&product_ansatz(t), &h)
+    });
+
+    let mut rng = ONDRng::new(b"minibatch-test");
+    let samples = 4000;
+    let mut mean = [0.0f64; 2];
+    for _ in 0..samples {
+        let g = minibatch_gradient(&product_ansatz, &h, &theta, 2, &mut rng);
+        mean[0] += g[0] / samples as f64;
+        mean[1] += g[1] / samples as f64;
+    }
+
+    for i in 0..2 {
+        assert!((mean[i] - full[i]).abs() < 0.05, "grad[{}]: {} vs {}", i, mean[i], full[i]);
+    }
+}
+
+#[test]
+fn value_and_grad_layered_matches_separate_calls() {
+    let mut ph = PauliSum::new(2);
+    ph.add(1.0, &[(0, Pauli::Z), (1, Pauli::Z)]);
+    ph.add(0.5, &[(0, Pauli::X)]);
+    ph.add(-0.3, &[(1, Pauli::X)]);
+
+    // product_ansatz as blocks: Ry on qubit 0, then Ry on qubit 1 followed by the CNOT.
+    let trunc = Truncation::builder().max_bond(4).cutoff(1e-14).build();
+    let step = |psi: &mut MPS, i: usize, t: f64| {
+        psi.apply_1q(i, ry(t));
+        if i == 1 {
+            apply_cnot(psi, 0, trunc);
+        }
+    };
+
+    let theta = [0.7, -0.4];
+    let (e, g) = value_and_grad_layered(&theta, &MPS::new_zero(2), step, |psi: &MPS| {
+        energy_pauli_sum(psi, &ph)
+    });
+    let full = parameter_shift_grad(&theta, &|t: &[f64]| energy_pauli_sum(&product_ansatz(t), &ph));
+
+    assert!((e - energy_pauli_sum(&product_ansatz(&theta), &ph)).abs() < 1e-12);
+    for i in 0..2 {
+        assert!((g[i] - full[i]).abs() < 1e-12, "grad[{}]: {} vs {}", i, g[i], full[i]);
+    }
+}
+
+#[test]
+fn layered_descent_matches_rebuilding_the_ansatz() {
+    let mut ph = PauliSum::new(2);
+    ph.add(1.0, &[(0, Pauli::Z), (1, Pauli::Z)]);
+    ph.add(0.5, &[(0, Pauli::X)]);
+    ph.add(-0.3, &[(1, Pauli::X)]);
+
+    let trunc = Truncation::builder().max_bond(4).cutoff(1e-14).build();
+    let step = |psi: &mut MPS, i: usize, t: f64| {
+        psi.apply_1q(i, ry(t));
+        if i == 1 {
+            apply_cnot(psi, 0, trunc);
+        }
+    };
+    let cfg = VqeConfig {
+        theta0: vec![0.7, -0.4],
+        lr: 0.2,
+        steps: 15,
+        schedule: LrSchedule::Decay { rate: 0.1 },
+        wrap_parameters: true,
+    };
+
+    let (theta, e) = vqe_gradient_params_layered(
+        &MPS::new_zero(2),
+        step,
+        |psi: &MPS| energy_pauli_sum(psi, &ph),
+        &cfg,
+    );
+    let (theta_ref, e_ref) =
+        vqe_gradient_params(|t: &[f64]| energy_pauli_sum(&product_ansatz(t), &ph), &cfg);
+
+    assert!((e - e_ref).abs() < 1e-12, "E = {} vs {}", e, e_ref);
+    for i in 0..2 {
+        assert!((theta[i] - theta_ref[i]).abs() < 1e-12, "theta[{}]", i);
+    }
+    assert!(e < energy_pauli_sum(&product_ansatz(&cfg.theta0), &ph));
+}
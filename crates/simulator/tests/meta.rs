@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use simulator::meta::{meta_path, write_meta};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct SweepArgs {
+    n: usize,
+    depth: usize,
+    max_bond: String,
+    cutoff: f64,
+    heisenberg_jz: f64,
+    seed: String,
+    out: String,
+}
+
+#[test]
+fn meta_sidecar_round_trips() {
+    let out = std::env::temp_dir().join(format!("meta_test_{}.csv", std::process::id()));
+    let out = out.to_str().unwrap().to_string();
+    let args = SweepArgs {
+        n: 24,
+        depth: 30,
+        max_bond: "16,32".to_string(),
+        cutoff: 1e-8,
+        heisenberg_jz: -0.5,
+        seed: "chi-sweep".to_string(),
+        out: out.clone(),
+    };
+
+    let path = write_meta(&out, &args).unwrap();
+    assert_eq!(path, meta_path(&out));
+    assert!(path.ends_with(".csv.meta.json"));
+
+    let text = std::fs::read_to_string(&path).unwrap();
+    let back: SweepArgs = serde_json::from_str(&text).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(back, args);
+}
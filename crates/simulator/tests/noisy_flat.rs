@@ -0,0 +1,47 @@
+use simulator::{noisy_vqe_energies, noisy_vqe_energies_flat};
+
+#[test]
+fn flat_schedule_matches_nested_for_fixed_seed() {
+    for (steps, trajectories) in [(6, 1), (8, 3), (4, 16)] {
+        let nested = noisy_vqe_energies(steps, trajectories, 20, 0.05, "flat-seed", None);
+        let flat = noisy_vqe_energies_flat(steps, trajectories, 20, 0.05, "flat-seed", None);
+        assert_eq!(
+            nested, flat,
+            "steps = {}, trajectories = {}",
+            steps, trajectories
+        );
+    }
+}
+
+#[test]
+fn flat_schedule_is_independent_of_pool_size() {
+    let run = |threads| {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap()
+            .install(|| noisy_vqe_energies_flat(5, 4, 10, 0.1, "flat-pool", None))
+    };
+    assert_eq!(run(1), run(3));
+}
+
+#[test]
+fn noise_seed_resamples_noise_only() {
+    let run = |p, noise_seed| noisy_vqe_energies(6, 4, 20, p, "circuit-seed", noise_seed);
+    let a = run(0.3, Some("noise-a"));
+    let b = run(0.3, Some("noise-b"));
+    assert_ne!(a, b);
+    assert_eq!(a, run(0.3, Some("noise-a")));
+    assert_eq!(
+        a,
+        noisy_vqe_energies_flat(6, 4, 20, 0.3, "circuit-seed", Some("noise-a"))
+    );
+
+    // With no noise drawn, the shot stream alone sets the energies, whatever the noise seed.
+    let clean = run(0.0, None);
+    assert_eq!(run(0.0, Some("noise-a")), clean);
+    assert_eq!(run(0.0, Some("noise-b")), clean);
+
+    // Without a noise seed, noise shares the circuit stream.
+    assert_ne!(run(0.3, None), a);
+}
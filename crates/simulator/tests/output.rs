@@ -0,0 +1,45 @@
+use simulator::output::CsvAppender;
+use std::io::ErrorKind;
+
+fn temp_csv(tag: &str) -> String {
+    let path = std::env::temp_dir().join(format!("csv_{}_{}.csv", tag, std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path.to_str().unwrap().to_string()
+}
+
+#[test]
+fn interrupted_writer_leaves_complete_rows() {
+    let path = temp_csv("interrupt");
+    let mut w = CsvAppender::create(&path, "chi,energy").unwrap();
+    for k in 0..3 {
+        w.write_row(&format!("{},{}", k, -0.5 * k as f64)).unwrap();
+    }
+    // Skip Drop entirely, as a killed process would.
+    std::mem::forget(w);
+
+    let text = std::fs::read_to_string(&path).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines, ["chi,energy", "0,-0", "1,-0.5", "2,-1"]);
+    assert!(text.ends_with('\n'));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn append_resumes_matching_file_and_rejects_other_headers() {
+    let path = temp_csv("append");
+    {
+        let mut w = CsvAppender::append(&path, "chi,energy").unwrap();
+        w.write_row("4,1.5").unwrap();
+    }
+    {
+        let mut w = CsvAppender::append(&path, "chi,energy").unwrap();
+        w.write_row("8,0.25").unwrap();
+    }
+
+    let text = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(text, "chi,energy\n4,1.5\n8,0.25\n");
+
+    let err = CsvAppender::append(&path, "depth,chi,fidelity").err().unwrap();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    std::fs::remove_file(&path).unwrap();
+}
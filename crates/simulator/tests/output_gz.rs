@@ -0,0 +1,37 @@
+#![cfg(feature = "flate2")]
+
+use flate2::read::GzDecoder;
+use simulator::output::{gz_path, CsvAppender};
+use std::io::Read;
+
+#[test]
+fn gzip_output_decompresses_to_plain_csv() {
+    let plain = std::env::temp_dir().join(format!("csv_gz_{}.csv", std::process::id()));
+    let plain = plain.to_str().unwrap().to_string();
+    let gz = gz_path(&plain);
+    assert!(gz.ends_with(".csv.gz"));
+
+    {
+        let mut a = CsvAppender::create(&plain, "depth,chi,fidelity").unwrap();
+        let mut b = CsvAppender::create_gz(&gz, "depth,chi,fidelity").unwrap();
+        for depth in 1..=20 {
+            for chi in [4, 8, 16] {
+                let row = format!("{},{},{}", depth, chi, 1.0 / (depth * chi) as f64);
+                a.write_row(&row).unwrap();
+                b.write_row(&row).unwrap();
+            }
+        }
+        a.finish().unwrap();
+        b.finish().unwrap();
+    }
+
+    let expected = std::fs::read(&plain).unwrap();
+    let mut decoded = Vec::new();
+    GzDecoder::new(std::fs::File::open(&gz).unwrap())
+        .read_to_end(&mut decoded)
+        .unwrap();
+    assert_eq!(decoded, expected);
+
+    std::fs::remove_file(&plain).unwrap();
+    std::fs::remove_file(&gz).unwrap();
+}
@@ -0,0 +1,38 @@
+use simulator::scaling::{circuit_scaling, thread_scaling, write_scaling_csv};
+use tn::truncation::Truncation;
+
+#[test]
+fn one_row_per_thread_count_with_identical_results() {
+    let counts = [1, 2, 4];
+    let rows = thread_scaling(&counts, 6, 8, 20, 0.05, "scaling-seed");
+
+    assert_eq!(rows.len(), counts.len());
+    for (row, &t) in rows.iter().zip(counts.iter()) {
+        assert_eq!(row.threads, t);
+        assert!(row.seconds >= 0.0);
+        assert_eq!(row.energies.len(), 7);
+        assert_eq!(row.energies, rows[0].energies, "threads = {}", t);
+    }
+    assert_eq!(rows[0].speedup, 1.0);
+
+    let path = std::env::temp_dir().join("simulator_thread_scaling.csv");
+    let path = path.to_str().unwrap();
+    write_scaling_csv(path, &rows).unwrap();
+    let csv = std::fs::read_to_string(path).unwrap();
+    assert_eq!(csv.lines().count(), counts.len() + 1);
+    assert!(csv.starts_with("threads,seconds,speedup,min_energy\n"));
+}
+
+#[test]
+fn circuit_scaling_runs_the_shared_fixture_deterministically() {
+    let counts = [1, 3];
+    let trunc = Truncation::builder().max_bond(8).build();
+    let rows = circuit_scaling(&counts, 8, 6, 5, trunc, "scaling-circuits");
+
+    assert_eq!(rows.len(), counts.len());
+    assert_eq!(rows[0].energies.len(), 5);
+    assert_eq!(rows[0].energies, rows[1].energies);
+    assert!(rows[0].energies.iter().all(|z| z.abs() <= 1.0 + 1e-12));
+    // Distinct seeds give distinct circuits.
+    assert_ne!(rows[0].energies[0], rows[0].energies[1]);
+}
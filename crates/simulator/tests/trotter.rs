@@ -0,0 +1,154 @@
+use quantum::{
+    gates::{pauli_x, rx},
+    hamiltonian::Heisenberg,
+    observables::{expect_x, expect_z},
+    trotter::trotter_step_heisenberg,
+};
+use simulator::trotter::{
+    evolve_and_record, trotter_error_estimate, trotter_step2_heisenberg, trotter_step_light_cone,
+};
+use tn::{
+    mps::{index_to_bits, overlap, C64, MPS},
+    truncation::Truncation,
+};
+
+fn neel(n: usize) -> MPS {
+    let mut psi = MPS::new_zero(n);
+    for k in (1..n).step_by(2) {
+        psi.apply_1q(k, pauli_x());
+    }
+    psi
+}
+
+#[test]
+fn trotter_error_shrinks_with_dt() {
+    let trunc = Truncation::builder().max_bond(32).cutoff(1e-14).build();
+    let h = Heisenberg::uniform(6, 1.0);
+    let psi = neel(6);
+
+    let errors: Vec<f64> = [0.2, 0.1, 0.05]
+        .iter()
+        .map(|&dt| trotter_error_estimate(&psi, &h, dt, trunc))
+        .collect();
+
+    assert!(errors[0] > 0.0);
+    assert!(errors[1] < errors[0], "{:?}", errors);
+    assert!(errors[2] < errors[1], "{:?}", errors);
+}
+
+/// H|v> for the uniform Heisenberg chain on a dense state (site 0 = MSB).
+fn heisenberg_apply(v: &[C64], n: usize, j: f64) -> Vec<C64> {
+    let mut out = vec![C64::new(0.0, 0.0); v.len()];
+    for (x, &amp) in v.iter().enumerate() {
+        let bits = index_to_bits(x, n);
+        for b in 0..n - 1 {
+            let mask = (1 << (n - 1 - b)) | (1 << (n - 2 - b));
+            let y = x ^ mask;
+            let zz = if bits[b] == bits[b + 1] { 1.0 } else { -1.0 };
+            // XX and YY both flip the pair; YY picks up -1 when the bits agree.
+            out[x] += amp * j * zz;
+            out[y] += amp * j * (1.0 - zz);
+        }
+    }
+    out
+}
+
+/// exp(-i H t)|v> via Taylor series on small substeps.
+fn exact_evolve(v: &[C64], n: usize, j: f64, t: f64) -> Vec<C64> {
+    let substeps = 200;
+    let tau = t / substeps as f64;
+    let mut state = v.to_vec();
+    for _ in 0..substeps {
+        let mut term = state.clone();
+        let mut acc = state.clone();
+        for k in 1..20 {
+            let h_term = heisenberg_apply(&term, n, j);
+            let scale = C64::new(0.0, -tau / k as f64);
+            term = h_term.into_iter().map(|x| x * scale).collect();
+            for (a, b) in acc.iter_mut().zip(term.iter()) {
+                *a += b;
+            }
+        }
+        state = acc;
+    }
+    state
+}
+
+fn infidelity(a: &[C64], b: &[C64]) -> f64 {
+    let ov: C64 = a.iter().zip(b.iter()).map(|(x, y)| x.conj() * y).sum();
+    1.0 - ov.norm_sqr()
+}
+
+#[test]
+fn second_order_beats_first_order_against_exact() {
+    let trunc = Truncation::builder().max_bond(32).cutoff(1e-14).build();
+    let n = 6;
+    let h = Heisenberg::uniform(n, 1.0);
+    let psi0 = neel(n);
+    let (t, steps) = (1.0, 10);
+    let dt = t / steps as f64;
+
+    let exact = exact_evolve(&psi0.to_statevector(), n, 1.0, t);
+
+    let mut first = psi0.clone();
+    let mut second = psi0.clone();
+    for _ in 0..steps {
+        trotter_step_heisenberg(&mut first, &h, dt, trunc);
+        trotter_step2_heisenberg(&mut second, &h, dt, trunc);
+    }
+
+    let err1 = infidelity(&exact, &first.to_statevector());
+    let err2 = infidelity(&exact, &second.to_statevector());
+    assert!(err2 < err1, "second-order {} vs first-order {}", err2, err1);
+    assert!(err2 < 0.1 * err1, "second-order {} vs first-order {}", err2, err1);
+}
+
+#[test]
+fn light_cone_matches_full_evolution_for_short_times() {
+    let trunc = Truncation::builder().max_bond(32).cutoff(1e-14).build();
+    let n = 12;
+    let center = 6;
+    let h = Heisenberg::uniform(n, 1.0);
+
+    // Single flipped spin on the ferromagnetic background, an eigenstate of every bond.
+    let mut full = MPS::new_zero(n);
+    full.apply_1q(center, pauli_x());
+    let mut cone = full.clone();
+
+    for step in 0..3 {
+        trotter_step_heisenberg(&mut full, &h, 0.1, trunc);
+        trotter_step_light_cone(&mut cone, &h, 0.1, trunc, center, 2 * (step + 1));
+    }
+
+    let f = overlap(&full, &cone).norm_sqr();
+    assert!((f - 1.0).abs() < 1e-10, "fidelity = {}", f);
+}
+
+#[test]
+fn zz_evolution_conserves_recorded_magnetization() {
+    let n = 6;
+    let h = Heisenberg {
+        jx: vec![0.0; n - 1],
+        jy: vec![0.0; n - 1],
+        jz: vec![1.0; n - 1],
+    };
+    let mut psi = MPS::new_zero(n);
+    for k in 0..n {
+        psi.apply_1q(k, rx(0.3 + 0.2 * k as f64));
+    }
+    let trunc = Truncation::builder().max_bond(32).cutoff(1e-14).build();
+    let magnetization = |psi: &MPS| (0..psi.sites().len()).map(|k| expect_z(psi, k)).sum::<f64>();
+
+    let series = evolve_and_record(&mut psi.clone(), &h, 0.1, 20, trunc, magnetization);
+
+    assert_eq!(series.len(), 21);
+    assert_eq!(series[0].0, 0.0);
+    assert!((series[20].0 - 2.0).abs() < 1e-12);
+    for &(t, m) in &series {
+        assert!((m - series[0].1).abs() < 1e-10, "t = {}: {} vs {}", t, m, series[0].1);
+    }
+
+    // The dynamics are not trivial: transverse magnetization does move.
+    let x = evolve_and_record(&mut psi, &h, 0.1, 20, trunc, |psi| expect_x(psi, 2));
+    assert!((x[20].1 - x[0].1).abs() > 1e-3);
+}
@@ -0,0 +1,206 @@
+//! Random brickwork circuits and CLI helpers shared by the sweep binaries.
+
+use quantum::{
+    apply_cnot,
+    gates::rx,
+    noise::{depolarizing_1q, depolarizing_2q},
+};
+use rng::ONDRng;
+use tn::{
+    mps::{C64, MPS},
+    truncation::Truncation,
+};
+
+/// Angles of one random two-qubit block on bond (k, k+1): Rz·Rx·Rz on each site, then CNOT.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GateParams {
+    pub k: usize,
+    pub a0: f64,
+    pub b0: f64,
+    pub c0: f64,
+    pub a1: f64,
+    pub b1: f64,
+    pub c1: f64,
+}
+
+pub fn rz(theta: f64) -> [[C64; 2]; 2] {
+    let c = (theta / 2.0).cos();
+    let s = (theta / 2.0).sin();
+    let z = C64::new(0.0, 0.0);
+    [[C64::new(c, -s), z], [z, C64::new(c, s)]]
+}
+
+/// Uniform angle in [0, 2π), its turn fraction rounded down to `bits` binary digits when given.
+pub fn rand_angle(rng: &mut ONDRng, ctx: &[u8], bits: Option<u32>) -> f64 {
+    let u = rng.next_f64(ctx);
+    let u = match bits {
+        Some(b) => quantize_turn(u, b),
+        None => u,
+    };
+    u * 2.0 * std::f64::consts::PI
+}
+
+/// Round a fraction of a turn in [0, 1) down to a multiple of 2^-bits.
+///
+/// For bits <= 52 the result is exact in f64, so every angle is an exact binary fraction
+/// of a turn that another tool can reproduce from the seed without rounding.
+pub fn quantize_turn(u: f64, bits: u32) -> f64 {
+    let scale = 2f64.powi(bits.min(52) as i32);
+    (u * scale).floor() / scale
+}
+
+fn draw_gate_params(k: usize, rng: &mut ONDRng, bits: Option<u32>) -> GateParams {
+    GateParams {
+        k,
+        a0: rand_angle(rng, b"RZ0", bits),
+        b0: rand_angle(rng, b"RX0", bits),
+        c0: rand_angle(rng, b"RZ1", bits),
+        a1: rand_angle(rng, b"RZ2", bits),
+        b1: rand_angle(rng, b"RX1", bits),
+        c1: rand_angle(rng, b"RZ3", bits),
+    }
+}
+
+/// Angles for one brickwork layer: even bonds first, then odd bonds, quantized to `bits`
+/// when given (see `rand_angle`).
+pub fn build_layer_params(n: usize, rng: &mut ONDRng, bits: Option<u32>) -> Vec<GateParams> {
+    let mut layer = Vec::with_capacity(n);
+    for start in [0usize, 1usize] {
+        let mut i = start;
+        while i + 1 < n {
+            layer.push(draw_gate_params(i, rng, bits));
+            i += 2;
+        }
+    }
+    layer
+}
+
+/// Apply one block; returns true when its CNOT saturated `max_bond`.
+pub fn apply_gate_params(psi: &mut MPS, trunc: Truncation, gate: GateParams) -> bool {
+    psi.apply_1q(gate.k, rz(gate.a0));
+    psi.apply_1q(gate.k, rx(gate.b0));
+    psi.apply_1q(gate.k, rz(gate.c0));
+    psi.apply_1q(gate.k + 1, rz(gate.a1));
+    psi.apply_1q(gate.k + 1, rx(gate.b1));
+    psi.apply_1q(gate.k + 1, rz(gate.c1));
+
+    apply_cnot(psi, gate.k, trunc)
+}
+
+/// Apply a whole layer; returns true when any gate saturated `max_bond`.
+pub fn apply_layer_params(psi: &mut MPS, trunc: Truncation, layer: &[GateParams]) -> bool {
+    let mut saturated = false;
+    for gate in layer {
+        saturated |= apply_gate_params(psi, trunc, *gate);
+    }
+    saturated
+}
+
+/// Depolarizing noise applied after each two-qubit block of a sweep circuit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SweepNoise {
+    /// `depolarizing_1q` with probability p on both sites of the block.
+    OneQubit(f64),
+    /// `depolarizing_2q` with probability p after the CNOT and no single-qubit noise,
+    /// as on hardware where two-qubit error rates dominate.
+    TwoQubitOnly(f64),
+}
+
+impl SweepNoise {
+    /// Noise model from the sweep CLI flags; None when `p` is 0 and runs stay noiseless.
+    pub fn from_flags(p: f64, two_qubit_only: bool) -> Option<Self> {
+        if p <= 0.0 {
+            None
+        } else if two_qubit_only {
+            Some(SweepNoise::TwoQubitOnly(p))
+        } else {
+            Some(SweepNoise::OneQubit(p))
+        }
+    }
+
+    /// Apply the channel to the block on bond (k, k+1).
+    pub fn apply(self, psi: &mut MPS, k: usize, rng: &mut ONDRng) {
+        match self {
+            SweepNoise::OneQubit(p) => {
+                depolarizing_1q(psi, k, p, rng);
+                depolarizing_1q(psi, k + 1, p, rng);
+            }
+            SweepNoise::TwoQubitOnly(p) => depolarizing_2q(psi, k, p, rng),
+        }
+    }
+}
+
+/// `apply_layer_params` with `noise` after every block, drawn from `noise_rng`.
+pub fn apply_noisy_layer_params(
+    psi: &mut MPS,
+    trunc: Truncation,
+    layer: &[GateParams],
+    noise: SweepNoise,
+    noise_rng: &mut ONDRng,
+) -> bool {
+    let mut saturated = false;
+    for gate in layer {
+        saturated |= apply_gate_params(psi, trunc, *gate);
+        noise.apply(psi, gate.k, noise_rng);
+    }
+    saturated
+}
+
+/// Draw and apply one random two-qubit block on bond (k, k+1).
+pub fn apply_random_2q(psi: &mut MPS, k: usize, trunc: Truncation, rng: &mut ONDRng) -> bool {
+    let gate = draw_gate_params(k, rng, None);
+    apply_gate_params(psi, trunc, gate)
+}
+
+/// Draw and apply one brickwork layer; returns true when any gate saturated `max_bond`.
+pub fn apply_brickwork_layer(
+    psi: &mut MPS,
+    trunc: Truncation,
+    rng: &mut ONDRng,
+    bits: Option<u32>,
+) -> bool {
+    let layer = build_layer_params(psi.sites().len(), rng, bits);
+    apply_layer_params(psi, trunc, &layer)
+}
+
+/// `depth` brickwork layers on |0...0> with a fresh RNG seeded by `seed`.
+pub fn build_state(
+    n: usize,
+    depth: usize,
+    trunc: Truncation,
+    seed: &str,
+    bits: Option<u32>,
+) -> MPS {
+    let mut rng = ONDRng::new(seed.as_bytes());
+    let mut psi = MPS::new_zero(n);
+
+    for _ in 0..depth {
+        apply_brickwork_layer(&mut psi, trunc, &mut rng, bits);
+    }
+
+    psi
+}
+
+/// Largest bond dimension anywhere in the chain (1 for an empty chain).
+pub fn chi_max(psi: &MPS) -> usize {
+    psi.sites()
+        .iter()
+        .map(|s| s.dl.max(s.dr))
+        .max()
+        .unwrap_or(1)
+}
+
+/// Comma-separated integers; blank and unparsable entries are skipped.
+pub fn parse_list(input: &str) -> Vec<usize> {
+    input
+        .split(',')
+        .filter_map(|s| {
+            let t = s.trim();
+            if t.is_empty() {
+                None
+            } else {
+                t.parse::<usize>().ok()
+            }
+        })
+        .collect()
+}
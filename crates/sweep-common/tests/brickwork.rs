@@ -0,0 +1,169 @@
+use quantum::{apply_cnot, observables::expect_z};
+use rng::ONDRng;
+use sweep_common::{
+    apply_brickwork_layer, apply_gate_params, apply_layer_params, apply_noisy_layer_params,
+    apply_random_2q, build_layer_params, build_state, chi_max, parse_list,
+    quantize_turn, rand_angle, rz, SweepNoise,
+};
+use tn::{
+    mps::{overlap, C64, MPS},
+    truncation::Truncation,
+};
+
+fn trunc() -> Truncation {
+    Truncation::builder().max_bond(64).cutoff(1e-12).build()
+}
+
+#[test]
+fn rz_is_a_diagonal_phase_rotation() {
+    let u = rz(std::f64::consts::PI);
+    assert!((u[0][0] - C64::new(0.0, -1.0)).norm() < 1e-12);
+    assert!((u[1][1] - C64::new(0.0, 1.0)).norm() < 1e-12);
+    assert_eq!(u[0][1], C64::new(0.0, 0.0));
+    assert_eq!(u[1][0], C64::new(0.0, 0.0));
+
+    let u = rz(0.7);
+    for row in u {
+        let norm: f64 = row.iter().map(|x| x.norm_sqr()).sum();
+        assert!((norm - 1.0).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn rand_angle_is_in_range_and_deterministic() {
+    let mut a = ONDRng::new(b"angles");
+    let mut b = ONDRng::new(b"angles");
+    for _ in 0..100 {
+        let x = rand_angle(&mut a, b"RZ0", None);
+        assert!((0.0..2.0 * std::f64::consts::PI).contains(&x));
+        assert_eq!(x, rand_angle(&mut b, b"RZ0", None));
+    }
+}
+
+#[test]
+fn layer_covers_even_then_odd_bonds() {
+    let mut rng = ONDRng::new(b"layer");
+    let layer = build_layer_params(6, &mut rng, None);
+    let bonds: Vec<usize> = layer.iter().map(|g| g.k).collect();
+    assert_eq!(bonds, vec![0, 2, 4, 1, 3]);
+
+    let mut rng = ONDRng::new(b"layer");
+    assert_eq!(build_layer_params(6, &mut rng, None), layer);
+
+    let mut rng = ONDRng::new(b"layer");
+    assert!(build_layer_params(1, &mut rng, None).is_empty());
+}
+
+#[test]
+fn random_2q_matches_drawn_params() {
+    let mut rng = ONDRng::new(b"block");
+    let mut direct = MPS::new_zero(3);
+    apply_random_2q(&mut direct, 1, trunc(), &mut rng);
+
+    let mut rng = ONDRng::new(b"block");
+    let gate = build_layer_params(2, &mut rng, None)[0];
+    let mut via_params = MPS::new_zero(3);
+    apply_gate_params(&mut via_params, trunc(), sweep_common::GateParams { k: 1, ..gate });
+
+    assert!((overlap(&direct, &via_params).norm() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn brickwork_layer_equals_params_applied() {
+    let mut rng = ONDRng::new(b"bw");
+    let mut a = MPS::new_zero(5);
+    apply_brickwork_layer(&mut a, trunc(), &mut rng, None);
+
+    let mut rng = ONDRng::new(b"bw");
+    let layer = build_layer_params(5, &mut rng, None);
+    let mut b = MPS::new_zero(5);
+    apply_layer_params(&mut b, trunc(), &layer);
+
+    assert!((overlap(&a, &b) - C64::new(1.0, 0.0)).norm() < 1e-12);
+}
+
+#[test]
+fn build_state_is_normalized_and_entangled() {
+    let psi = build_state(6, 4, trunc(), "state", None);
+    assert!((overlap(&psi, &psi).re - 1.0).abs() < 1e-10);
+    assert!(chi_max(&psi) > 1);
+
+    let again = build_state(6, 4, trunc(), "state", None);
+    assert!((overlap(&psi, &again).norm() - 1.0).abs() < 1e-10);
+}
+
+#[test]
+fn chi_max_tracks_largest_bond() {
+    assert_eq!(chi_max(&MPS::new_zero(0)), 1);
+    assert_eq!(chi_max(&MPS::new_zero(4)), 1);
+
+    let mut psi = MPS::new_zero(2);
+    psi.apply_1q(0, quantum::gates::hadamard());
+    apply_cnot(&mut psi, 0, trunc());
+    assert_eq!(chi_max(&psi), 2);
+}
+
+#[test]
+fn parse_list_skips_blank_and_invalid_entries() {
+    assert_eq!(parse_list("8,16,32"), vec![8, 16, 32]);
+    assert_eq!(parse_list(" 4 , ,x, 12,"), vec![4, 12]);
+    assert!(parse_list("").is_empty());
+}
+
+#[test]
+fn quantized_angles_are_exact_turn_fractions() {
+    let mut rng = ONDRng::new(b"angle-bits");
+    let layer = build_layer_params(6, &mut rng, Some(12));
+    let turn = 2.0 * std::f64::consts::PI;
+    for g in &layer {
+        for a in [g.a0, g.b0, g.c0, g.a1, g.b1, g.c1] {
+            let steps = a / turn * 4096.0;
+            assert!((steps - steps.round()).abs() < 1e-9, "angle {} is not on the grid", a);
+            assert!((0.0..turn).contains(&a));
+        }
+    }
+
+    assert_eq!(quantize_turn(0.75, 1), 0.5);
+    assert_eq!(quantize_turn(0.3, 8), 76.0 / 256.0);
+
+    let full = build_layer_params(6, &mut ONDRng::new(b"x"), None);
+    assert_eq!(full, build_layer_params(6, &mut ONDRng::new(b"x"), None));
+}
+
+/// Five seeded brickwork layers on six sites, with `noise` after each block when given.
+fn noisy_run(noise: Option<SweepNoise>) -> MPS {
+    let mut rng = ONDRng::new(b"sweep-noise");
+    let mut noise_rng = ONDRng::new(b"sweep-noise-draws");
+    let mut psi = MPS::new_zero(6);
+    for _ in 0..5 {
+        let layer = build_layer_params(6, &mut rng, None);
+        match noise {
+            Some(noise) => apply_noisy_layer_params(&mut psi, trunc(), &layer, noise, &mut noise_rng),
+            None => apply_layer_params(&mut psi, trunc(), &layer),
+        };
+    }
+    psi
+}
+
+#[test]
+fn two_qubit_only_noise_is_exact_at_zero_and_perturbs_above() {
+    assert_eq!(SweepNoise::from_flags(0.0, true), None);
+    assert_eq!(SweepNoise::from_flags(0.1, true), Some(SweepNoise::TwoQubitOnly(0.1)));
+    assert_eq!(SweepNoise::from_flags(0.1, false), Some(SweepNoise::OneQubit(0.1)));
+
+    let clean = noisy_run(None);
+    let zero = noisy_run(Some(SweepNoise::TwoQubitOnly(0.0)));
+    for (a, b) in clean.sites().iter().zip(zero.sites()) {
+        assert_eq!(a.data, b.data);
+    }
+
+    let noisy = noisy_run(Some(SweepNoise::TwoQubitOnly(0.5)));
+    assert!((overlap(&noisy, &clean).norm() - 1.0).abs() > 1e-3);
+    assert!((0..6).any(|k| (expect_z(&noisy, k) - expect_z(&clean, k)).abs() > 1e-3));
+
+    // Pauli kicks are local, so the bond dimensions of this saturated circuit agree; the
+    // spectrum the later gates build on them does not.
+    let bonds = |psi: &MPS| psi.sites().iter().map(|s| s.dr).collect::<Vec<_>>();
+    assert_eq!(bonds(&noisy), bonds(&clean));
+    assert!((noisy.entanglement_entropy(3) - clean.entanglement_entropy(3)).abs() > 1e-3);
+}
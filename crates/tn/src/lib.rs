@@ -1,2 +1,25 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+/// Evaluate `$e`, charging its wall time to `TimingReport::$field` when the `profiling`
+/// feature is on; a plain expression otherwise.
+macro_rules! timed {
+    ($field:ident, $e:expr) => {{
+        #[cfg(feature = "profiling")]
+        let start = std::time::Instant::now();
+        let out = $e;
+        #[cfg(feature = "profiling")]
+        crate::profiling::charge(|r| r.$field += start.elapsed());
+        out
+    }};
+}
+
+#[cfg(feature = "std")]
 pub mod mps;
+#[cfg(feature = "profiling")]
+pub mod profiling;
+#[cfg(feature = "std")]
+pub mod svd;
+pub mod tensor;
 pub mod truncation;
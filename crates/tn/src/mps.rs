@@ -1,77 +1,324 @@
+use crate::svd::{randomized_svd, thin_svd, SvdBackend, SvdFactors};
 use crate::truncation::Truncation;
-use faer::Mat;
-use num_complex::Complex64;
+use faer::{Mat, MatRef};
+use rng::ONDRng;
+use std::io::{self, Read, Write};
 
-pub type C64 = Complex64;
+/// Leading bytes of a `MPS::save` stream.
+const SAVE_MAGIC: &[u8; 8] = b"QSIMMPS1";
 
-#[derive(Clone)]
-pub struct Tensor3 {
-    pub data: Vec<C64>,
-    pub dl: usize,
-    pub dp: usize,
-    pub dr: usize,
+pub use crate::tensor::{bits_to_index, index_to_bits, Boundary, Tensor3, C64, MPS};
+
+/// Drop threshold for spectrum `s`: the user cutoff or the relative floor, whichever is larger.
+fn drop_threshold(s: &[f64], trunc: Truncation) -> f64 {
+    trunc.threshold(s.iter().copied().fold(0.0, f64::max))
 }
 
-impl Tensor3 {
-    pub fn zeros(dl: usize, dp: usize, dr: usize) -> Self {
-        Self {
-            data: vec![C64::new(0.0, 0.0); dl * dp * dr],
-            dl,
-            dp,
-            dr,
+fn kept_bond(s: &[f64], trunc: Truncation) -> usize {
+    let threshold = drop_threshold(s, trunc);
+    let mut kept = 0;
+    for &sv in s {
+        if sv > threshold && kept < trunc.max_bond {
+            kept += 1;
         }
     }
+    if kept == 0 {
+        kept = 1;
+    }
+    kept
+}
 
-    #[inline]
-    fn idx(&self, l: usize, p: usize, r: usize) -> usize {
-        (l * self.dp + p) * self.dr + r
+/// Unit factor that makes column m's largest-magnitude entry real and positive.
+///
+/// Applied to u_m and v_m together, it leaves U S V^H unchanged but removes the phase
+/// freedom SVD drivers resolve differently, so the written tensors do not depend on the
+/// backend or platform. Near-ties for the largest entry go to the first row, and
+/// degenerate singular values can still mix their vectors.
+fn column_phase(u: MatRef<'_, C64>, m: usize) -> C64 {
+    let max = (0..u.nrows()).map(|i| u.read(i, m).norm()).fold(0.0, f64::max);
+    if max == 0.0 {
+        return C64::new(1.0, 0.0);
     }
+    let i = (0..u.nrows())
+        .find(|&i| u.read(i, m).norm() >= max * (1.0 - 1e-10))
+        .unwrap_or(0);
+    let pivot = u.read(i, m);
+    pivot.conj() / pivot.norm()
+}
+
+/// True when more than `max_bond` singular values clear the cutoff, i.e. the cap bound.
+fn saturates(s: &[f64], trunc: Truncation) -> bool {
+    let threshold = drop_threshold(s, trunc);
+    s.iter().filter(|&&sv| sv > threshold).count() > trunc.max_bond
+}
+
+/// Overlap ⟨a|b⟩ by left-to-right transfer-matrix contraction; 1 for two empty chains.
+///
+/// Periodic chains are traced over their boundary bond: the sweep is run once per pair
+/// of boundary indices and closed on the same pair.
+pub fn overlap(a: &MPS, b: &MPS) -> C64 {
+    assert_eq!(a.sites.len(), b.sites.len(), "MPS length mismatch");
+    let (Some(sa), Some(sb)) = (a.sites.first(), b.sites.first()) else {
+        return C64::new(1.0, 0.0);
+    };
+    // Open chains start from index 0 only, as a dimension-1 boundary would.
+    let da = if a.is_periodic() { sa.dl } else { 1 };
+    let db = if b.is_periodic() { sb.dl } else { 1 };
+
+    let mut total = C64::new(0.0, 0.0);
+    for alpha in 0..da {
+        for beta in 0..db {
+            let mut env = vec![C64::new(0.0, 0.0); sa.dl * sb.dl];
+            env[alpha * sb.dl + beta] = C64::new(1.0, 0.0);
+
+            for (sa, sb) in a.sites.iter().zip(b.sites.iter()) {
+                let mut next = vec![C64::new(0.0, 0.0); sa.dr * sb.dr];
+                for la in 0..sa.dl {
+                    for lb in 0..sb.dl {
+                        let env_val = env[la * sb.dl + lb];
+                        if env_val == C64::new(0.0, 0.0) {
+                            continue;
+                        }
+                        for ra in 0..sa.dr {
+                            for rb in 0..sb.dr {
+                                let mut acc = C64::new(0.0, 0.0);
+                                for p in 0..sa.dp {
+                                    acc += sa.get(la, p, ra).conj() * sb.get(lb, p, rb);
+                                }
+                                next[ra * sb.dr + rb] += env_val * acc;
+                            }
+                        }
+                    }
+                }
+                env = next;
+            }
 
-    pub fn get(&self, l: usize, p: usize, r: usize) -> C64 {
-        self.data[self.idx(l, p, r)]
+            // Open chains end on a 1×1 env; rings close on the pair they started from.
+            let db_end = b.sites[b.sites.len() - 1].dr;
+            total += match (a.is_periodic(), b.is_periodic()) {
+                (false, false) => env.into_iter().fold(C64::new(0.0, 0.0), |a, b| a + b),
+                (pa, pb) => {
+                    let ra = if pa { alpha } else { 0 };
+                    let rb = if pb { beta } else { 0 };
+                    env[ra * db_end + rb]
+                }
+            };
+        }
     }
+    total
+}
 
-    pub fn set(&mut self, l: usize, p: usize, r: usize, v: C64) {
-        let i = self.idx(l, p, r);
-        self.data[i] = v;
+/// Normalized state fidelity |⟨a|b⟩|² / (⟨a|a⟩⟨b|b⟩); 0 if either state vanishes.
+pub fn fidelity(a: &MPS, b: &MPS) -> f64 {
+    let norm = overlap(a, a).re * overlap(b, b).re;
+    if norm == 0.0 {
+        return 0.0;
     }
+    overlap(a, b).norm_sqr() / norm
 }
 
-#[derive(Clone)]
-pub struct MPS {
-    pub sites: Vec<Tensor3>,
+/// Relative phase arg⟨a|b⟩ in (-π, π]; meaningless (0) when the states are orthogonal.
+pub fn overlap_phase(a: &MPS, b: &MPS) -> f64 {
+    overlap(a, b).arg()
+}
+
+/// Return amplitude G(t) = ⟨ψ(0)|ψ(t)⟩ for quench dynamics; same as `overlap(initial, current)`.
+///
+/// The Loschmidt rate function is λ(t) = -ln|G(t)|² / n for an n-site chain.
+pub fn return_amplitude(initial: &MPS, current: &MPS) -> C64 {
+    overlap(initial, current)
+}
+
+fn read_u64<R: Read>(r: &mut R) -> io::Result<u64> {
+    let mut buf = [0u8; 8];
+    r.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+fn read_len<R: Read>(r: &mut R) -> io::Result<usize> {
+    usize::try_from(read_u64(r)?)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "length overflows usize"))
+}
+
+/// Largest local dimension `load` accepts.
+const MAX_LOAD_DP: usize = 16;
+
+/// Entries read before the buffer grows to the size a header claims, so a corrupt header
+/// cannot allocate more than the file actually holds.
+const LOAD_CHUNK: usize = 1 << 16;
+
+/// Max deviation of the isometry Gram matrix from the identity, contracting `l, p` (left)
+/// or `p, r` (right).
+fn isometry_error(t: &Tensor3, left: bool) -> f64 {
+    let d = if left { t.dr } else { t.dl };
+    let mut err = 0.0f64;
+    for a in 0..d {
+        for b in 0..d {
+            let mut acc = C64::new(0.0, 0.0);
+            for p in 0..t.dp {
+                for o in 0..if left { t.dl } else { t.dr } {
+                    acc += if left {
+                        t.get(o, p, a).conj() * t.get(o, p, b)
+                    } else {
+                        t.get(a, p, o) * t.get(b, p, o).conj()
+                    };
+                }
+            }
+            let expected = if a == b { 1.0 } else { 0.0 };
+            err = err.max((acc - expected).norm());
+        }
+    }
+    err
 }
 
 impl MPS {
-    pub fn new_zero(n: usize) -> Self {
-        let mut sites = Vec::with_capacity(n);
-        for _ in 0..n {
-            let mut t = Tensor3::zeros(1, 2, 1);
-            t.set(0, 0, 0, C64::new(1.0, 0.0));
-            sites.push(t);
+    /// Write the chain in a little-endian binary format that `load` reads back bit for bit.
+    ///
+    /// Layout: magic, site count, orthogonality center (u64::MAX for none), then per site
+    /// dl, dp, dr and the data as (re, im) f64 pairs.
+    pub fn save<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        if self.is_periodic() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "save: periodic MPS are not supported",
+            ));
         }
-        Self { sites }
+        w.write_all(SAVE_MAGIC)?;
+        w.write_all(&(self.sites.len() as u64).to_le_bytes())?;
+        let center = self.center.map_or(u64::MAX, |c| c as u64);
+        w.write_all(&center.to_le_bytes())?;
+        for t in &self.sites {
+            for d in [t.dl, t.dp, t.dr] {
+                w.write_all(&(d as u64).to_le_bytes())?;
+            }
+            for v in &t.data {
+                w.write_all(&v.re.to_le_bytes())?;
+                w.write_all(&v.im.to_le_bytes())?;
+            }
+        }
+        Ok(())
     }
 
-    pub fn apply_1q(&mut self, k: usize, u: [[C64; 2]; 2]) {
-        let s = &self.sites[k];
-        let mut out = Tensor3::zeros(s.dl, s.dp, s.dr);
+    /// Read a chain written by `save`; InvalidData on a bad magic or inconsistent shapes.
+    ///
+    /// The stored orthogonality center is kept only if the tensors really are isometries
+    /// around it, so a hand-edited file cannot feed the center fast paths bad data.
+    pub fn load<R: Read>(r: &mut R) -> io::Result<MPS> {
+        let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_string());
 
-        for l in 0..s.dl {
-            for r in 0..s.dr {
-                for p in 0..2 {
-                    let mut acc = C64::new(0.0, 0.0);
-                    for pp in 0..2 {
-                        acc += u[p][pp] * s.get(l, pp, r);
-                    }
-                    out.set(l, p, r, acc);
-                }
+        let mut magic = [0u8; 8];
+        r.read_exact(&mut magic)?;
+        if &magic != SAVE_MAGIC {
+            return Err(invalid("not an MPS checkpoint"));
+        }
+
+        let n = read_len(r)?;
+        let center = match read_u64(r)? {
+            u64::MAX => None,
+            c if (c as usize) < n => Some(c as usize),
+            _ => return Err(invalid("orthogonality center out of range")),
+        };
+
+        let mut sites: Vec<Tensor3> = Vec::new();
+        for k in 0..n {
+            let (dl, dp, dr) = (read_len(r)?, read_len(r)?, read_len(r)?);
+            let prev_dr = sites.last().map_or(1, |t| t.dr);
+            if dl != prev_dr || (k + 1 == n && dr != 1) {
+                return Err(invalid("bond dimensions do not match between sites"));
+            }
+            if !(1..=MAX_LOAD_DP).contains(&dp) {
+                return Err(invalid("physical dimension out of range"));
+            }
+            let len = dl
+                .checked_mul(dp)
+                .and_then(|x| x.checked_mul(dr))
+                .filter(|len| len.checked_mul(core::mem::size_of::<C64>()).is_some())
+                .ok_or_else(|| invalid("tensor size overflows"))?;
+
+            let mut data = Vec::with_capacity(len.min(LOAD_CHUNK));
+            for _ in 0..len {
+                let re = f64::from_bits(read_u64(r)?);
+                let im = f64::from_bits(read_u64(r)?);
+                data.push(C64::new(re, im));
+            }
+            sites.push(Tensor3 { data, dl, dp, dr });
+        }
+
+        let mut psi = MPS::from_sites(sites);
+        psi.center = center.filter(|&c| {
+            psi.sites[..c].iter().all(|t| isometry_error(t, true) < 1e-10)
+                && psi.sites[c + 1..].iter().all(|t| isometry_error(t, false) < 1e-10)
+        });
+        Ok(psi)
+    }
+
+    /// `apply_2q_svd` with the gate as row-major `[re, im, ...]` pairs, for interop callers.
+    pub fn apply_2q_flat(&mut self, k: usize, m: &[f64; 32], trunc: Truncation) -> bool {
+        let mut u = [[C64::new(0.0, 0.0); 4]; 4];
+        for (i, row) in u.iter_mut().enumerate() {
+            for (j, v) in row.iter_mut().enumerate() {
+                let idx = 2 * (i * 4 + j);
+                *v = C64::new(m[idx], m[idx + 1]);
             }
         }
-        self.sites[k] = out;
+        self.apply_2q_svd(k, u, trunc)
+    }
+
+    /// `gate` on every bond (i, i+1) with i = offset, offset + 2, ...; offset 0 is the
+    /// even half of a brickwork layer, 1 the odd half. Returns true if any gate saturated.
+    pub fn apply_2q_layer(&mut self, gate: [[C64; 4]; 4], offset: usize, trunc: Truncation) -> bool {
+        let mut saturated = false;
+        let mut i = offset;
+        while i + 1 < self.sites.len() {
+            saturated |= self.apply_2q_svd(i, gate, trunc);
+            i += 2;
+        }
+        saturated
+    }
+
+    /// Apply a two-site gate on (k, k+1) and re-split with truncation.
+    ///
+    /// Returns true when `max_bond` was saturated: more singular values cleared the
+    /// cutoff than the cap allowed, so the result is truncation-dominated.
+    pub fn apply_2q_svd(&mut self, k: usize, u: [[C64; 4]; 4], trunc: Truncation) -> bool {
+        self.apply_2q_svd_with(k, u, trunc, SvdBackend::Faer)
+    }
+
+    /// `apply_2q_svd` with an explicit choice of SVD routine for the theta matrix.
+    pub fn apply_2q_svd_with(
+        &mut self,
+        k: usize,
+        u: [[C64; 4]; 4],
+        trunc: Truncation,
+        backend: SvdBackend,
+    ) -> bool {
+        self.assert_bond(k, "apply_2q_svd");
+        let theta = timed!(theta, self.two_site_theta(k, u));
+        let svd = timed!(svd, thin_svd(&theta, backend));
+        timed!(split, self.split_two_site(k, &svd, trunc))
+    }
+
+    /// `apply_2q_svd` with a randomized rank-`max_bond` factorization of theta.
+    ///
+    /// Only pays off when `max_bond` is well below the theta dimension (chi >= 64);
+    /// the discarded weight is at most a few times the exact truncation error, and
+    /// `power_iters` (1-2 is typical) tightens it when the spectrum decays slowly.
+    pub fn apply_2q_rsvd(
+        &mut self,
+        k: usize,
+        u: [[C64; 4]; 4],
+        trunc: Truncation,
+        power_iters: usize,
+        rng: &mut ONDRng,
+    ) -> bool {
+        self.assert_bond(k, "apply_2q_rsvd");
+        let theta = timed!(theta, self.two_site_theta(k, u));
+        let svd = timed!(svd, randomized_svd(&theta, trunc.max_bond, power_iters, rng));
+        timed!(split, self.split_two_site(k, &svd, trunc))
     }
 
-    pub fn apply_2q_svd(&mut self, k: usize, u: [[C64; 4]; 4], trunc: Truncation) {
+    /// Gate applied to the contracted pair (k, k+1), reshaped to (dl·2) × (2·dr).
+    fn two_site_theta(&self, k: usize, u: [[C64; 4]; 4]) -> Mat<C64> {
         let a = &self.sites[k];
         let b = &self.sites[k + 1];
 
@@ -103,51 +350,282 @@ impl MPS {
                 }
             }
         }
+        theta
+    }
 
-        let svd = theta.thin_svd();
-        let s = svd.s_diagonal();
-
-        let mut kept = 0;
-        for i in 0..s.nrows() {
-            let sv = s.read(i).re;
-            if sv > trunc.cutoff && kept < trunc.max_bond {
-                kept += 1;
-            }
-        }
-        if kept == 0 {
-            kept = 1;
-        }
+    /// Truncate the factorized theta and write U·S into site k and V^H into site k+1.
+    ///
+    /// Returns whether the truncation hit `max_bond`.
+    fn split_two_site(&mut self, k: usize, svd: &SvdFactors, trunc: Truncation) -> bool {
+        #[cfg(feature = "profiling")]
+        crate::profiling::charge(|r| r.apply_2q_calls += 1);
+        let dl = self.sites[k].dl;
+        let dr = self.sites[k + 1].dr;
+        let kept = kept_bond(&svd.s, trunc);
 
-        let u_full = svd.u();
-        let v_full = svd.v();
-        let u_mat = u_full.submatrix(0, 0, u_full.nrows(), kept);
-        let v_mat = v_full.submatrix(0, 0, v_full.nrows(), kept);
-        let mut s_vals = Vec::with_capacity(kept);
-        for i in 0..kept {
-            s_vals.push(s.read(i).re);
-        }
+        let u_mat = svd.u.submatrix(0, 0, svd.u.nrows(), kept);
+        let v_mat = svd.v.submatrix(0, 0, svd.v.nrows(), kept);
+        let s_vals = &svd.s[..kept];
+        let phases: Vec<C64> = (0..kept).map(|m| column_phase(u_mat, m)).collect();
 
-        let mut new_a = Tensor3::zeros(dl, 2, kept);
+        // Theta holds everything we need, so overwrite the old tensors in place.
+        let new_a = &mut self.sites[k];
+        new_a.reset(dl, 2, kept);
         for l in 0..dl {
             for p in 0..2 {
                 for m in 0..kept {
-                    let u_val = u_mat.read(l * 2 + p, m);
+                    let u_val = u_mat.read(l * 2 + p, m) * phases[m];
                     new_a.set(l, p, m, u_val * s_vals[m]);
                 }
             }
         }
 
-        let mut new_b = Tensor3::zeros(kept, 2, dr);
-        for m in 0..kept {
+        let new_b = &mut self.sites[k + 1];
+        new_b.reset(kept, 2, dr);
+        for (m, &ph) in phases.iter().enumerate() {
             for p in 0..2 {
                 for r in 0..dr {
-                    let v_val = v_mat.read(p * dr + r, m).conj();
+                    let v_val = (v_mat.read(p * dr + r, m) * ph).conj();
                     new_b.set(m, p, r, v_val);
                 }
             }
         }
 
+        self.center = match self.center {
+            Some(c) if c == k || c == k + 1 => Some(k),
+            _ => None,
+        };
+        saturates(&svd.s, trunc)
+    }
+
+    /// Left-orthogonalize site k via QR and absorb R into site k+1 (no truncation).
+    pub fn qr_left(&mut self, k: usize) {
+        assert!(k + 1 < self.sites.len(), "qr_left needs a right neighbor");
+
+        let a = &self.sites[k];
+        let (dl, dp, dr) = (a.dl, a.dp, a.dr);
+
+        let mut m = Mat::<C64>::zeros(dl * dp, dr);
+        for l in 0..dl {
+            for p in 0..dp {
+                for r in 0..dr {
+                    m.write(l * dp + p, r, a.get(l, p, r));
+                }
+            }
+        }
+
+        let qr = m.qr();
+        let q = qr.compute_thin_q();
+        let r_mat = qr.compute_thin_r();
+        let kept = q.ncols();
+
+        let mut new_a = Tensor3::zeros(dl, dp, kept);
+        for l in 0..dl {
+            for p in 0..dp {
+                for j in 0..kept {
+                    new_a.set(l, p, j, q.read(l * dp + p, j));
+                }
+            }
+        }
+
+        let b = &self.sites[k + 1];
+        let mut new_b = Tensor3::zeros(kept, b.dp, b.dr);
+        for j in 0..kept {
+            for m in 0..b.dl {
+                let rv = r_mat.read(j, m);
+                if rv == C64::new(0.0, 0.0) {
+                    continue;
+                }
+                for p in 0..b.dp {
+                    for r in 0..b.dr {
+                        let cur = new_b.get(j, p, r);
+                        new_b.set(j, p, r, cur + rv * b.get(m, p, r));
+                    }
+                }
+            }
+        }
+
         self.sites[k] = new_a;
         self.sites[k + 1] = new_b;
+        self.center = (self.center == Some(k)).then_some(k + 1);
+    }
+
+    /// Right-orthogonalize site k via QR and absorb the factor into site k-1 (no truncation).
+    pub fn qr_right(&mut self, k: usize) {
+        assert!(k > 0 && k < self.sites.len(), "qr_right needs a left neighbor");
+
+        let a = &self.sites[k];
+        let (dl, dp, dr) = (a.dl, a.dp, a.dr);
+
+        // QR of the adjoint: A = R^H Q^H with Q^H having orthonormal rows.
+        let mut m = Mat::<C64>::zeros(dp * dr, dl);
+        for l in 0..dl {
+            for p in 0..dp {
+                for r in 0..dr {
+                    m.write(p * dr + r, l, a.get(l, p, r).conj());
+                }
+            }
+        }
+
+        let qr = m.qr();
+        let q = qr.compute_thin_q();
+        let r_mat = qr.compute_thin_r();
+        let kept = q.ncols();
+
+        let mut new_a = Tensor3::zeros(kept, dp, dr);
+        for j in 0..kept {
+            for p in 0..dp {
+                for r in 0..dr {
+                    new_a.set(j, p, r, q.read(p * dr + r, j).conj());
+                }
+            }
+        }
+
+        let b = &self.sites[k - 1];
+        let mut new_b = Tensor3::zeros(b.dl, b.dp, kept);
+        for l in 0..b.dl {
+            for p in 0..b.dp {
+                for j in 0..kept {
+                    let mut acc = C64::new(0.0, 0.0);
+                    for m in 0..b.dr {
+                        acc += b.get(l, p, m) * r_mat.read(j, m).conj();
+                    }
+                    new_b.set(l, p, j, acc);
+                }
+            }
+        }
+
+        self.sites[k] = new_a;
+        self.sites[k - 1] = new_b;
+        self.center = (self.center == Some(k)).then_some(k - 1);
+    }
+
+    /// Move the orthogonality center to site k with QR sweeps (no truncation).
+    ///
+    /// Starts from the current center when one is known, so shifting it by a few sites
+    /// only costs a few QRs.
+    pub fn canonicalize_at(&mut self, k: usize) {
+        let n = self.sites.len();
+        assert!(k < n, "canonicalize_at: site {} out of range for {} sites", k, n);
+        assert!(!self.is_periodic(), "canonicalize_at needs an open-boundary MPS");
+
+        let (from_left, from_right) = match self.center {
+            Some(c) => (c.min(k), c.max(k)),
+            None => (0, n - 1),
+        };
+        for i in from_left..k {
+            self.qr_left(i);
+        }
+        for i in (k + 1..=from_right).rev() {
+            self.qr_right(i);
+        }
+        self.center = Some(k);
+    }
+
+    /// Schmidt values across bond b (between sites b-1 and b), as returned by the SVD.
+    ///
+    /// There is one value per index of the bond, so `len()` is its bond dimension
+    /// unless the left block is smaller. Not normalized: their squares sum to ⟨ψ|ψ⟩.
+    pub fn schmidt_values(&self, b: usize) -> Vec<f64> {
+        let n = self.sites.len();
+        assert!(b > 0 && b < n, "bond must lie strictly inside the chain");
+
+        // With the orthogonality center on site b-1 its SVD gives the Schmidt values.
+        let mut psi = self.clone();
+        psi.canonicalize_at(b - 1);
+
+        let a = &psi.sites[b - 1];
+        let mut m = Mat::<C64>::zeros(a.dl * a.dp, a.dr);
+        for l in 0..a.dl {
+            for p in 0..a.dp {
+                for r in 0..a.dr {
+                    m.write(l * a.dp + p, r, a.get(l, p, r));
+                }
+            }
+        }
+
+        thin_svd(&m, SvdBackend::Faer).s
+    }
+
+    /// Von Neumann entropy -Σ p ln p of the Schmidt spectrum across bond b (between sites b-1 and b).
+    pub fn entanglement_entropy(&self, b: usize) -> f64 {
+        let weights: Vec<f64> = self.schmidt_values(b).iter().map(|s| s * s).collect();
+        let total: f64 = weights.iter().sum();
+        if total == 0.0 {
+            return 0.0;
+        }
+
+        let mut entropy = 0.0;
+        for w in weights {
+            let p = w / total;
+            if p > 0.0 {
+                entropy -= p * p.ln();
+            }
+        }
+        entropy
+    }
+
+    /// QR sweep left to right: sites 0..n-1 become left isometries and the norm
+    /// ends up on the last site. The state itself is unchanged.
+    pub fn left_canonicalize(&mut self) {
+        assert!(!self.is_periodic(), "left_canonicalize needs an open-boundary MPS");
+        for k in 0..self.sites.len().saturating_sub(1) {
+            self.qr_left(k);
+        }
+        self.center = self.sites.len().checked_sub(1);
+    }
+
+    /// Left-canonicalize with QR, then truncate every bond right-to-left with SVD.
+    pub fn compress(&mut self, trunc: Truncation) {
+        let n = self.sites.len();
+        if n < 2 {
+            return;
+        }
+
+        self.left_canonicalize();
+
+        for k in (1..n).rev() {
+            let a = &self.sites[k];
+            let (dl, dp, dr) = (a.dl, a.dp, a.dr);
+
+            let mut m = Mat::<C64>::zeros(dl, dp * dr);
+            for l in 0..dl {
+                for p in 0..dp {
+                    for r in 0..dr {
+                        m.write(l, p * dr + r, a.get(l, p, r));
+                    }
+                }
+            }
+
+            let SvdFactors { u, s: s_all, v } = thin_svd(&m, SvdBackend::Faer);
+            let kept = kept_bond(&s_all, trunc);
+
+            let mut new_a = Tensor3::zeros(kept, dp, dr);
+            for j in 0..kept {
+                for p in 0..dp {
+                    for r in 0..dr {
+                        new_a.set(j, p, r, v.read(p * dr + r, j).conj());
+                    }
+                }
+            }
+
+            let b = &self.sites[k - 1];
+            let mut new_b = Tensor3::zeros(b.dl, b.dp, kept);
+            for l in 0..b.dl {
+                for p in 0..b.dp {
+                    for (j, &sv) in s_all.iter().enumerate().take(kept) {
+                        let mut acc = C64::new(0.0, 0.0);
+                        for m in 0..b.dr {
+                            acc += b.get(l, p, m) * u.read(m, j);
+                        }
+                        new_b.set(l, p, j, acc * sv);
+                    }
+                }
+            }
+
+            self.sites[k] = new_a;
+            self.sites[k - 1] = new_b;
+        }
+        self.center = Some(0);
     }
 }
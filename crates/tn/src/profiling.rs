@@ -0,0 +1,53 @@
+//! Per-thread gate timers, compiled in with the `profiling` feature.
+
+use crate::tensor::MPS;
+use std::cell::Cell;
+use std::fmt;
+use std::time::Duration;
+
+/// Wall time spent in each phase of the gate paths on the current thread.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TimingReport {
+    pub apply_1q: Duration,
+    pub apply_1q_calls: u64,
+    /// Contracting the gate into the two-site theta matrix.
+    pub theta: Duration,
+    /// The SVD (or randomized SVD) of theta.
+    pub svd: Duration,
+    /// Truncating and writing the factors back into the two sites.
+    pub split: Duration,
+    pub apply_2q_calls: u64,
+}
+
+impl fmt::Display for TimingReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "apply_1q: {:?} over {} calls", self.apply_1q, self.apply_1q_calls)?;
+        writeln!(f, "apply_2q: {} calls", self.apply_2q_calls)?;
+        writeln!(f, "  theta:  {:?}", self.theta)?;
+        writeln!(f, "  svd:    {:?}", self.svd)?;
+        write!(f, "  split:  {:?}", self.split)
+    }
+}
+
+thread_local! {
+    static TIMING: Cell<TimingReport> = Cell::new(TimingReport::default());
+}
+
+pub(crate) fn charge(f: impl FnOnce(&mut TimingReport)) {
+    TIMING.with(|t| {
+        let mut r = t.get();
+        f(&mut r);
+        t.set(r);
+    });
+}
+
+impl MPS {
+    /// Timers accumulated on this thread since it started or since `reset_timing`.
+    pub fn timing_report() -> TimingReport {
+        TIMING.with(Cell::get)
+    }
+
+    pub fn reset_timing() {
+        TIMING.with(|t| t.set(TimingReport::default()));
+    }
+}
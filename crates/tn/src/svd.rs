@@ -0,0 +1,230 @@
+use crate::mps::C64;
+use faer::Mat;
+use rng::ONDRng;
+
+/// Which routine factorizes the two-site theta matrix.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SvdBackend {
+    /// faer's thin SVD (default).
+    #[default]
+    Faer,
+    /// One-sided Jacobi; cheap and very accurate for the small thetas of low-chi sweeps.
+    Jacobi,
+}
+
+/// Thin SVD m = U diag(s) V^H with `s` sorted in descending order.
+pub struct SvdFactors {
+    pub u: Mat<C64>,
+    pub s: Vec<f64>,
+    pub v: Mat<C64>,
+}
+
+pub fn thin_svd(m: &Mat<C64>, backend: SvdBackend) -> SvdFactors {
+    match backend {
+        SvdBackend::Faer => faer_svd(m),
+        SvdBackend::Jacobi => {
+            if m.nrows() >= m.ncols() {
+                jacobi_svd(m)
+            } else {
+                // m^H = U S V^H  =>  m = V S U^H
+                let f = jacobi_svd(&adjoint(m));
+                SvdFactors {
+                    u: f.v,
+                    s: f.s,
+                    v: f.u,
+                }
+            }
+        }
+    }
+}
+
+/// faer's thin SVD, verified when the spectrum is rank-deficient.
+///
+/// faer 0.19 can return wrong singular vectors for matrices with exactly zero singular
+/// values, such as the structured site tensors of Dicke states. When the smallest value
+/// is negligible the factors are checked against `m` and recomputed with Jacobi if they
+/// do not reproduce it; generic full-rank thetas skip the check.
+fn faer_svd(m: &Mat<C64>) -> SvdFactors {
+    let svd = m.thin_svd();
+    let s = svd.s_diagonal();
+    let f = SvdFactors {
+        u: svd.u().to_owned(),
+        s: (0..s.nrows()).map(|i| s.read(i).re).collect(),
+        v: svd.v().to_owned(),
+    };
+
+    let s_max = f.s.first().copied().unwrap_or(0.0);
+    let s_min = f.s.last().copied().unwrap_or(0.0);
+    if s_min > 1e-12 * s_max {
+        return f;
+    }
+    let mut rebuilt = f.u.clone();
+    for (j, &sv) in f.s.iter().enumerate() {
+        for i in 0..rebuilt.nrows() {
+            rebuilt.write(i, j, rebuilt.read(i, j) * sv);
+        }
+    }
+    let residual = (&rebuilt * adjoint(&f.v) - m).norm_l2();
+    if residual <= 1e-10 * m.norm_l2() {
+        f
+    } else {
+        thin_svd(m, SvdBackend::Jacobi)
+    }
+}
+
+fn adjoint(m: &Mat<C64>) -> Mat<C64> {
+    Mat::<C64>::from_fn(m.ncols(), m.nrows(), |i, j| m.read(j, i).conj())
+}
+
+/// Standard complex Gaussian entry via Box-Muller.
+fn gaussian(rng: &mut ONDRng) -> C64 {
+    let u1 = rng.next_f64(b"RSVD_SKETCH").max(f64::MIN_POSITIVE);
+    let u2 = rng.next_f64(b"RSVD_SKETCH");
+    let r = (-u1.ln()).sqrt();
+    let phi = 2.0 * std::f64::consts::PI * u2;
+    C64::new(r * phi.cos(), r * phi.sin())
+}
+
+/// Randomized rank-`rank` SVD (Halko-Martinsson-Tropp) with a Gaussian sketch.
+///
+/// Oversamples by 8 columns and runs `power_iters` subspace iterations; the result
+/// has at most `rank + 8` singular triplets, so callers still truncate to `rank`.
+pub fn randomized_svd(
+    m: &Mat<C64>,
+    rank: usize,
+    power_iters: usize,
+    rng: &mut ONDRng,
+) -> SvdFactors {
+    let min_dim = m.nrows().min(m.ncols());
+    let l = rank.saturating_add(8).min(min_dim).max(1);
+    if l == min_dim {
+        return thin_svd(m, SvdBackend::Faer);
+    }
+
+    let mut omega = Mat::<C64>::zeros(m.ncols(), l);
+    for j in 0..l {
+        for i in 0..m.ncols() {
+            omega.write(i, j, gaussian(rng));
+        }
+    }
+
+    let m_adj = adjoint(m);
+    let mut q = (m * &omega).qr().compute_thin_q();
+    for _ in 0..power_iters {
+        let z = (&m_adj * &q).qr().compute_thin_q();
+        q = (m * &z).qr().compute_thin_q();
+    }
+
+    // m ≈ Q B with B = Q^H m small, so the SVD of B gives the leading triplets.
+    let b = &adjoint(&q) * m;
+    let small = thin_svd(&b, SvdBackend::Faer);
+    SvdFactors {
+        u: &q * &small.u,
+        s: small.s,
+        v: small.v,
+    }
+}
+
+fn col_dot(a: &Mat<C64>, p: usize, q: usize) -> C64 {
+    let mut acc = C64::new(0.0, 0.0);
+    for i in 0..a.nrows() {
+        acc += a.read(i, p).conj() * a.read(i, q);
+    }
+    acc
+}
+
+/// Right-multiply columns p, q of `a` by [[c, s], [-s·ph, c·ph]] (ph a unit phase).
+fn rotate_cols(a: &mut Mat<C64>, p: usize, q: usize, c: f64, s: f64, ph: C64) {
+    for i in 0..a.nrows() {
+        let ap = a.read(i, p);
+        let aq = a.read(i, q) * ph;
+        a.write(i, p, ap * c - aq * s);
+        a.write(i, q, ap * s + aq * c);
+    }
+}
+
+/// One-sided (Hestenes) Jacobi SVD for m.nrows() >= m.ncols().
+fn jacobi_svd(m: &Mat<C64>) -> SvdFactors {
+    let rows = m.nrows();
+    let cols = m.ncols();
+    let mut a = Mat::<C64>::from_fn(rows, cols, |i, j| m.read(i, j));
+    let mut v = Mat::<C64>::identity(cols, cols);
+
+    for _sweep in 0..64 {
+        let mut rotated = false;
+        for p in 0..cols {
+            for q in p + 1..cols {
+                let alpha = col_dot(&a, p, p).re;
+                let beta = col_dot(&a, q, q).re;
+                let gamma = col_dot(&a, p, q);
+                let g = gamma.norm();
+                if g <= 1e-15 * (alpha * beta).sqrt() || g == 0.0 {
+                    continue;
+                }
+                rotated = true;
+
+                // Phase-align column q so the off-diagonal is real, then rotate as in the real case.
+                let ph = (gamma / g).conj();
+                let zeta = (beta - alpha) / (2.0 * g);
+                let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                let c = 1.0 / (1.0 + t * t).sqrt();
+                let s = c * t;
+                rotate_cols(&mut a, p, q, c, s, ph);
+                rotate_cols(&mut v, p, q, c, s, ph);
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let norms: Vec<f64> = (0..cols).map(|j| col_dot(&a, j, j).re.sqrt()).collect();
+    let mut order: Vec<usize> = (0..cols).collect();
+    order.sort_by(|&x, &y| norms[y].total_cmp(&norms[x]));
+
+    let scale = norms.iter().cloned().fold(0.0, f64::max);
+    let mut u = Mat::<C64>::zeros(rows, cols);
+    let mut v_sorted = Mat::<C64>::zeros(cols, cols);
+    let mut s = Vec::with_capacity(cols);
+    for (jn, &jo) in order.iter().enumerate() {
+        let sv = norms[jo];
+        s.push(sv);
+        for i in 0..cols {
+            v_sorted.write(i, jn, v.read(i, jo));
+        }
+        if sv > 1e-14 * scale {
+            for i in 0..rows {
+                u.write(i, jn, a.read(i, jo) / sv);
+            }
+        } else {
+            complete_column(&mut u, jn);
+        }
+    }
+
+    SvdFactors { u, s, v: v_sorted }
+}
+
+/// Fill column j with a unit vector orthogonal to columns 0..j (for null singular values).
+fn complete_column(u: &mut Mat<C64>, j: usize) {
+    for e in 0..u.nrows() {
+        let mut cand: Vec<C64> = (0..u.nrows())
+            .map(|i| if i == e { C64::new(1.0, 0.0) } else { C64::new(0.0, 0.0) })
+            .collect();
+        for k in 0..j {
+            let mut proj = C64::new(0.0, 0.0);
+            for (i, &c) in cand.iter().enumerate() {
+                proj += u.read(i, k).conj() * c;
+            }
+            for (i, c) in cand.iter_mut().enumerate() {
+                *c -= proj * u.read(i, k);
+            }
+        }
+        let norm = cand.iter().map(|x| x.norm_sqr()).sum::<f64>().sqrt();
+        if norm > 1e-8 {
+            for (i, &c) in cand.iter().enumerate() {
+                u.write(i, j, c / norm);
+            }
+            return;
+        }
+    }
+}
@@ -0,0 +1,412 @@
+//! Tensor storage and the SVD-free gate paths; builds without `std` (needs `alloc`).
+
+use alloc::vec;
+use alloc::vec::Vec;
+use num_complex::Complex64;
+
+pub type C64 = Complex64;
+
+pub struct Tensor3 {
+    pub data: Vec<C64>,
+    pub dl: usize,
+    pub dp: usize,
+    pub dr: usize,
+}
+
+impl Clone for Tensor3 {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            dl: self.dl,
+            dp: self.dp,
+            dr: self.dr,
+        }
+    }
+
+    /// Copies into the existing allocation when it is large enough.
+    fn clone_from(&mut self, source: &Self) {
+        self.data.clone_from(&source.data);
+        self.dl = source.dl;
+        self.dp = source.dp;
+        self.dr = source.dr;
+    }
+}
+
+impl Tensor3 {
+    pub fn zeros(dl: usize, dp: usize, dr: usize) -> Self {
+        Self {
+            data: vec![C64::new(0.0, 0.0); dl * dp * dr],
+            dl,
+            dp,
+            dr,
+        }
+    }
+
+    /// Reshape to (dl, dp, dr) filled with zeros, keeping the existing allocation when it fits.
+    pub fn reset(&mut self, dl: usize, dp: usize, dr: usize) {
+        self.data.clear();
+        self.data.resize(dl * dp * dr, C64::new(0.0, 0.0));
+        self.dl = dl;
+        self.dp = dp;
+        self.dr = dr;
+    }
+
+    #[inline]
+    fn idx(&self, l: usize, p: usize, r: usize) -> usize {
+        (l * self.dp + p) * self.dr + r
+    }
+
+    pub fn get(&self, l: usize, p: usize, r: usize) -> C64 {
+        self.data[self.idx(l, p, r)]
+    }
+
+    pub fn set(&mut self, l: usize, p: usize, r: usize, v: C64) {
+        let i = self.idx(l, p, r);
+        self.data[i] = v;
+    }
+}
+
+/// Canonical qubit ordering: site 0 is the most significant bit of a basis index.
+///
+/// `index_to_bits(0b011, 3) == [0, 1, 1]`, i.e. `bits[k]` is the state of site k.
+pub fn index_to_bits(index: usize, n: usize) -> Vec<u8> {
+    (0..n).map(|k| ((index >> (n - 1 - k)) & 1) as u8).collect()
+}
+
+/// Inverse of `index_to_bits`: site 0 is the most significant bit.
+pub fn bits_to_index(bits: &[u8]) -> usize {
+    bits.iter().fold(0usize, |acc, &b| (acc << 1) | (b & 1) as usize)
+}
+
+/// Boundary condition of the chain.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Boundary {
+    /// Dimension-1 bonds at both ends.
+    #[default]
+    Open,
+    /// The last site's right bond closes onto site 0's left bond; amplitudes are traces
+    /// Tr(A_0[p_0] ... A_{n-1}[p_{n-1}]).
+    Periodic,
+}
+
+pub struct MPS {
+    pub(crate) sites: Vec<Tensor3>,
+    /// Site every other tensor is an isometry towards, when known.
+    pub(crate) center: Option<usize>,
+    pub(crate) boundary: Boundary,
+}
+
+impl Clone for MPS {
+    fn clone(&self) -> Self {
+        Self {
+            sites: self.sites.clone(),
+            center: self.center,
+            boundary: self.boundary,
+        }
+    }
+
+    /// Reuses the site buffers, so resetting a scratch copy per shot does not allocate.
+    fn clone_from(&mut self, source: &Self) {
+        self.sites.clone_from(&source.sites);
+        self.center = source.center;
+        self.boundary = source.boundary;
+    }
+}
+
+impl MPS {
+    pub fn new_zero(n: usize) -> Self {
+        Self::new_zero_dim(n, 2)
+    }
+
+    /// Product state |0...0> with local dimension dp on every site.
+    pub fn new_zero_dim(n: usize, dp: usize) -> Self {
+        let mut sites = Vec::with_capacity(n);
+        for _ in 0..n {
+            let mut t = Tensor3::zeros(1, dp, 1);
+            t.set(0, 0, 0, C64::new(1.0, 0.0));
+            sites.push(t);
+        }
+        Self::from_sites(sites)
+    }
+
+    /// Wrap hand-built site tensors; no gauge is assumed.
+    pub fn from_sites(sites: Vec<Tensor3>) -> Self {
+        Self {
+            sites,
+            center: None,
+            boundary: Boundary::Open,
+        }
+    }
+
+    /// Ring of hand-built site tensors; site 0's left bond must match the last right bond.
+    pub fn periodic(sites: Vec<Tensor3>) -> Self {
+        if let (Some(first), Some(last)) = (sites.first(), sites.last()) {
+            assert_eq!(
+                first.dl, last.dr,
+                "periodic: boundary bond mismatch (site 0 dl = {}, last dr = {})",
+                first.dl, last.dr
+            );
+        }
+        Self {
+            sites,
+            center: None,
+            boundary: Boundary::Periodic,
+        }
+    }
+
+    pub fn boundary(&self) -> Boundary {
+        self.boundary
+    }
+
+    pub fn is_periodic(&self) -> bool {
+        self.boundary == Boundary::Periodic
+    }
+
+    /// The same state as an open chain, carrying the boundary bond along every site.
+    ///
+    /// Site i becomes I_D ⊗ A_i on a bond of dimension D·χ (D the boundary bond), with
+    /// the identity opened on site 0 and traced on the last site. Open chains are cloned.
+    pub fn to_open(&self) -> MPS {
+        if !self.is_periodic() || self.sites.is_empty() {
+            let mut psi = self.clone();
+            psi.boundary = Boundary::Open;
+            return psi;
+        }
+        let n = self.sites.len();
+        let d = self.sites[0].dl;
+        let mut sites = Vec::with_capacity(n);
+        for (i, a) in self.sites.iter().enumerate() {
+            // Bond index (α, m) flattens to α·χ + m; site 0 keeps l = α, the last site r = α.
+            let (first, last) = (i == 0, i + 1 == n);
+            let dl = if first { 1 } else { d * a.dl };
+            let dr = if last { 1 } else { d * a.dr };
+            let mut t = Tensor3::zeros(dl, a.dp, dr);
+            for alpha in 0..d {
+                for l in 0..a.dl {
+                    for p in 0..a.dp {
+                        for r in 0..a.dr {
+                            if (first && l != alpha) || (last && r != alpha) {
+                                continue;
+                            }
+                            let li = if first { 0 } else { alpha * a.dl + l };
+                            let ri = if last { 0 } else { alpha * a.dr + r };
+                            let cur = t.get(li, p, ri);
+                            t.set(li, p, ri, cur + a.get(l, p, r));
+                        }
+                    }
+                }
+            }
+            sites.push(t);
+        }
+        MPS::from_sites(sites)
+    }
+
+    /// The orthogonality center, if the chain is known to be in mixed-canonical form.
+    ///
+    /// `Some(k)` means sites left of k are left isometries and sites right of k are
+    /// right isometries, so local expectation values only need site k. Gates keep
+    /// this up to date; anything that may break the gauge resets it to `None`.
+    pub fn orthogonality_center(&self) -> Option<usize> {
+        self.center
+    }
+
+    /// Site tensors, left to right.
+    pub fn sites(&self) -> &[Tensor3] {
+        &self.sites
+    }
+
+    /// All site tensors for writing; forgets the orthogonality center.
+    pub fn sites_mut(&mut self) -> &mut [Tensor3] {
+        self.center = None;
+        &mut self.sites
+    }
+
+    /// Site k for writing; the orthogonality center survives only if it is k.
+    #[track_caller]
+    pub fn site_mut(&mut self, k: usize) -> &mut Tensor3 {
+        self.assert_site(k, "site_mut");
+        self.site_modified(k);
+        &mut self.sites[k]
+    }
+
+    /// Record that site k was rewritten; the center survives only if it is k.
+    pub(crate) fn site_modified(&mut self, k: usize) {
+        if self.center != Some(k) {
+            self.center = None;
+        }
+    }
+
+    /// Bytes held by the site tensors.
+    pub fn memory_bytes(&self) -> usize {
+        self.sites
+            .iter()
+            .map(|t| t.data.len() * core::mem::size_of::<C64>())
+            .sum()
+    }
+
+    /// Upper bound on `memory_bytes` for n qubits with every bond capped at max_bond.
+    ///
+    /// Bond k (between sites k-1 and k) is at most min(max_bond, 2^min(k, n-k)).
+    pub fn memory_bytes_bound(n: usize, max_bond: usize) -> usize {
+        let bond = |k: usize| {
+            let e = k.min(n - k);
+            if e >= usize::BITS as usize - 1 {
+                max_bond
+            } else {
+                max_bond.min(1usize << e)
+            }
+        };
+        (0..n)
+            .map(|k| bond(k) * 2 * bond(k + 1) * core::mem::size_of::<C64>())
+            .sum()
+    }
+
+    /// Computational-basis product state; `bits[k]` is the state of site k.
+    pub fn from_bitstring(bits: &[u8]) -> Self {
+        let mut sites = Vec::with_capacity(bits.len());
+        for &b in bits {
+            let mut t = Tensor3::zeros(1, 2, 1);
+            t.set(0, b as usize, 0, C64::new(1.0, 0.0));
+            sites.push(t);
+        }
+        Self::from_sites(sites)
+    }
+
+    /// Dense amplitudes, indexed with site 0 as the most significant digit.
+    pub fn to_statevector(&self) -> Vec<C64> {
+        if self.is_periodic() {
+            return self.to_open().to_statevector();
+        }
+        let mut amps = vec![C64::new(1.0, 0.0)];
+        let mut bond = 1usize;
+        for s in &self.sites {
+            let states = amps.len() / bond;
+            let mut next = vec![C64::new(0.0, 0.0); states * s.dp * s.dr];
+            for x in 0..states {
+                for l in 0..s.dl {
+                    let v = amps[x * bond + l];
+                    for p in 0..s.dp {
+                        for r in 0..s.dr {
+                            next[(x * s.dp + p) * s.dr + r] += v * s.get(l, p, r);
+                        }
+                    }
+                }
+            }
+            amps = next;
+            bond = s.dr;
+        }
+        amps
+    }
+
+    /// Panic with a readable message unless site k exists.
+    #[track_caller]
+    pub fn assert_site(&self, k: usize, op: &str) {
+        let n = self.sites.len();
+        assert!(k < n, "{}: site {} out of range for {}-site MPS", op, k, n);
+    }
+
+    /// Panic with a readable message unless bond (k, k+1) lies inside the chain.
+    #[track_caller]
+    pub fn assert_bond(&self, k: usize, op: &str) {
+        let n = self.sites.len();
+        assert!(
+            k.checked_add(1).is_some_and(|j| j < n),
+            "{}: bond ({}, {}) out of range for {}-site MPS",
+            op,
+            k,
+            k.wrapping_add(1),
+            n
+        );
+    }
+
+    pub fn apply_1q(&mut self, k: usize, u: [[C64; 2]; 2]) {
+        self.assert_site(k, "apply_1q");
+        let s = &self.sites[k];
+        let out = timed!(apply_1q, {
+            let mut out = Tensor3::zeros(s.dl, s.dp, s.dr);
+            for l in 0..s.dl {
+                for r in 0..s.dr {
+                    for (p, row) in u.iter().enumerate() {
+                        let mut acc = C64::new(0.0, 0.0);
+                        for (pp, &g) in row.iter().enumerate() {
+                            acc += g * s.get(l, pp, r);
+                        }
+                        out.set(l, p, r, acc);
+                    }
+                }
+            }
+            out
+        });
+        #[cfg(feature = "profiling")]
+        crate::profiling::charge(|r| r.apply_1q_calls += 1);
+        self.sites[k] = out;
+        self.site_modified(k);
+    }
+
+    /// The same single-qubit gate on every site, e.g. H⊗n for uniform-superposition prep.
+    pub fn apply_1q_all(&mut self, u: [[C64; 2]; 2]) {
+        for k in 0..self.sites.len() {
+            self.apply_1q(k, u);
+        }
+    }
+
+    /// `gates[k]` on site k for every k, e.g. one rotation layer of a hardware-efficient ansatz.
+    pub fn apply_1q_layer(&mut self, gates: &[[[C64; 2]; 2]]) {
+        assert_eq!(
+            gates.len(),
+            self.sites.len(),
+            "apply_1q_layer needs one gate per site"
+        );
+        for (k, &u) in gates.iter().enumerate() {
+            self.apply_1q(k, u);
+        }
+    }
+
+    /// `apply_1q` with the gate as row-major `[re, im, ...]` pairs, for interop callers.
+    pub fn apply_1q_flat(&mut self, k: usize, m: &[f64; 8]) {
+        let mut u = [[C64::new(0.0, 0.0); 2]; 2];
+        for (i, row) in u.iter_mut().enumerate() {
+            for (j, v) in row.iter_mut().enumerate() {
+                let idx = 2 * (i * 2 + j);
+                *v = C64::new(m[idx], m[idx + 1]);
+            }
+        }
+        self.apply_1q(k, u);
+    }
+
+    /// Diagonal two-site gate diag(d[0], d[1], d[2], d[3]) on (k, k+1), applied exactly.
+    ///
+    /// No SVD: the control index is copied into the bond, so bond k grows by a factor
+    /// of 2. Call `compress` afterwards (with `std`) to bring it back down.
+    pub fn apply_2q_diagonal(&mut self, k: usize, d: [C64; 4]) {
+        self.assert_bond(k, "apply_2q_diagonal");
+        let a = &self.sites[k];
+        let b = &self.sites[k + 1];
+        assert!(a.dp == 2 && b.dp == 2, "apply_2q_diagonal supports qubits only");
+        let (dl, chi, dr) = (a.dl, a.dr, b.dr);
+
+        let mut new_a = Tensor3::zeros(dl, 2, chi * 2);
+        for l in 0..dl {
+            for p in 0..2 {
+                for m in 0..chi {
+                    new_a.set(l, p, m * 2 + p, a.get(l, p, m));
+                }
+            }
+        }
+
+        let mut new_b = Tensor3::zeros(chi * 2, 2, dr);
+        for m in 0..chi {
+            for p in 0..2 {
+                for q in 0..2 {
+                    for r in 0..dr {
+                        new_b.set(m * 2 + p, q, r, d[p * 2 + q] * b.get(m, q, r));
+                    }
+                }
+            }
+        }
+
+        self.sites[k] = new_a;
+        self.sites[k + 1] = new_b;
+        self.center = None;
+    }
+}
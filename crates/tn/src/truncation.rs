@@ -1,5 +1,104 @@
-#[derive(Clone, Copy)]
+/// SVD truncation policy for two-site updates and compression.
+///
+/// Build it with `Truncation::builder()` or a preset (`exact`, `fast`, `default`). The
+/// struct is `#[non_exhaustive]`, so literals do not compile outside this crate and new
+/// options can be added without breaking callers. It is a small `Copy` value and is
+/// passed by value everywhere.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
 pub struct Truncation {
     pub max_bond: usize,
     pub cutoff: f64,
+    /// Singular values at or below `rel_floor · s_max` are dropped whatever `cutoff` says,
+    /// so SVD round-off never survives as extra bond dimension. Set through the builder.
+    rel_floor: f64,
+}
+
+/// Default `rel_floor`: a few ULPs of the largest singular value.
+pub const DEFAULT_REL_FLOOR: f64 = 16.0 * f64::EPSILON;
+
+impl Default for Truncation {
+    /// max_bond = 64, cutoff = 1e-8, rel_floor = `DEFAULT_REL_FLOOR`.
+    fn default() -> Self {
+        Self {
+            max_bond: 64,
+            cutoff: 1e-8,
+            rel_floor: DEFAULT_REL_FLOOR,
+        }
+    }
+}
+
+impl Truncation {
+    /// Effective drop threshold for a spectrum whose largest singular value is `s_max`.
+    pub fn threshold(&self, s_max: f64) -> f64 {
+        self.cutoff.max(self.rel_floor * s_max)
+    }
+
+    /// Relative singular-value floor; see `TruncationBuilder::rel_floor`.
+    pub fn rel_floor(&self) -> f64 {
+        self.rel_floor
+    }
+
+    /// Builder starting from `Truncation::default()`.
+    pub fn builder() -> TruncationBuilder {
+        let d = Self::default();
+        TruncationBuilder {
+            max_bond: d.max_bond,
+            cutoff: d.cutoff,
+            rel_floor: d.rel_floor,
+        }
+    }
+
+    /// No bond cap and a cutoff at round-off level: only numerically zero weight is dropped.
+    pub fn exact() -> Self {
+        Self {
+            max_bond: usize::MAX,
+            cutoff: 1e-14,
+            rel_floor: DEFAULT_REL_FLOOR,
+        }
+    }
+
+    /// Cheap exploratory runs: max_bond = 16, cutoff = 1e-6.
+    pub fn fast() -> Self {
+        Self {
+            max_bond: 16,
+            cutoff: 1e-6,
+            rel_floor: DEFAULT_REL_FLOOR,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct TruncationBuilder {
+    max_bond: usize,
+    cutoff: f64,
+    rel_floor: f64,
+}
+
+impl TruncationBuilder {
+    /// Largest bond dimension kept after an SVD.
+    pub fn max_bond(mut self, max_bond: usize) -> Self {
+        self.max_bond = max_bond;
+        self
+    }
+
+    /// Singular values at or below this are dropped.
+    pub fn cutoff(mut self, cutoff: f64) -> Self {
+        self.cutoff = cutoff;
+        self
+    }
+
+    /// Relative floor on kept singular values; 0 disables it.
+    pub fn rel_floor(mut self, rel_floor: f64) -> Self {
+        self.rel_floor = rel_floor;
+        self
+    }
+
+    pub fn build(self) -> Truncation {
+        Truncation {
+            max_bond: self.max_bond,
+            cutoff: self.cutoff,
+            rel_floor: self.rel_floor,
+        }
+    }
 }
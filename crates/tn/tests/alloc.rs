@@ -0,0 +1,104 @@
+#![cfg(feature = "std")]
+
+//! Allocation bound for `apply_2q_svd` at chi = 32.
+//!
+//! Lives in its own test binary so the counting allocator sees no other tests.
+
+mod common;
+
+use common::{cphase, ry};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tn::{
+    mps::{C64, MPS},
+    truncation::Truncation,
+};
+
+struct Counting;
+
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static WATCHED: [AtomicUsize; 2] = [AtomicUsize::new(0), AtomicUsize::new(0)];
+static RELEASED: AtomicUsize = AtomicUsize::new(0);
+
+/// Counts frees and reallocs of the watched site buffers. The allocator may hand a freed
+/// block straight back, so an unchanged pointer alone does not prove the buffer was kept.
+fn release(ptr: *mut u8) {
+    if WATCHED.iter().any(|w| w.load(Ordering::Relaxed) == ptr as usize) {
+        RELEASED.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        release(ptr);
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        BYTES.fetch_add(new_size, Ordering::Relaxed);
+        release(ptr);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+#[test]
+fn reuse_cuts_allocations_at_chi_32() {
+    let n = 12;
+    let k = n / 2 - 1;
+    let trunc = Truncation::builder().max_bond(32).cutoff(0.0).build();
+
+    let mut psi = MPS::new_zero(n);
+    for layer in 0..8 {
+        for q in 0..n {
+            psi.apply_1q(q, ry(0.3 + 0.7 * (q + layer) as f64));
+        }
+        for q in 0..n - 1 {
+            psi.apply_2q_svd(q, cphase(1.1 + 0.4 * q as f64), trunc);
+        }
+    }
+    assert_eq!(psi.sites()[k].dl, 32);
+    assert_eq!(psi.sites()[k + 1].dr, 32);
+
+    let reps = 5;
+    let gate = cphase(0.9);
+    let buffers = |psi: &MPS| {
+        let (a, b) = (&psi.sites()[k].data, &psi.sites()[k + 1].data);
+        [(a.as_ptr(), a.capacity()), (b.as_ptr(), b.capacity())]
+    };
+
+    let bytes0 = BYTES.load(Ordering::Relaxed);
+    for rep in 0..reps {
+        let before = buffers(&psi);
+        for (w, (ptr, _)) in WATCHED.iter().zip(before) {
+            w.store(ptr as usize, Ordering::Relaxed);
+        }
+        psi.apply_2q_svd(k, gate, trunc);
+        // The bond stays at 32, so the update must write into the same site buffers.
+        assert_eq!(buffers(&psi), before, "site tensors moved on gate {}", rep);
+    }
+    for w in &WATCHED {
+        w.store(0, Ordering::Relaxed);
+    }
+    let released = RELEASED.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed) - bytes0;
+
+    // faer's SVD needs about 48 theta-sized (64x64) buffers per gate; the bound leaves
+    // headroom for that but fails if a second theta-scale workspace creeps in.
+    let theta_bytes = (2 * 32) * (2 * 32) * std::mem::size_of::<C64>();
+    let per_gate = bytes / reps;
+    assert!(
+        per_gate <= 64 * theta_bytes,
+        "{} B/gate allocated, bound is {} B",
+        per_gate,
+        64 * theta_bytes
+    );
+    assert_eq!(released, 0, "site buffers freed or reallocated: {}", released);
+}
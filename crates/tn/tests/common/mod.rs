@@ -0,0 +1,35 @@
+// Each test binary uses its own subset of these helpers.
+#![allow(dead_code)]
+
+// `tensor` builds without `std`, so `no_std_core` can share `hadamard`.
+use tn::tensor::C64;
+
+pub fn hadamard() -> [[C64; 2]; 2] {
+    let h = core::f64::consts::FRAC_1_SQRT_2;
+    [
+        [C64::new(h, 0.0), C64::new(h, 0.0)],
+        [C64::new(h, 0.0), C64::new(-h, 0.0)],
+    ]
+}
+
+pub fn ry(theta: f64) -> [[C64; 2]; 2] {
+    let c = (theta / 2.0).cos();
+    let s = (theta / 2.0).sin();
+    [
+        [C64::new(c, 0.0), C64::new(-s, 0.0)],
+        [C64::new(s, 0.0), C64::new(c, 0.0)],
+    ]
+}
+
+// `C64::from_polar` needs num-complex's `std` feature.
+#[cfg(feature = "std")]
+pub fn cphase(phi: f64) -> [[C64; 4]; 4] {
+    let z = C64::new(0.0, 0.0);
+    let o = C64::new(1.0, 0.0);
+    [
+        [o, z, z, z],
+        [z, o, z, z],
+        [z, z, o, z],
+        [z, z, z, C64::from_polar(1.0, phi)],
+    ]
+}
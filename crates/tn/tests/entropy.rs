@@ -0,0 +1,28 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::hadamard;
+use tn::{
+    mps::{C64, MPS},
+    truncation::Truncation,
+};
+
+fn cnot() -> [[C64; 4]; 4] {
+    let z = C64::new(0.0, 0.0);
+    let o = C64::new(1.0, 0.0);
+    [[o, z, z, z], [z, o, z, z], [z, z, z, o], [z, z, o, z]]
+}
+
+#[test]
+fn entropy_of_bell_pair_is_ln2() {
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-12).build();
+    // |0> (|00> + |11>)/√2 |0>: only the middle bond is entangled.
+    let mut psi = MPS::new_zero(4);
+    psi.apply_1q(1, hadamard());
+    psi.apply_2q_svd(1, cnot(), trunc);
+
+    assert!(psi.entanglement_entropy(1).abs() < 1e-12);
+    assert!((psi.entanglement_entropy(2) - 2.0_f64.ln()).abs() < 1e-12);
+    assert!(psi.entanglement_entropy(3).abs() < 1e-12);
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::hadamard;
+use tn::{
+    mps::{C64, MPS},
+    truncation::Truncation,
+};
+
+fn max_diff(a: &[C64], b: &[C64]) -> f64 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (x - y).norm())
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn flat_hadamard_matches_typed() {
+    let h = std::f64::consts::FRAC_1_SQRT_2;
+    let flat = [h, 0.0, h, 0.0, h, 0.0, -h, 0.0];
+
+    let mut a = MPS::new_zero(3);
+    let mut b = MPS::new_zero(3);
+    a.apply_1q(1, hadamard());
+    b.apply_1q_flat(1, &flat);
+
+    assert!(max_diff(&a.to_statevector(), &b.to_statevector()) < 1e-15);
+}
+
+#[test]
+fn flat_two_qubit_gate_reads_row_major_complex() {
+    // diag(1, i, i, -1) = S ⊗ S, given as row-major (re, im) pairs.
+    let mut flat = [0.0; 32];
+    let diag = [(1.0, 0.0), (0.0, 1.0), (0.0, 1.0), (-1.0, 0.0)];
+    for (i, &(re, im)) in diag.iter().enumerate() {
+        flat[2 * (i * 4 + i)] = re;
+        flat[2 * (i * 4 + i) + 1] = im;
+    }
+
+    let trunc = Truncation::exact();
+    let mut psi = MPS::new_zero(2);
+    psi.apply_1q(0, hadamard());
+    psi.apply_1q(1, hadamard());
+    psi.apply_2q_flat(0, &flat, trunc);
+
+    let amps = psi.to_statevector();
+    let expected = [
+        C64::new(0.5, 0.0),
+        C64::new(0.0, 0.5),
+        C64::new(0.0, 0.5),
+        C64::new(-0.5, 0.0),
+    ];
+    assert!(max_diff(&amps, &expected) < 1e-12);
+}
@@ -0,0 +1,15 @@
+#![cfg(feature = "std")]
+
+use tn::mps::{C64, MPS};
+
+#[test]
+fn memory_bound_matches_product_and_full_rank() {
+    let c64 = std::mem::size_of::<C64>();
+    let psi = MPS::new_zero(6);
+
+    assert_eq!(psi.memory_bytes(), 6 * 2 * c64);
+    assert_eq!(MPS::memory_bytes_bound(6, 1), psi.memory_bytes());
+
+    // n = 4 unbounded: bonds 1, 2, 4, 2, 1.
+    assert_eq!(MPS::memory_bytes_bound(4, 1024), (4 + 16 + 16 + 4) * c64);
+}
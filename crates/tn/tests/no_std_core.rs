@@ -0,0 +1,62 @@
+//! Uses only the `tensor` module, so it also runs under `--no-default-features`.
+
+mod common;
+
+use common::hadamard;
+use tn::tensor::{C64, MPS};
+
+fn assert_amps(got: &[C64], expected: &[C64]) {
+    assert_eq!(got.len(), expected.len());
+    for (g, e) in got.iter().zip(expected) {
+        assert!((g - e).norm_sqr() < 1e-24, "{} vs {}", g, e);
+    }
+}
+
+#[test]
+fn apply_1q_without_svd() {
+    let mut psi = MPS::new_zero(2);
+    psi.apply_1q(1, hadamard());
+
+    let h = core::f64::consts::FRAC_1_SQRT_2;
+    let z = C64::new(0.0, 0.0);
+    assert_amps(&psi.to_statevector(), &[C64::new(h, 0.0), C64::new(h, 0.0), z, z]);
+}
+
+#[test]
+fn apply_2q_diagonal_matches_controlled_z() {
+    let o = C64::new(1.0, 0.0);
+    let cz = [o, o, o, -o];
+
+    let mut psi = MPS::new_zero(3);
+    for k in 0..3 {
+        psi.apply_1q(k, hadamard());
+    }
+    psi.apply_2q_diagonal(0, cz);
+    psi.apply_2q_diagonal(1, cz);
+    assert_eq!(psi.sites()[0].dr, 2);
+    assert_eq!(psi.sites()[1].dr, 2);
+
+    // (-1)^(b0 b1 + b1 b2) / √8, with site 0 as the most significant bit.
+    let amp = 1.0 / 8f64.sqrt();
+    let expected: Vec<C64> = (0..8)
+        .map(|x: usize| {
+            let (b0, b1, b2) = (x >> 2 & 1, x >> 1 & 1, x & 1);
+            let sign = if (b0 * b1 + b1 * b2) % 2 == 1 { -1.0 } else { 1.0 };
+            C64::new(sign * amp, 0.0)
+        })
+        .collect();
+    assert_amps(&psi.to_statevector(), &expected);
+}
+
+#[test]
+fn apply_1q_all_hadamard_gives_uniform_superposition() {
+    let mut psi = MPS::new_zero(4);
+    psi.apply_1q_all(hadamard());
+
+    let amps = psi.to_statevector();
+    assert_eq!(amps.len(), 16);
+    for a in &amps {
+        assert!((a - C64::new(0.25, 0.0)).norm_sqr() < 1e-24, "{}", a);
+    }
+    assert!(psi.sites().iter().all(|s| s.dl == 1 && s.dr == 1));
+}
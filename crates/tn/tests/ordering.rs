@@ -0,0 +1,132 @@
+#![cfg(feature = "std")]
+
+use tn::{
+    mps::{bits_to_index, index_to_bits, C64, MPS},
+    svd::SvdBackend,
+    truncation::Truncation,
+};
+
+#[test]
+fn bits_index_round_trip() {
+    let n = 4;
+    for i in 0..1usize << n {
+        assert_eq!(bits_to_index(&index_to_bits(i, n)), i);
+    }
+    assert_eq!(index_to_bits(0b0011, 4), vec![0, 0, 1, 1]);
+}
+
+#[test]
+fn statevector_uses_site_zero_as_msb() {
+    let n = 4;
+    for i in 0..1usize << n {
+        let psi = MPS::from_bitstring(&index_to_bits(i, n));
+        let amps = psi.to_statevector();
+        for (j, a) in amps.iter().enumerate() {
+            let expected = if j == i { 1.0 } else { 0.0 };
+            assert!((a - C64::new(expected, 0.0)).norm() < 1e-15, "i={} j={}", i, j);
+        }
+    }
+}
+
+/// Single-qubit state with a complex relative phase, as a gate taking |0> to it.
+fn prep(theta: f64, phi: f64) -> [[C64; 2]; 2] {
+    let (c, s) = ((theta / 2.0).cos(), (theta / 2.0).sin());
+    let e = C64::from_polar(1.0, phi);
+    [[C64::new(c, 0.0), -e.conj() * s], [e * s, C64::new(c, 0.0)]]
+}
+
+/// Controlled-U with control on the left site and a complex, non-symmetric U.
+fn controlled_u() -> [[C64; 4]; 4] {
+    let z = C64::new(0.0, 0.0);
+    let o = C64::new(1.0, 0.0);
+    let u = [
+        [C64::new(0.6, 0.0), C64::from_polar(0.8, 0.3 + std::f64::consts::FRAC_PI_2)],
+        [C64::from_polar(0.8, -0.7 + std::f64::consts::FRAC_PI_2), C64::from_polar(0.6, -0.4)],
+    ];
+    [
+        [o, z, z, z],
+        [z, o, z, z],
+        [z, z, u[0][0], u[0][1]],
+        [z, z, u[1][0], u[1][1]],
+    ]
+}
+
+fn dense_1q(amps: &mut [C64], n: usize, k: usize, u: [[C64; 2]; 2]) {
+    let bit = 1 << (n - 1 - k);
+    for i in 0..amps.len() {
+        if i & bit == 0 {
+            let (a0, a1) = (amps[i], amps[i | bit]);
+            amps[i] = u[0][0] * a0 + u[0][1] * a1;
+            amps[i | bit] = u[1][0] * a0 + u[1][1] * a1;
+        }
+    }
+}
+
+fn dense_2q(amps: &mut [C64], n: usize, k: usize, g: [[C64; 4]; 4]) {
+    let (hi, lo) = (1 << (n - 1 - k), 1 << (n - 2 - k));
+    for i in 0..amps.len() {
+        if i & (hi | lo) == 0 {
+            let idx = [i, i | lo, i | hi, i | hi | lo];
+            let old = idx.map(|j| amps[j]);
+            for (row, &j) in idx.iter().enumerate() {
+                amps[j] = (0..4).map(|col| g[row][col] * old[col]).sum();
+            }
+        }
+    }
+}
+
+#[test]
+fn asymmetric_two_qubit_gate_matches_dense_reference() {
+    let n = 3;
+    let trunc = Truncation::exact();
+    for backend in [SvdBackend::Faer, SvdBackend::Jacobi] {
+        let mut psi = MPS::new_zero(n);
+        let mut dense = vec![C64::new(0.0, 0.0); 1 << n];
+        dense[0] = C64::new(1.0, 0.0);
+
+        for k in 0..n {
+            let u = prep(0.7 + 0.5 * k as f64, 0.4 - 0.9 * k as f64);
+            psi.apply_1q(k, u);
+            dense_1q(&mut dense, n, k, u);
+        }
+        // Control left then right, so any transpose or conjugation of the split shows up.
+        for k in [0, 1, 0] {
+            psi.apply_2q_svd_with(k, controlled_u(), trunc, backend);
+            dense_2q(&mut dense, n, k, controlled_u());
+        }
+
+        for (i, (a, b)) in psi.to_statevector().iter().zip(&dense).enumerate() {
+            assert!((a - b).norm() < 1e-12, "{:?} amplitude {}: {} vs {}", backend, i, a, b);
+        }
+    }
+}
+
+/// Entangling circuit with complex amplitudes and a non-degenerate Schmidt spectrum.
+fn entangled(backend: SvdBackend) -> MPS {
+    let mut psi = MPS::new_zero(4);
+    for k in 0..4 {
+        psi.apply_1q(k, prep(0.4 + 0.3 * k as f64, 0.9 * k as f64));
+    }
+    for k in [0, 2, 1, 0, 2] {
+        psi.apply_2q_svd_with(k, controlled_u(), Truncation::exact(), backend);
+    }
+    psi
+}
+
+#[test]
+fn svd_phase_gauge_makes_tensor_data_deterministic() {
+    let a = entangled(SvdBackend::Faer);
+    let b = entangled(SvdBackend::Faer);
+    for (sa, sb) in a.sites().iter().zip(b.sites()) {
+        assert_eq!(sa.data, sb.data);
+    }
+
+    // The gauge is fixed by the data, not by the driver: both backends write the same tensors.
+    let j = entangled(SvdBackend::Jacobi);
+    for (k, (sa, sj)) in a.sites().iter().zip(j.sites()).enumerate() {
+        assert_eq!((sa.dl, sa.dr), (sj.dl, sj.dr), "site {}", k);
+        for (x, y) in sa.data.iter().zip(&sj.data) {
+            assert!((x - y).norm() < 1e-10, "site {}: {} vs {}", k, x, y);
+        }
+    }
+}
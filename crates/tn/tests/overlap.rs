@@ -0,0 +1,52 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::hadamard;
+use tn::mps::{overlap, overlap_phase, return_amplitude, C64, MPS};
+
+#[test]
+fn return_amplitude_is_one_at_t0() {
+    let mut psi = MPS::new_zero(4);
+    for k in 0..4 {
+        psi.apply_1q(k, hadamard());
+    }
+
+    let g = return_amplitude(&psi, &psi.clone());
+    assert!((g - C64::new(1.0, 0.0)).norm() < 1e-12, "G(0) = {}", g);
+}
+
+#[test]
+fn overlap_of_orthogonal_basis_states_vanishes() {
+    let a = MPS::from_bitstring(&[0, 1, 1]);
+    let b = MPS::from_bitstring(&[0, 1, 0]);
+
+    assert!(overlap(&a, &b).norm() < 1e-15);
+    assert!((overlap(&a, &a) - C64::new(1.0, 0.0)).norm() < 1e-15);
+}
+
+#[test]
+fn empty_chain_has_unit_norm() {
+    let empty = MPS::new_zero(0);
+
+    assert_eq!(overlap(&empty, &empty), C64::new(1.0, 0.0));
+    assert_eq!(empty.to_statevector(), vec![C64::new(1.0, 0.0)]);
+    assert_eq!(MPS::memory_bytes_bound(0, 16), 0);
+}
+
+#[test]
+fn overlap_phase_recovers_global_phase() {
+    let mut psi = MPS::new_zero(3);
+    for k in 0..3 {
+        psi.apply_1q(k, hadamard());
+    }
+
+    for phi in [0.0, 0.4, -1.3, 3.0] {
+        let mut rotated = psi.clone();
+        let ph = C64::from_polar(1.0, phi);
+        rotated.apply_1q(1, [[ph, C64::new(0.0, 0.0)], [C64::new(0.0, 0.0), ph]]);
+
+        assert!((overlap_phase(&psi, &rotated) - phi).abs() < 1e-12, "phi = {}", phi);
+        assert!((overlap_phase(&rotated, &psi) + phi).abs() < 1e-12, "phi = {}", phi);
+    }
+}
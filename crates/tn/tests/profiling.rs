@@ -0,0 +1,33 @@
+#![cfg(feature = "profiling")]
+
+use std::time::Duration;
+use tn::{
+    mps::{C64, MPS},
+    truncation::Truncation,
+};
+
+#[test]
+fn svd_time_is_recorded_only_after_a_two_qubit_gate() {
+    MPS::reset_timing();
+    let mut psi = MPS::new_zero(4);
+    let report = MPS::timing_report();
+    assert_eq!(report.svd, Duration::ZERO);
+    assert_eq!(report.apply_2q_calls, 0);
+
+    let s = std::f64::consts::FRAC_1_SQRT_2;
+    let h = [[C64::new(s, 0.0), C64::new(s, 0.0)], [C64::new(s, 0.0), C64::new(-s, 0.0)]];
+    psi.apply_1q(1, h);
+    let report = MPS::timing_report();
+    assert_eq!(report.apply_1q_calls, 1);
+    assert_eq!(report.svd, Duration::ZERO);
+
+    psi.apply_2q_svd(1, [[C64::new(0.5, 0.0); 4]; 4], Truncation::default());
+    let report = MPS::timing_report();
+    assert_eq!(report.apply_2q_calls, 1);
+    assert!(report.svd > Duration::ZERO, "{}", report);
+    assert!(report.theta > Duration::ZERO);
+    assert!(report.to_string().contains("svd:"));
+
+    MPS::reset_timing();
+    assert_eq!(MPS::timing_report(), Default::default());
+}
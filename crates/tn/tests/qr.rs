@@ -0,0 +1,204 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::{cphase, ry};
+use tn::{
+    mps::{C64, MPS},
+    truncation::Truncation,
+};
+
+fn entangled_state(n: usize) -> MPS {
+    let trunc = Truncation::builder().max_bond(16).cutoff(1e-14).build();
+    let mut psi = MPS::new_zero(n);
+    for layer in 0..3 {
+        for k in 0..n {
+            psi.apply_1q(k, ry(0.3 + 0.7 * (k + layer) as f64));
+        }
+        for k in 0..n - 1 {
+            psi.apply_2q_svd(k, cphase(1.1 + 0.4 * k as f64), trunc);
+        }
+    }
+    psi
+}
+
+fn max_diff(a: &[C64], b: &[C64]) -> f64 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (x - y).norm())
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn qr_left_gives_left_isometry() {
+    let mut psi = entangled_state(4);
+    let before = psi.to_statevector();
+
+    psi.qr_left(1);
+
+    let a = &psi.sites()[1];
+    for m in 0..a.dr {
+        for mp in 0..a.dr {
+            let mut acc = C64::new(0.0, 0.0);
+            for l in 0..a.dl {
+                for p in 0..a.dp {
+                    acc += a.get(l, p, m).conj() * a.get(l, p, mp);
+                }
+            }
+            let expected = if m == mp { 1.0 } else { 0.0 };
+            assert!((acc - expected).norm() < 1e-12, "A^H A [{}][{}] = {}", m, mp, acc);
+        }
+    }
+
+    let after = psi.to_statevector();
+    assert!(max_diff(&before, &after) < 1e-12);
+}
+
+#[test]
+fn qr_right_gives_right_isometry() {
+    let mut psi = entangled_state(4);
+    let before = psi.to_statevector();
+
+    psi.qr_right(2);
+
+    let a = &psi.sites()[2];
+    for l in 0..a.dl {
+        for lp in 0..a.dl {
+            let mut acc = C64::new(0.0, 0.0);
+            for p in 0..a.dp {
+                for r in 0..a.dr {
+                    acc += a.get(l, p, r) * a.get(lp, p, r).conj();
+                }
+            }
+            let expected = if l == lp { 1.0 } else { 0.0 };
+            assert!((acc - expected).norm() < 1e-12, "A A^H [{}][{}] = {}", l, lp, acc);
+        }
+    }
+
+    let after = psi.to_statevector();
+    assert!(max_diff(&before, &after) < 1e-12);
+}
+
+#[test]
+fn left_canonicalize_leaves_isometries_and_norm_on_last_site() {
+    let mut psi = entangled_state(5);
+    let before = psi.to_statevector();
+
+    psi.left_canonicalize();
+
+    for a in &psi.sites()[..4] {
+        for m in 0..a.dr {
+            for mp in 0..a.dr {
+                let mut acc = C64::new(0.0, 0.0);
+                for l in 0..a.dl {
+                    for p in 0..a.dp {
+                        acc += a.get(l, p, m).conj() * a.get(l, p, mp);
+                    }
+                }
+                let expected = if m == mp { 1.0 } else { 0.0 };
+                assert!((acc - expected).norm() < 1e-12);
+            }
+        }
+    }
+
+    let last = &psi.sites()[4];
+    let norm: f64 = last.data.iter().map(|x| x.norm_sqr()).sum();
+    assert!((norm - 1.0).abs() < 1e-12, "norm on last site = {}", norm);
+
+    let after = psi.to_statevector();
+    assert!(max_diff(&before, &after) < 1e-12);
+
+    let mut single = MPS::new_zero(1);
+    single.left_canonicalize();
+    assert_eq!(single.sites().len(), 1);
+}
+
+fn is_left_isometry(a: &tn::mps::Tensor3) -> bool {
+    (0..a.dr).all(|m| {
+        (0..a.dr).all(|mp| {
+            let mut acc = C64::new(0.0, 0.0);
+            for l in 0..a.dl {
+                for p in 0..a.dp {
+                    acc += a.get(l, p, m).conj() * a.get(l, p, mp);
+                }
+            }
+            (acc - if m == mp { 1.0 } else { 0.0 }).norm() < 1e-12
+        })
+    })
+}
+
+fn is_right_isometry(a: &tn::mps::Tensor3) -> bool {
+    (0..a.dl).all(|l| {
+        (0..a.dl).all(|lp| {
+            let mut acc = C64::new(0.0, 0.0);
+            for p in 0..a.dp {
+                for r in 0..a.dr {
+                    acc += a.get(l, p, r) * a.get(lp, p, r).conj();
+                }
+            }
+            (acc - if l == lp { 1.0 } else { 0.0 }).norm() < 1e-12
+        })
+    })
+}
+
+#[test]
+fn canonicalize_at_sets_center_and_distant_gate_clears_it() {
+    let mut psi = entangled_state(6);
+    assert_eq!(psi.orthogonality_center(), None);
+    let before = psi.to_statevector();
+
+    psi.canonicalize_at(3);
+    assert_eq!(psi.orthogonality_center(), Some(3));
+    assert!(psi.sites()[..3].iter().all(is_left_isometry));
+    assert!(psi.sites()[4..].iter().all(is_right_isometry));
+    assert!(max_diff(&before, &psi.to_statevector()) < 1e-12);
+
+    psi.apply_1q(3, ry(0.4));
+    assert_eq!(psi.orthogonality_center(), Some(3));
+
+    psi.apply_1q(0, ry(0.4));
+    assert_eq!(psi.orthogonality_center(), None);
+}
+
+#[test]
+fn writable_sites_invalidate_the_center() {
+    let mut psi = entangled_state(6);
+    psi.canonicalize_at(3);
+
+    // The center tensor itself may be rewritten freely.
+    psi.site_mut(3).data[0] *= 0.5;
+    assert_eq!(psi.orthogonality_center(), Some(3));
+
+    psi.site_mut(1).data[0] *= 0.5;
+    assert_eq!(psi.orthogonality_center(), None);
+
+    psi.canonicalize_at(2);
+    let _ = psi.sites_mut();
+    assert_eq!(psi.orthogonality_center(), None);
+}
+
+#[test]
+fn center_follows_qr_and_two_site_gates() {
+    let trunc = Truncation::builder().max_bond(16).cutoff(1e-14).build();
+    let mut psi = entangled_state(6);
+
+    psi.canonicalize_at(4);
+    psi.canonicalize_at(1);
+    assert_eq!(psi.orthogonality_center(), Some(1));
+    assert!(psi.sites()[2..].iter().all(is_right_isometry));
+
+    psi.apply_2q_svd(1, cphase(0.8), trunc);
+    assert_eq!(psi.orthogonality_center(), Some(1));
+    assert!(is_right_isometry(&psi.sites()[2]));
+
+    psi.qr_left(1);
+    assert_eq!(psi.orthogonality_center(), Some(2));
+
+    psi.apply_2q_svd(4, cphase(0.8), trunc);
+    assert_eq!(psi.orthogonality_center(), None);
+
+    psi.left_canonicalize();
+    assert_eq!(psi.orthogonality_center(), Some(5));
+    psi.compress(trunc);
+    assert_eq!(psi.orthogonality_center(), Some(0));
+}
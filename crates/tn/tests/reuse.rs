@@ -0,0 +1,58 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::{cphase, ry};
+use tn::{
+    mps::{C64, MPS, Tensor3},
+    truncation::Truncation,
+};
+
+/// Brick circuit; `fresh` swaps in exact-capacity copies before every gate so no buffer is reused.
+fn run(n: usize, layers: usize, fresh: bool) -> MPS {
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-14).build();
+    let mut psi = MPS::new_zero(n);
+    for layer in 0..layers {
+        for k in 0..n {
+            psi.apply_1q(k, ry(0.3 + 0.7 * (k + layer) as f64));
+        }
+        for k in 0..n - 1 {
+            if fresh {
+                for site in [k, k + 1] {
+                    let t = &psi.sites()[site];
+                    let copy = Tensor3 {
+                        data: t.data.to_vec(),
+                        ..*t
+                    };
+                    psi.sites_mut()[site] = copy;
+                }
+            }
+            psi.apply_2q_svd(k, cphase(1.1 + 0.4 * k as f64), trunc);
+        }
+    }
+    psi
+}
+
+#[test]
+fn reset_zeroes_and_keeps_capacity() {
+    let mut t = Tensor3::zeros(4, 2, 4);
+    t.set(1, 1, 1, C64::new(2.0, 0.0));
+    let cap = t.data.capacity();
+
+    t.reset(2, 2, 3);
+    assert_eq!((t.dl, t.dp, t.dr), (2, 2, 3));
+    assert_eq!(t.data.len(), 12);
+    assert!(t.data.iter().all(|v| *v == C64::new(0.0, 0.0)));
+    assert_eq!(t.data.capacity(), cap);
+}
+
+#[test]
+fn buffer_reuse_gives_identical_results() {
+    let reused = run(6, 4, false);
+    let fresh = run(6, 4, true);
+
+    for (a, b) in reused.sites().iter().zip(fresh.sites().iter()) {
+        assert_eq!((a.dl, a.dp, a.dr), (b.dl, b.dp, b.dr));
+        assert_eq!(a.data, b.data);
+    }
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "std")]
+
+use faer::Mat;
+use rng::ONDRng;
+use tn::{
+    mps::C64,
+    svd::{randomized_svd, thin_svd, SvdBackend, SvdFactors},
+};
+
+/// Rank-`rank` matrix plus small dense noise, built deterministically.
+fn low_rank_plus_noise(n: usize, rank: usize, noise: f64) -> Mat<C64> {
+    Mat::<C64>::from_fn(n, n, |i, j| {
+        let mut acc = C64::new(0.0, 0.0);
+        for r in 0..rank {
+            let w = 1.0 / (1.0 + r as f64);
+            let a = C64::from_polar(1.0, 0.37 * (i * (r + 1)) as f64);
+            let b = C64::from_polar(1.0, 0.91 * (j * (r + 2)) as f64 + r as f64);
+            acc += a * b * w;
+        }
+        let x = (i * n + j) as f64;
+        acc + C64::new((1.3 * x).sin(), (2.9 * x).cos()) * noise
+    })
+}
+
+/// Frobenius error of the rank-k truncation of `f` against `m`.
+fn truncation_error(m: &Mat<C64>, f: &SvdFactors, k: usize) -> f64 {
+    let mut err = 0.0;
+    for i in 0..m.nrows() {
+        for j in 0..m.ncols() {
+            let mut acc = C64::new(0.0, 0.0);
+            for t in 0..k {
+                acc += f.u.read(i, t) * f.s[t] * f.v.read(j, t).conj();
+            }
+            err += (acc - m.read(i, j)).norm_sqr();
+        }
+    }
+    err.sqrt()
+}
+
+#[test]
+fn randomized_truncation_matches_exact_on_low_rank_theta() {
+    let k = 6;
+    let m = low_rank_plus_noise(48, k, 1e-4);
+
+    let exact = thin_svd(&m, SvdBackend::Faer);
+    let mut rng = ONDRng::new(b"rsvd-test");
+    let approx = randomized_svd(&m, k, 2, &mut rng);
+
+    let exact_err = truncation_error(&m, &exact, k);
+    let approx_err = truncation_error(&m, &approx, k);
+    assert!(
+        approx_err <= 1.1 * exact_err,
+        "randomized {} vs exact {}",
+        approx_err,
+        exact_err
+    );
+
+    for t in 0..k {
+        assert!((approx.s[t] - exact.s[t]).abs() < 1e-6 * exact.s[0]);
+    }
+}
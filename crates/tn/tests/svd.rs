@@ -0,0 +1,94 @@
+#![cfg(feature = "std")]
+
+use faer::Mat;
+use tn::{
+    mps::{C64, MPS},
+    svd::{thin_svd, SvdBackend},
+    truncation::Truncation,
+};
+
+/// Deterministic pseudo-random complex matrix.
+fn theta(rows: usize, cols: usize) -> Mat<C64> {
+    Mat::<C64>::from_fn(rows, cols, |i, j| {
+        let x = (i * cols + j) as f64;
+        C64::new((1.7 * x + 0.3).sin(), (2.3 * x + 1.1).cos())
+    })
+}
+
+fn reconstruct_err(m: &Mat<C64>, backend: SvdBackend) -> f64 {
+    let f = thin_svd(m, backend);
+    let mut err = 0.0f64;
+    for i in 0..m.nrows() {
+        for j in 0..m.ncols() {
+            let mut acc = C64::new(0.0, 0.0);
+            for k in 0..f.s.len() {
+                acc += f.u.read(i, k) * f.s[k] * f.v.read(j, k).conj();
+            }
+            err = err.max((acc - m.read(i, j)).norm());
+        }
+    }
+    err
+}
+
+#[test]
+fn backends_agree_on_singular_values() {
+    for (rows, cols) in [(8, 6), (6, 8), (16, 16)] {
+        let m = theta(rows, cols);
+        let faer = thin_svd(&m, SvdBackend::Faer);
+        let jacobi = thin_svd(&m, SvdBackend::Jacobi);
+
+        assert_eq!(faer.s.len(), jacobi.s.len());
+        for (a, b) in faer.s.iter().zip(jacobi.s.iter()) {
+            assert!((a - b).abs() < 1e-10, "{}x{}: {} vs {}", rows, cols, a, b);
+        }
+        assert!(reconstruct_err(&m, SvdBackend::Jacobi) < 1e-10);
+    }
+}
+
+#[test]
+fn faer_backend_handles_large_thetas_on_a_test_thread() {
+    // faer's SVD overflows the 2 MB test thread stack unoptimized; the workspace builds
+    // faer with opt-level 3 in dev, which this guards.
+    for (rows, cols) in [(64, 64), (128, 96)] {
+        let m = theta(rows, cols);
+        let err = reconstruct_err(&m, SvdBackend::Faer);
+        assert!(err < 1e-9, "{}x{}: err = {}", rows, cols, err);
+    }
+}
+
+#[test]
+fn jacobi_backend_gives_same_state() {
+    let trunc = Truncation::builder().max_bond(8).cutoff(1e-14).build();
+    let h = 1.0 / 2.0_f64.sqrt();
+    let had = [
+        [C64::new(h, 0.0), C64::new(h, 0.0)],
+        [C64::new(h, 0.0), C64::new(-h, 0.0)],
+    ];
+    let z = C64::new(0.0, 0.0);
+    let o = C64::new(1.0, 0.0);
+    let gate = [
+        [o, z, z, z],
+        [z, C64::new(0.0, 1.0), z, z],
+        [z, z, o, z],
+        [z, z, z, C64::from_polar(1.0, 0.7)],
+    ];
+
+    let mut a = MPS::new_zero(4);
+    let mut b = MPS::new_zero(4);
+    for layer in 0..3 {
+        for k in 0..4 {
+            a.apply_1q(k, had);
+            b.apply_1q(k, had);
+        }
+        for k in (layer % 2..3).step_by(2) {
+            a.apply_2q_svd(k, gate, trunc);
+            b.apply_2q_svd_with(k, gate, trunc, SvdBackend::Jacobi);
+        }
+    }
+
+    let va = a.to_statevector();
+    let vb = b.to_statevector();
+    for (x, y) in va.iter().zip(vb.iter()) {
+        assert!((x - y).norm() < 1e-10);
+    }
+}
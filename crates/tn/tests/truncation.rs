@@ -0,0 +1,135 @@
+#![cfg(feature = "std")]
+
+use tn::{
+    mps::{fidelity, Tensor3, C64, MPS},
+    truncation::{Truncation, DEFAULT_REL_FLOOR},
+};
+
+#[test]
+fn builder_defaults_match_common_literal() {
+    let built = Truncation::builder().build();
+    let literal = Truncation::builder().max_bond(64).cutoff(1e-8).build();
+    assert_eq!(built, literal);
+}
+
+#[test]
+fn builder_setters_override_defaults() {
+    let t = Truncation::builder().max_bond(8).cutoff(1e-12).build();
+    assert_eq!(t.max_bond, 8);
+    assert_eq!(t.cutoff, 1e-12);
+
+    let t = Truncation::builder().cutoff(0.0).build();
+    assert_eq!(t.max_bond, 64);
+    assert_eq!(t.cutoff, 0.0);
+}
+
+#[test]
+fn default_and_presets() {
+    let d = Truncation::default();
+    assert_eq!((d.max_bond, d.cutoff), (64, 1e-8));
+
+    let e = Truncation::exact();
+    assert_eq!((e.max_bond, e.cutoff), (usize::MAX, 1e-14));
+
+    let f = Truncation::fast();
+    assert_eq!((f.max_bond, f.cutoff), (16, 1e-6));
+}
+
+#[test]
+fn exact_preserves_bell_state() {
+    let s = std::f64::consts::FRAC_1_SQRT_2;
+    let z = C64::new(0.0, 0.0);
+    let o = C64::new(1.0, 0.0);
+    let h = [[C64::new(s, 0.0), C64::new(s, 0.0)], [C64::new(s, 0.0), C64::new(-s, 0.0)]];
+    let cnot = [[o, z, z, z], [z, o, z, z], [z, z, z, o], [z, z, o, z]];
+
+    let mut psi = MPS::new_zero(2);
+    psi.apply_1q(0, h);
+    psi.apply_2q_svd(0, cnot, Truncation::exact());
+
+    let mut bell = MPS::new_zero(2);
+    bell.sites_mut()[0] = Tensor3::zeros(1, 2, 2);
+    bell.sites_mut()[0].set(0, 0, 0, C64::new(s, 0.0));
+    bell.sites_mut()[0].set(0, 1, 1, C64::new(s, 0.0));
+    bell.sites_mut()[1] = Tensor3::zeros(2, 2, 1);
+    bell.sites_mut()[1].set(0, 0, 0, o);
+    bell.sites_mut()[1].set(1, 1, 0, o);
+
+    assert_eq!(psi.sites()[0].dr, 2);
+    assert!((fidelity(&psi, &bell) - 1.0).abs() < 1e-14);
+}
+
+#[test]
+fn truncation_is_copied_into_closures() {
+    let trunc = Truncation::builder().max_bond(4).cutoff(1e-12).build();
+    let evolve = move |n: usize| {
+        let mut psi = MPS::new_zero(n);
+        for k in 0..n - 1 {
+            psi.apply_2q_svd(k, [[C64::new(0.5, 0.0); 4]; 4], trunc);
+        }
+        psi
+    };
+
+    let a = evolve(4);
+    let b = evolve(4);
+    assert!((fidelity(&a, &b) - 1.0).abs() < 1e-12);
+    // Still usable after being captured by value.
+    assert_eq!(trunc, Truncation::builder().max_bond(4).cutoff(1e-12).build());
+    assert_eq!(
+        format!("{:?}", trunc),
+        format!("Truncation {{ max_bond: 4, cutoff: 1e-12, rel_floor: {:?} }}", DEFAULT_REL_FLOOR)
+    );
+}
+
+#[test]
+fn maximally_entangling_gate_saturates_unit_bond() {
+    let h = std::f64::consts::FRAC_1_SQRT_2;
+    let z = C64::new(0.0, 0.0);
+    let p = C64::new(h, 0.0);
+    let m = C64::new(-h, 0.0);
+    // CNOT(0 -> 1) · (H ⊗ I): |00> -> (|00> + |11>)/√2.
+    let bell = [[p, z, p, z], [z, p, z, p], [z, p, z, m], [p, z, m, z]];
+
+    let mut psi = MPS::new_zero(2);
+    assert!(psi.apply_2q_svd(0, bell, Truncation::builder().max_bond(1).build()));
+    assert_eq!(psi.sites()[0].dr, 1);
+
+    let mut psi = MPS::new_zero(2);
+    assert!(!psi.apply_2q_svd(0, bell, Truncation::builder().max_bond(2).build()));
+    assert_eq!(psi.sites()[0].dr, 2);
+}
+
+#[test]
+fn relative_floor_drops_round_off_singular_values() {
+    let o = C64::new(1.0, 0.0);
+    let z = C64::new(0.0, 0.0);
+    let id = [[o, z, z, z], [z, o, z, z], [z, z, o, z], [z, z, z, o]];
+
+    // |00> + 1e-15 |11>: Schmidt values 1 and 1e-15 across the bond.
+    let noisy = || {
+        let mut psi = MPS::new_zero(2);
+        psi.sites_mut()[0] = Tensor3::zeros(1, 2, 2);
+        psi.sites_mut()[0].set(0, 0, 0, o);
+        psi.sites_mut()[0].set(0, 1, 1, o);
+        psi.sites_mut()[1] = Tensor3::zeros(2, 2, 1);
+        psi.sites_mut()[1].set(0, 0, 0, o);
+        psi.sites_mut()[1].set(1, 1, 0, C64::new(1e-15, 0.0));
+        psi
+    };
+
+    let mut floored = noisy();
+    floored.apply_2q_svd(0, id, Truncation::builder().max_bond(4).cutoff(0.0).build());
+    assert_eq!(floored.sites()[0].dr, 1);
+    assert!((fidelity(&floored, &MPS::new_zero(2)) - 1.0).abs() < 1e-14);
+
+    let no_floor = Truncation::builder().max_bond(4).cutoff(0.0).rel_floor(0.0).build();
+    let mut kept = noisy();
+    kept.apply_2q_svd(0, id, no_floor);
+    assert_eq!(kept.sites()[0].dr, 2);
+
+    let t = Truncation::default();
+    assert_eq!(t.rel_floor(), DEFAULT_REL_FLOOR);
+    assert_eq!(no_floor.rel_floor(), 0.0);
+    assert_eq!(t.threshold(1.0), 1e-8);
+    assert_eq!(t.threshold(1e9), 1e9 * DEFAULT_REL_FLOOR);
+}